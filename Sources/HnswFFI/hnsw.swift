@@ -414,7 +414,29 @@ fileprivate final class UniffiHandleMap<T>: @unchecked Sendable {
 
 
 // Public interface members begin here.
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+private let IDX_CALLBACK_FREE: Int32 = 0
+// Callback return codes
+private let UNIFFI_CALLBACK_SUCCESS: Int32 = 0
+private let UNIFFI_CALLBACK_ERROR: Int32 = 1
+private let UNIFFI_CALLBACK_UNEXPECTED_ERROR: Int32 = 2
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt16: FfiConverterPrimitive {
+    typealias FfiType = UInt16
+    typealias SwiftType = UInt16
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt16 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
@@ -432,6 +454,22 @@ fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterInt32: FfiConverterPrimitive {
+    typealias FfiType = Int32
+    typealias SwiftType = Int32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Int32, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -448,6 +486,22 @@ fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterInt64: FfiConverterPrimitive {
+    typealias FfiType = Int64
+    typealias SwiftType = Int64
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int64 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Int64, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -464,6 +518,22 @@ fileprivate struct FfiConverterFloat: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDouble: FfiConverterPrimitive {
+    typealias FfiType = Double
+    typealias SwiftType = Double
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Double {
+        return try lift(readDouble(&buf))
+    }
+
+    public static func write(_ value: Double, into buf: inout [UInt8]) {
+        writeDouble(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -529,31 +599,37 @@ fileprivate struct FfiConverterString: FfiConverter {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterData: FfiConverterRustBuffer {
+    typealias SwiftType = Data
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Data {
+        let len: Int32 = try readInt(&buf)
+        return Data(try readBytes(&buf, count: Int(len)))
+    }
 
+    public static func write(_ value: Data, into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        writeBytes(&buf, value)
+    }
+}
 
-public protocol HnswIndexProtocol: AnyObject, Sendable {
-    
-    func compact(deletedIds: [UInt64], config: HnswIndexConfig) throws  -> HnswIndex
-    
-    func getDimension()  -> UInt32
-    
-    func insert(data: [Float], id: UInt64) throws 
-    
-    func insertBatch(data: [[Float]], ids: [UInt64]) throws 
-    
-    func isEmpty() throws  -> Bool
-    
-    func len() throws  -> UInt64
+
+
+
+public protocol CancellationTokenProtocol: AnyObject, Sendable {
     
-    func save(directory: String, basename: String) throws 
+    func cancel() 
     
-    func search(query: [Float], k: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    func isCancelled()  -> Bool
     
-    func setSearchingMode(enabled: Bool) throws 
+    func reset() 
     
 }
-open class HnswIndex: HnswIndexProtocol, @unchecked Sendable {
+open class CancellationToken: CancellationTokenProtocol, @unchecked Sendable {
     fileprivate let handle: UInt64
 
     /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
@@ -590,112 +666,43 @@ open class HnswIndex: HnswIndexProtocol, @unchecked Sendable {
     @_documentation(visibility: private)
 #endif
     public func uniffiCloneHandle() -> UInt64 {
-        return try! rustCall { uniffi_hnsw_fn_clone_hnswindex(self.handle, $0) }
+        return try! rustCall { uniffi_hnsw_fn_clone_cancellationtoken(self.handle, $0) }
     }
-public convenience init(config: HnswIndexConfig) {
+public convenience init() {
     let handle =
         try! rustCall() {
-    uniffi_hnsw_fn_constructor_hnswindex_new(
-        FfiConverterTypeHnswIndexConfig_lower(config),$0
+    uniffi_hnsw_fn_constructor_cancellationtoken_new($0
     )
 }
     self.init(unsafeFromHandle: handle)
 }
 
     deinit {
-        try! rustCall { uniffi_hnsw_fn_free_hnswindex(handle, $0) }
+        try! rustCall { uniffi_hnsw_fn_free_cancellationtoken(handle, $0) }
     }
 
     
-public static func load(directory: String, basename: String, config: HnswIndexConfig)throws  -> HnswIndex  {
-    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
-    uniffi_hnsw_fn_constructor_hnswindex_load(
-        FfiConverterString.lower(directory),
-        FfiConverterString.lower(basename),
-        FfiConverterTypeHnswIndexConfig_lower(config),$0
-    )
-})
-}
-    
 
     
-open func compact(deletedIds: [UInt64], config: HnswIndexConfig)throws  -> HnswIndex  {
-    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
-    uniffi_hnsw_fn_method_hnswindex_compact(
-            self.uniffiCloneHandle(),
-        FfiConverterSequenceUInt64.lower(deletedIds),
-        FfiConverterTypeHnswIndexConfig_lower(config),$0
-    )
-})
-}
-    
-open func getDimension() -> UInt32  {
-    return try!  FfiConverterUInt32.lift(try! rustCall() {
-    uniffi_hnsw_fn_method_hnswindex_get_dimension(
+open func cancel()  {try! rustCall() {
+    uniffi_hnsw_fn_method_cancellationtoken_cancel(
             self.uniffiCloneHandle(),$0
     )
-})
-}
-    
-open func insert(data: [Float], id: UInt64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
-    uniffi_hnsw_fn_method_hnswindex_insert(
-            self.uniffiCloneHandle(),
-        FfiConverterSequenceFloat.lower(data),
-        FfiConverterUInt64.lower(id),$0
-    )
-}
-}
-    
-open func insertBatch(data: [[Float]], ids: [UInt64])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
-    uniffi_hnsw_fn_method_hnswindex_insert_batch(
-            self.uniffiCloneHandle(),
-        FfiConverterSequenceSequenceFloat.lower(data),
-        FfiConverterSequenceUInt64.lower(ids),$0
-    )
 }
 }
     
-open func isEmpty()throws  -> Bool  {
-    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
-    uniffi_hnsw_fn_method_hnswindex_is_empty(
+open func isCancelled() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_cancellationtoken_is_cancelled(
             self.uniffiCloneHandle(),$0
     )
 })
 }
     
-open func len()throws  -> UInt64  {
-    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
-    uniffi_hnsw_fn_method_hnswindex_len(
+open func reset()  {try! rustCall() {
+    uniffi_hnsw_fn_method_cancellationtoken_reset(
             self.uniffiCloneHandle(),$0
     )
-})
-}
-    
-open func save(directory: String, basename: String)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
-    uniffi_hnsw_fn_method_hnswindex_save(
-            self.uniffiCloneHandle(),
-        FfiConverterString.lower(directory),
-        FfiConverterString.lower(basename),$0
-    )
-}
-}
-    
-open func search(query: [Float], k: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
-    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
-    uniffi_hnsw_fn_method_hnswindex_search(
-            self.uniffiCloneHandle(),
-        FfiConverterSequenceFloat.lower(query),
-        FfiConverterUInt32.lower(k),
-        FfiConverterUInt32.lower(efSearch),$0
-    )
-})
-}
-    
-open func setSearchingMode(enabled: Bool)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
-    uniffi_hnsw_fn_method_hnswindex_set_searching_mode(
-            self.uniffiCloneHandle(),
-        FfiConverterBool.lower(enabled),$0
-    )
 }
 }
     
@@ -707,24 +714,24 @@ open func setSearchingMode(enabled: Bool)throws   {try rustCallWithError(FfiConv
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHnswIndex: FfiConverter {
+public struct FfiConverterTypeCancellationToken: FfiConverter {
     typealias FfiType = UInt64
-    typealias SwiftType = HnswIndex
+    typealias SwiftType = CancellationToken
 
-    public static func lift(_ handle: UInt64) throws -> HnswIndex {
-        return HnswIndex(unsafeFromHandle: handle)
+    public static func lift(_ handle: UInt64) throws -> CancellationToken {
+        return CancellationToken(unsafeFromHandle: handle)
     }
 
-    public static func lower(_ value: HnswIndex) -> UInt64 {
+    public static func lower(_ value: CancellationToken) -> UInt64 {
         return value.uniffiCloneHandle()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswIndex {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CancellationToken {
         let handle: UInt64 = try readInt(&buf)
         return try lift(handle)
     }
 
-    public static func write(_ value: HnswIndex, into buf: inout [UInt8]) {
+    public static func write(_ value: CancellationToken, into buf: inout [UInt8]) {
         writeInt(&buf, lower(value))
     }
 }
@@ -733,121 +740,150 @@ public struct FfiConverterTypeHnswIndex: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHnswIndex_lift(_ handle: UInt64) throws -> HnswIndex {
-    return try FfiConverterTypeHnswIndex.lift(handle)
+public func FfiConverterTypeCancellationToken_lift(_ handle: UInt64) throws -> CancellationToken {
+    return try FfiConverterTypeCancellationToken.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHnswIndex_lower(_ value: HnswIndex) -> UInt64 {
-    return FfiConverterTypeHnswIndex.lower(value)
+public func FfiConverterTypeCancellationToken_lower(_ value: CancellationToken) -> UInt64 {
+    return FfiConverterTypeCancellationToken.lower(value)
 }
 
 
 
 
-public struct HnswIndexConfig: Equatable, Hashable {
-    public var maxNbConnection: UInt32
-    public var maxElements: UInt64
-    public var maxLayer: UInt32
-    public var efConstruction: UInt32
-    public var dimension: UInt32
-    public var distance: DistanceType
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(maxNbConnection: UInt32, maxElements: UInt64, maxLayer: UInt32, efConstruction: UInt32, dimension: UInt32, distance: DistanceType) {
-        self.maxNbConnection = maxNbConnection
-        self.maxElements = maxElements
-        self.maxLayer = maxLayer
-        self.efConstruction = efConstruction
-        self.dimension = dimension
-        self.distance = distance
-    }
 
+public protocol DimReducerProtocol: AnyObject, Sendable {
+    
+    func inputDimension()  -> UInt32
+    
+    func outputDimension()  -> UInt32
+    
+    func transform(vector: [Float]) throws  -> [Float]
     
 }
+open class DimReducer: DimReducerProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
 
-#if compiler(>=6)
-extension HnswIndexConfig: Sendable {}
-#endif
-
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHnswIndexConfig: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswIndexConfig {
-        return
-            try HnswIndexConfig(
-                maxNbConnection: FfiConverterUInt32.read(from: &buf), 
-                maxElements: FfiConverterUInt64.read(from: &buf), 
-                maxLayer: FfiConverterUInt32.read(from: &buf), 
-                efConstruction: FfiConverterUInt32.read(from: &buf), 
-                dimension: FfiConverterUInt32.read(from: &buf), 
-                distance: FfiConverterTypeDistanceType.read(from: &buf)
-        )
+    public struct NoHandle {
+        public init() {}
     }
 
-    public static func write(_ value: HnswIndexConfig, into buf: inout [UInt8]) {
-        FfiConverterUInt32.write(value.maxNbConnection, into: &buf)
-        FfiConverterUInt64.write(value.maxElements, into: &buf)
-        FfiConverterUInt32.write(value.maxLayer, into: &buf)
-        FfiConverterUInt32.write(value.efConstruction, into: &buf)
-        FfiConverterUInt32.write(value.dimension, into: &buf)
-        FfiConverterTypeDistanceType.write(value.distance, into: &buf)
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
     }
-}
-
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHnswIndexConfig_lift(_ buf: RustBuffer) throws -> HnswIndexConfig {
-    return try FfiConverterTypeHnswIndexConfig.lift(buf)
-}
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
 
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHnswIndexConfig_lower(_ value: HnswIndexConfig) -> RustBuffer {
-    return FfiConverterTypeHnswIndexConfig.lower(value)
-}
-
-
-public struct SearchResult: Equatable, Hashable {
-    public var id: UInt64
-    public var distance: Float
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_dimreducer(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(id: UInt64, distance: Float) {
-        self.id = id
-        self.distance = distance
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_dimreducer(handle, $0) }
     }
 
     
+public static func randomProjection(inputDim: UInt32, targetDim: UInt32, seed: UInt64)throws  -> DimReducer  {
+    return try  FfiConverterTypeDimReducer_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_dimreducer_random_projection(
+        FfiConverterUInt32.lower(inputDim),
+        FfiConverterUInt32.lower(targetDim),
+        FfiConverterUInt64.lower(seed),$0
+    )
+})
+}
+    
+public static func trainPca(vectors: [[Float]], targetDim: UInt32)throws  -> DimReducer  {
+    return try  FfiConverterTypeDimReducer_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_dimreducer_train_pca(
+        FfiConverterSequenceSequenceFloat.lower(vectors),
+        FfiConverterUInt32.lower(targetDim),$0
+    )
+})
+}
+    
+
+    
+open func inputDimension() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_dimreducer_input_dimension(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func outputDimension() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_dimreducer_output_dimension(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func transform(vector: [Float])throws  -> [Float]  {
+    return try  FfiConverterSequenceFloat.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_dimreducer_transform(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(vector),$0
+    )
+})
+}
+    
+
+    
 }
 
-#if compiler(>=6)
-extension SearchResult: Sendable {}
-#endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSearchResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchResult {
-        return
-            try SearchResult(
-                id: FfiConverterUInt64.read(from: &buf), 
-                distance: FfiConverterFloat.read(from: &buf)
-        )
+public struct FfiConverterTypeDimReducer: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = DimReducer
+
+    public static func lift(_ handle: UInt64) throws -> DimReducer {
+        return DimReducer(unsafeFromHandle: handle)
     }
 
-    public static func write(_ value: SearchResult, into buf: inout [UInt8]) {
-        FfiConverterUInt64.write(value.id, into: &buf)
-        FfiConverterFloat.write(value.distance, into: &buf)
+    public static func lower(_ value: DimReducer) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DimReducer {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: DimReducer, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
     }
 }
 
@@ -855,365 +891,8867 @@ public struct FfiConverterTypeSearchResult: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSearchResult_lift(_ buf: RustBuffer) throws -> SearchResult {
-    return try FfiConverterTypeSearchResult.lift(buf)
+public func FfiConverterTypeDimReducer_lift(_ handle: UInt64) throws -> DimReducer {
+    return try FfiConverterTypeDimReducer.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSearchResult_lower(_ value: SearchResult) -> RustBuffer {
-    return FfiConverterTypeSearchResult.lower(value)
+public func FfiConverterTypeDimReducer_lower(_ value: DimReducer) -> UInt64 {
+    return FfiConverterTypeDimReducer.lower(value)
 }
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-public enum DistanceType: Equatable, Hashable {
-    
-    case l2
-    case cosine
-    case l1
-    case dot
 
 
 
-}
 
-#if compiler(>=6)
-extension DistanceType: Sendable {}
-#endif
+public protocol HnswCollectionProtocol: AnyObject, Sendable {
+    
+    func contains(name: String) throws  -> Bool
+    
+    func create(name: String, config: HnswIndexConfig) throws  -> HnswIndex
+    
+    func dropIndex(name: String) throws 
+    
+    func get(name: String) throws  -> HnswIndex
+    
+    func getConfig(name: String) throws  -> HnswIndexConfig
+    
+    func list() throws  -> [String]
+    
+    func loadAll() throws 
+    
+    func save(name: String) throws 
+    
+    func saveAll() throws 
+    
+}
+open class HnswCollection: HnswCollectionProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
 
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeDistanceType: FfiConverterRustBuffer {
-    typealias SwiftType = DistanceType
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DistanceType {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .l2
-        
-        case 2: return .cosine
-        
-        case 3: return .l1
-        
-        case 4: return .dot
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
+    public struct NoHandle {
+        public init() {}
     }
 
-    public static func write(_ value: DistanceType, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .l2:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .cosine:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .l1:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .dot:
-            writeInt(&buf, Int32(4))
-        
-        }
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
     }
-}
-
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDistanceType_lift(_ buf: RustBuffer) throws -> DistanceType {
-    return try FfiConverterTypeDistanceType.lift(buf)
-}
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
 
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeDistanceType_lower(_ value: DistanceType) -> RustBuffer {
-    return FfiConverterTypeDistanceType.lower(value)
-}
-
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_hnswcollection(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
 
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_hnswcollection(handle, $0) }
+    }
 
-public enum HnswError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+    
+public static func `open`(directory: String)throws  -> HnswCollection  {
+    return try  FfiConverterTypeHnswCollection_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswcollection_open(
+        FfiConverterString.lower(directory),$0
+    )
+})
+}
+    
 
     
+open func contains(name: String)throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswcollection_contains(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(name),$0
+    )
+})
+}
     
-    case IoError(message: String)
+open func create(name: String, config: HnswIndexConfig)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswcollection_create(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(name),
+        FfiConverterTypeHnswIndexConfig_lower(config),$0
+    )
+})
+}
     
-    case LockError(message: String)
+open func dropIndex(name: String)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswcollection_drop_index(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(name),$0
+    )
+}
+}
     
-    case EmptyIndex(message: String)
+open func get(name: String)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswcollection_get(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(name),$0
+    )
+})
+}
     
-    case DistanceMismatch(message: String)
+open func getConfig(name: String)throws  -> HnswIndexConfig  {
+    return try  FfiConverterTypeHnswIndexConfig_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswcollection_get_config(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(name),$0
+    )
+})
+}
     
-    case DimensionMismatch(message: String)
+open func list()throws  -> [String]  {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswcollection_list(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
     
-    case ReloadError(message: String)
+open func loadAll()throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswcollection_load_all(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
     
-    case DumpError(message: String)
+open func save(name: String)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswcollection_save(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(name),$0
+    )
+}
+}
     
-
+open func saveAll()throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswcollection_save_all(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
     
 
     
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
-    
 }
 
-#if compiler(>=6)
-extension HnswError: Sendable {}
-#endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHnswError: FfiConverterRustBuffer {
-    typealias SwiftType = HnswError
+public struct FfiConverterTypeHnswCollection: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = HnswCollection
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
+    public static func lift(_ handle: UInt64) throws -> HnswCollection {
+        return HnswCollection(unsafeFromHandle: handle)
+    }
 
-        
+    public static func lower(_ value: HnswCollection) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
 
-        
-        case 1: return .IoError(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 2: return .LockError(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 3: return .EmptyIndex(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 4: return .DistanceMismatch(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 5: return .DimensionMismatch(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 6: return .ReloadError(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 7: return .DumpError(
-            message: try FfiConverterString.read(from: &buf)
-        )
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswCollection {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
 
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
+    public static func write(_ value: HnswCollection, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
     }
-
-    public static func write(_ value: HnswError, into buf: inout [UInt8]) {
-        switch value {
-
-        
-
-        
-        case .IoError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(1))
-        case .LockError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(2))
-        case .EmptyIndex(_ /* message is ignored*/):
-            writeInt(&buf, Int32(3))
-        case .DistanceMismatch(_ /* message is ignored*/):
-            writeInt(&buf, Int32(4))
-        case .DimensionMismatch(_ /* message is ignored*/):
-            writeInt(&buf, Int32(5))
-        case .ReloadError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(6))
-        case .DumpError(_ /* message is ignored*/):
-            writeInt(&buf, Int32(7))
-
-        
-        }
-    }
-}
+}
 
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHnswError_lift(_ buf: RustBuffer) throws -> HnswError {
-    return try FfiConverterTypeHnswError.lift(buf)
+public func FfiConverterTypeHnswCollection_lift(_ handle: UInt64) throws -> HnswCollection {
+    return try FfiConverterTypeHnswCollection.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHnswError_lower(_ value: HnswError) -> RustBuffer {
-    return FfiConverterTypeHnswError.lower(value)
+public func FfiConverterTypeHnswCollection_lower(_ value: HnswCollection) -> UInt64 {
+    return FfiConverterTypeHnswCollection.lower(value)
+}
+
+
+
+
+
+
+public protocol HnswF64IndexProtocol: AnyObject, Sendable {
+    
+    func getDimension()  -> UInt32
+    
+    func insert(data: [Double], id: UInt64) throws 
+    
+    func insertBatch(data: [[Double]], ids: [UInt64]) throws 
+    
+    func isEmpty() throws  -> Bool
+    
+    func len() throws  -> UInt64
+    
+    func save(directory: String, basename: String) throws 
+    
+    func search(query: [Double], k: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    
 }
+open class HnswF64Index: HnswF64IndexProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
 
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceUInt64: FfiConverterRustBuffer {
-    typealias SwiftType = [UInt64]
-
-    public static func write(_ value: [UInt64], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterUInt64.write(item, into: &buf)
-        }
+    public struct NoHandle {
+        public init() {}
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt64] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [UInt64]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterUInt64.read(from: &buf))
-        }
-        return seq
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
     }
-}
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceFloat: FfiConverterRustBuffer {
-    typealias SwiftType = [Float]
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
 
-    public static func write(_ value: [Float], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterFloat.write(item, into: &buf)
-        }
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_hnswf64index(self.handle, $0) }
     }
+public convenience init(config: HnswIndexConfig)throws  {
+    let handle =
+        try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswf64index_new(
+        FfiConverterTypeHnswIndexConfig_lower(config),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Float] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [Float]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterFloat.read(from: &buf))
-        }
-        return seq
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_hnswf64index(handle, $0) }
     }
+
+    
+public static func load(directory: String, basename: String)throws  -> HnswF64Index  {
+    return try  FfiConverterTypeHnswF64Index_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswf64index_load(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+})
+}
+    
+
+    
+open func getDimension() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswf64index_get_dimension(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func insert(data: [Double], id: UInt64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswf64index_insert(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceDouble.lower(data),
+        FfiConverterUInt64.lower(id),$0
+    )
+}
+}
+    
+open func insertBatch(data: [[Double]], ids: [UInt64])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswf64index_insert_batch(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceDouble.lower(data),
+        FfiConverterSequenceUInt64.lower(ids),$0
+    )
+}
+}
+    
+open func isEmpty()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswf64index_is_empty(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func len()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswf64index_len(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func save(directory: String, basename: String)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswf64index_save(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+}
+}
+    
+open func search(query: [Double], k: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswf64index_search(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceDouble.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+
+    
 }
 
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceTypeSearchResult: FfiConverterRustBuffer {
-    typealias SwiftType = [SearchResult]
+public struct FfiConverterTypeHnswF64Index: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = HnswF64Index
 
-    public static func write(_ value: [SearchResult], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeSearchResult.write(item, into: &buf)
-        }
+    public static func lift(_ handle: UInt64) throws -> HnswF64Index {
+        return HnswF64Index(unsafeFromHandle: handle)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SearchResult] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [SearchResult]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeSearchResult.read(from: &buf))
-        }
-        return seq
+    public static func lower(_ value: HnswF64Index) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswF64Index {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: HnswF64Index, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
     }
 }
 
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterSequenceSequenceFloat: FfiConverterRustBuffer {
-    typealias SwiftType = [[Float]]
-
-    public static func write(_ value: [[Float]], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterSequenceFloat.write(item, into: &buf)
-        }
-    }
+public func FfiConverterTypeHnswF64Index_lift(_ handle: UInt64) throws -> HnswF64Index {
+    return try FfiConverterTypeHnswF64Index.lift(handle)
+}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [[Float]] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [[Float]]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterSequenceFloat.read(from: &buf))
-        }
-        return seq
-    }
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswF64Index_lower(_ value: HnswF64Index) -> UInt64 {
+    return FfiConverterTypeHnswF64Index.lower(value)
 }
 
-private enum InitializationResult {
-    case ok
-    case contractVersionMismatch
-    case apiChecksumMismatch
+
+
+
+
+
+public protocol HnswI32IndexProtocol: AnyObject, Sendable {
+    
+    func getDimension()  -> UInt32
+    
+    func insert(data: [Int32], id: UInt64) throws 
+    
+    func insertBatch(data: [[Int32]], ids: [UInt64]) throws 
+    
+    func isEmpty() throws  -> Bool
+    
+    func len() throws  -> UInt64
+    
+    func save(directory: String, basename: String) throws 
+    
+    func search(query: [Int32], k: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    
 }
-// Use a global variable to perform the versioning checks. Swift ensures that
-// the code inside is only computed once.
-private let initializationResult: InitializationResult = {
-    // Get the bindings contract version from our ComponentInterface
-    let bindings_contract_version = 30
-    // Get the scaffolding contract version by calling the into the dylib
-    let scaffolding_contract_version = ffi_hnsw_uniffi_contract_version()
-    if bindings_contract_version != scaffolding_contract_version {
+open class HnswI32Index: HnswI32IndexProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_hnswi32index(self.handle, $0) }
+    }
+public convenience init(config: HnswIndexConfig)throws  {
+    let handle =
+        try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswi32index_new(
+        FfiConverterTypeHnswIndexConfig_lower(config),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_hnswi32index(handle, $0) }
+    }
+
+    
+public static func load(directory: String, basename: String)throws  -> HnswI32Index  {
+    return try  FfiConverterTypeHnswI32Index_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswi32index_load(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+})
+}
+    
+
+    
+open func getDimension() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswi32index_get_dimension(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func insert(data: [Int32], id: UInt64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswi32index_insert(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceInt32.lower(data),
+        FfiConverterUInt64.lower(id),$0
+    )
+}
+}
+    
+open func insertBatch(data: [[Int32]], ids: [UInt64])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswi32index_insert_batch(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceInt32.lower(data),
+        FfiConverterSequenceUInt64.lower(ids),$0
+    )
+}
+}
+    
+open func isEmpty()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswi32index_is_empty(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func len()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswi32index_len(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func save(directory: String, basename: String)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswi32index_save(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+}
+}
+    
+open func search(query: [Int32], k: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswi32index_search(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceInt32.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHnswI32Index: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = HnswI32Index
+
+    public static func lift(_ handle: UInt64) throws -> HnswI32Index {
+        return HnswI32Index(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: HnswI32Index) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswI32Index {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: HnswI32Index, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswI32Index_lift(_ handle: UInt64) throws -> HnswI32Index {
+    return try FfiConverterTypeHnswI32Index.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswI32Index_lower(_ value: HnswI32Index) -> UInt64 {
+    return FfiConverterTypeHnswI32Index.lower(value)
+}
+
+
+
+
+
+
+public protocol HnswIndexProtocol: AnyObject, Sendable {
+    
+    func applyChanges(changes: Data) throws  -> ChangeApplyReport
+    
+    func centroid(ids: [UInt64]) throws  -> [Float]
+    
+    func changeVersion() throws  -> UInt64
+    
+    func clearQueryTransform() 
+    
+    func compact(deletedIds: [UInt64], config: HnswIndexConfig) throws  -> HnswIndex
+    
+    func contains(id: UInt64) throws  -> Bool
+    
+    func count() throws  -> UInt64
+    
+    func countFiltered(filter: String) throws  -> UInt64
+    
+    func countWithin(query: [Float], radius: Float, efSearch: UInt32) throws  -> UInt64
+    
+    func createView(distance: DistanceType)  -> HnswIndexView
+    
+    func destroy(directory: String, basename: String) throws  -> UInt64
+    
+    func disableQueryCache() 
+    
+    func enableQueryCache(capacity: UInt32) throws 
+    
+    func evaluateRecall(queries: [[Float]], k: UInt32, efSearch: UInt32) throws  -> RecallReport
+    
+    func evictVectors() throws  -> Bool
+    
+    func exportAll(path: String, format: ExportFormat) throws  -> UInt64
+    
+    func exportArrow(path: String) throws  -> UInt64
+    
+    func exportChanges(sinceVersion: UInt64) throws  -> Data
+    
+    func exportGraph(path: String, format: GraphFormat) throws 
+    
+    func findDuplicates(threshold: Float, batchSize: UInt32) throws  -> [DuplicatePair]
+    
+    func getAttrs(id: UInt64) throws  -> [String: AttrValue]
+    
+    func getCapacity()  -> UInt64
+    
+    func getDefaultEfSearch()  -> UInt32
+    
+    func getDimension()  -> UInt32
+    
+    func getDimensionWeights()  -> [Float]?
+    
+    func getDistance()  -> DistanceType
+    
+    func getDocumentChunks(documentId: UInt64) throws  -> [UInt64]
+    
+    func getDocumentId(id: UInt64) throws  -> UInt64?
+    
+    func getEntryPoint() throws  -> UInt64?
+    
+    func getExtendCandidates()  -> Bool
+    
+    func getIdForKey(key: String) throws  -> UInt64?
+    
+    func getKeepPruned()  -> Bool
+    
+    func getKey(id: UInt64) throws  -> String?
+    
+    func getMaxLayer() throws  -> UInt32
+    
+    func getNamespace(id: UInt64) throws  -> UInt32?
+    
+    func getPayload(id: UInt64) throws  -> Data?
+    
+    func getPointData(id: UInt64) throws  -> [Float]?
+    
+    func getPrivacyEpsilon()  -> Double?
+    
+    func getReducer()  -> DimReducer?
+    
+    func getTimestamp(id: UInt64) throws  -> Int64?
+    
+    func graphNeighbors(id: UInt64, layer: UInt32) throws  -> [UInt64]
+    
+    func growTo(newMax: UInt64) throws 
+    
+    func handleMemoryPressure(level: MemoryPressureLevel) throws  -> UInt64
+    
+    func hasBinarySketches() throws  -> Bool
+    
+    func healthCheck() throws  -> HealthReport
+    
+    func hiddenIds() throws  -> [UInt64]
+    
+    func importArrow(path: String, vectorColumn: String, idColumn: String) throws  -> UInt64
+    
+    func importFaissFlat(path: String, listener: ProgressListener) throws  -> UInt64
+    
+    func importJsonl(path: String, vectorField: String, idField: String, payloadFields: [String]) throws  -> JsonlImportReport
+    
+    func importNpy(path: String, idOffset: UInt64) throws  -> UInt64
+    
+    func importNpz(path: String, vectorsName: String, idsName: String?, idOffset: UInt64) throws  -> UInt64
+    
+    func insert(data: [Float], id: UInt64) throws 
+    
+    func insertBatch(data: [[Float]], ids: [UInt64]) throws 
+    
+    func insertBatchCancellable(data: [[Float]], ids: [UInt64], token: CancellationToken) throws 
+    
+    func insertBatchFlat(data: [Float], ids: [UInt64]) throws 
+    
+    func insertBatchKeyed(data: [[Float]], keys: [String]) throws  -> [UInt64]
+    
+    func insertBatchSerial(data: [[Float]], ids: [UInt64]) throws 
+    
+    func insertBatchWithNamespace(data: [[Float]], ids: [UInt64], namespace: UInt32) throws 
+    
+    func insertBatchWithProgress(data: [[Float]], ids: [UInt64], listener: ProgressListener) throws 
+    
+    func insertBatchWithQos(data: [[Float]], ids: [UInt64], qos: ThreadQos) throws 
+    
+    func insertDocument(documentId: UInt64, chunks: [[Float]]) throws  -> [UInt64]
+    
+    func insertKeyed(data: [Float], key: String) throws  -> UInt64
+    
+    func insertSession(chunkSize: UInt32) throws  -> InsertSession
+    
+    func insertText(text: String, id: UInt64) throws 
+    
+    func insertTexts(texts: [String], ids: [UInt64]) throws 
+    
+    func insertWithAttrs(data: [Float], id: UInt64, attrs: [String: AttrValue]) throws 
+    
+    func insertWithNamespace(data: [Float], id: UInt64, namespace: UInt32) throws 
+    
+    func insertWithPayload(data: [Float], id: UInt64, payload: Data) throws 
+    
+    func insertWithTimestamp(data: [Float], id: UInt64, timestamp: Int64) throws 
+    
+    func isEmpty() throws  -> Bool
+    
+    func isHidden(id: UInt64) throws  -> Bool
+    
+    func isLoaded() throws  -> Bool
+    
+    func isMmapped()  -> Bool
+    
+    func isReadOnly()  -> Bool
+    
+    func kmeans(k: UInt32, maxIters: UInt32, useGraph: Bool) throws  -> [ClusterAssignment]
+    
+    func len() throws  -> UInt64
+    
+    func maintain(budgetMs: UInt64) throws  -> MaintenanceReport
+    
+    func memoryFootprint() throws  -> UInt64
+    
+    func migrate(embedder: MigrationEmbedder, newDimension: UInt32, newConfig: HnswIndexConfig, directory: String, basename: String, listener: ProgressListener, token: CancellationToken?) throws  -> HnswIndex
+    
+    func purgeOlderThan(timestamp: Int64) throws  -> UInt64
+    
+    func rebuild(newConfig: HnswIndexConfig, listener: ProgressListener, token: CancellationToken?) throws  -> HnswIndex
+    
+    func reloadIfChanged() throws  -> Bool
+    
+    func remainingCapacity() throws  -> UInt64
+    
+    func removeBatch(ids: [UInt64]) throws  -> UInt64
+    
+    func repairGraph(maxWork: UInt64) throws  -> UInt64
+    
+    func reserve(additional: UInt64) throws 
+    
+    func residencyStats() throws  -> ResidencyStats
+    
+    func save(directory: String, basename: String) throws 
+    
+    func saveBundle(path: String) throws 
+    
+    func saveCancellable(directory: String, basename: String, token: CancellationToken) throws 
+    
+    func saveUsearch(path: String) throws 
+    
+    func saveWithQos(directory: String, basename: String, qos: ThreadQos) throws 
+    
+    func search(query: [Float], k: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    
+    func searchAuto(query: [Float], k: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    
+    func searchBatchFlat(queries: [Float], numQueries: UInt32, k: UInt32, efSearch: UInt32) throws  -> FlatSearchResults
+    
+    func searchBounded(query: [Float], k: UInt32, efSearch: UInt32, limits: SearchLimits) throws  -> BoundedSearchResults
+    
+    func searchBq(query: [Float], k: UInt32, rerankFactor: UInt32) throws  -> [SearchResult]
+    
+    func searchCentroid(ids: [UInt64], k: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    
+    func searchDocuments(query: [Float], k: UInt32, efSearch: UInt32, aggregation: DocumentAggregation) throws  -> [DocumentSearchResult]
+    
+    func searchExact(query: [Float], k: UInt32) throws  -> [SearchResult]
+    
+    func searchExpression(positives: [[Float]], negatives: [[Float]], k: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    
+    func searchFiltered(query: [Float], k: UInt32, efSearch: UInt32, filter: String) throws  -> [SearchResult]
+    
+    func searchFlat(query: [Float], k: UInt32, efSearch: UInt32) throws  -> FlatSearchResults
+    
+    func searchGrouped(query: [Float], groupByAttr: String, groupsK: UInt32, perGroupK: UInt32, efSearch: UInt32) throws  -> [GroupSearchResult]
+    
+    func searchInNamespace(query: [Float], namespace: UInt32, k: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    
+    func searchKeyed(query: [Float], k: UInt32, efSearch: UInt32) throws  -> [KeyedSearchResult]
+    
+    func searchPage(query: [Float], k: UInt32, offset: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    
+    func searchSince(query: [Float], k: UInt32, efSearch: UInt32, minTimestamp: Int64) throws  -> [SearchResult]
+    
+    func searchText(text: String, k: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    
+    func searchWithOptions(query: [Float], k: UInt32, efSearch: UInt32, options: SearchOptions) throws  -> [SearchResult]
+    
+    func searchWithStats(query: [Float], k: UInt32, efSearch: UInt32) throws  -> SearchWithStats
+    
+    func setAttrs(id: UInt64, attrs: [String: AttrValue]) throws 
+    
+    func setAutoGrow(enabled: Bool) 
+    
+    func setBinarySketches(enabled: Bool) throws 
+    
+    func setDefaultEfSearch(efSearch: UInt32) 
+    
+    func setDimensionWeights(weights: [Float]?) throws 
+    
+    func setEmbedder(embedder: Embedder?) 
+    
+    func setExtendCandidates(enabled: Bool) throws 
+    
+    func setHidden(id: UInt64, hidden: Bool) throws 
+    
+    func setKeepPruned(enabled: Bool) throws 
+    
+    func setMaxWaitingSearches(limit: UInt32) 
+    
+    func setMemoryPressureHandler(enabled: Bool) 
+    
+    func setObserver(observer: IndexObserver?) throws 
+    
+    func setPayload(id: UInt64, payload: Data?) throws 
+    
+    func setPrivacyEpsilon(epsilon: Double?) throws 
+    
+    func setQueryTransform(matrix: [[Float]], bias: [Float]) throws 
+    
+    func setSearchingMode(enabled: Bool) throws 
+    
+    func setTimestamp(id: UInt64, timestamp: Int64?) throws 
+    
+    func shrinkToFit() throws 
+    
+    func toBytes() throws  -> Data
+    
+    func trySearch(query: [Float], k: UInt32, efSearch: UInt32, timeoutMs: UInt32) throws  -> [SearchResult]
+    
+    func tuneEfSearch(sampleQueries: [[Float]], k: UInt32, targetRecall: Float, apply: Bool) throws  -> UInt32
+    
+    func verifyIntegrity(deep: Bool) throws  -> IntegrityReport
+    
+}
+open class HnswIndex: HnswIndexProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_hnswindex(self.handle, $0) }
+    }
+public convenience init(config: HnswIndexConfig) {
+    let handle =
+        try! rustCall() {
+    uniffi_hnsw_fn_constructor_hnswindex_new(
+        FfiConverterTypeHnswIndexConfig_lower(config),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_hnswindex(handle, $0) }
+    }
+
+    
+public static func fromBytes(bytes: Data)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswindex_from_bytes(
+        FfiConverterData.lower(bytes),$0
+    )
+})
+}
+    
+public static func fromHnswlib(path: String, dimension: UInt32, distanceType: DistanceType)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswindex_from_hnswlib(
+        FfiConverterString.lower(path),
+        FfiConverterUInt32.lower(dimension),
+        FfiConverterTypeDistanceType_lower(distanceType),$0
+    )
+})
+}
+    
+public static func load(directory: String, basename: String, config: HnswIndexConfig)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswindex_load(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),
+        FfiConverterTypeHnswIndexConfig_lower(config),$0
+    )
+})
+}
+    
+public static func loadBundle(path: String)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswindex_load_bundle(
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+public static func loadCancellable(directory: String, basename: String, config: HnswIndexConfig, token: CancellationToken)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswindex_load_cancellable(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),
+        FfiConverterTypeHnswIndexConfig_lower(config),
+        FfiConverterTypeCancellationToken_lower(token),$0
+    )
+})
+}
+    
+public static func loadLazy(directory: String, basename: String, config: HnswIndexConfig, mmap: Bool)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswindex_load_lazy(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),
+        FfiConverterTypeHnswIndexConfig_lower(config),
+        FfiConverterBool.lower(mmap),$0
+    )
+})
+}
+    
+public static func loadReadOnly(directory: String, basename: String, config: HnswIndexConfig)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswindex_load_read_only(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),
+        FfiConverterTypeHnswIndexConfig_lower(config),$0
+    )
+})
+}
+    
+public static func loadResource(directory: String, basename: String, config: HnswIndexConfig, mmap: Bool)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswindex_load_resource(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),
+        FfiConverterTypeHnswIndexConfig_lower(config),
+        FfiConverterBool.lower(mmap),$0
+    )
+})
+}
+    
+public static func loadUsearch(path: String)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswindex_load_usearch(
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+public static func loadWithProgress(directory: String, basename: String, config: HnswIndexConfig, listener: ProgressListener, token: CancellationToken?)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswindex_load_with_progress(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),
+        FfiConverterTypeHnswIndexConfig_lower(config),
+        FfiConverterCallbackInterfaceProgressListener_lower(listener),
+        FfiConverterOptionTypeCancellationToken.lower(token),$0
+    )
+})
+}
+    
+public static func newWithReducer(config: HnswIndexConfig, reducer: DimReducer)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswindex_new_with_reducer(
+        FfiConverterTypeHnswIndexConfig_lower(config),
+        FfiConverterTypeDimReducer_lower(reducer),$0
+    )
+})
+}
+    
+
+    
+open func applyChanges(changes: Data)throws  -> ChangeApplyReport  {
+    return try  FfiConverterTypeChangeApplyReport_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_apply_changes(
+            self.uniffiCloneHandle(),
+        FfiConverterData.lower(changes),$0
+    )
+})
+}
+    
+open func centroid(ids: [UInt64])throws  -> [Float]  {
+    return try  FfiConverterSequenceFloat.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_centroid(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceUInt64.lower(ids),$0
+    )
+})
+}
+    
+open func changeVersion()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_change_version(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func clearQueryTransform()  {try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_clear_query_transform(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
+    
+open func compact(deletedIds: [UInt64], config: HnswIndexConfig)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_compact(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceUInt64.lower(deletedIds),
+        FfiConverterTypeHnswIndexConfig_lower(config),$0
+    )
+})
+}
+    
+open func contains(id: UInt64)throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_contains(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),$0
+    )
+})
+}
+    
+open func count()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_count(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func countFiltered(filter: String)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_count_filtered(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(filter),$0
+    )
+})
+}
+    
+open func countWithin(query: [Float], radius: Float, efSearch: UInt32)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_count_within(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterFloat.lower(radius),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func createView(distance: DistanceType) -> HnswIndexView  {
+    return try!  FfiConverterTypeHnswIndexView_lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_create_view(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeDistanceType_lower(distance),$0
+    )
+})
+}
+    
+open func destroy(directory: String, basename: String)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_destroy(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+})
+}
+    
+open func disableQueryCache()  {try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_disable_query_cache(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
+    
+open func enableQueryCache(capacity: UInt32)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_enable_query_cache(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(capacity),$0
+    )
+}
+}
+    
+open func evaluateRecall(queries: [[Float]], k: UInt32, efSearch: UInt32)throws  -> RecallReport  {
+    return try  FfiConverterTypeRecallReport_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_evaluate_recall(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(queries),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func evictVectors()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_evict_vectors(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func exportAll(path: String, format: ExportFormat)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_export_all(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(path),
+        FfiConverterTypeExportFormat_lower(format),$0
+    )
+})
+}
+    
+open func exportArrow(path: String)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_export_arrow(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(path),$0
+    )
+})
+}
+    
+open func exportChanges(sinceVersion: UInt64)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_export_changes(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(sinceVersion),$0
+    )
+})
+}
+    
+open func exportGraph(path: String, format: GraphFormat)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_export_graph(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(path),
+        FfiConverterTypeGraphFormat_lower(format),$0
+    )
+}
+}
+    
+open func findDuplicates(threshold: Float, batchSize: UInt32)throws  -> [DuplicatePair]  {
+    return try  FfiConverterSequenceTypeDuplicatePair.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_find_duplicates(
+            self.uniffiCloneHandle(),
+        FfiConverterFloat.lower(threshold),
+        FfiConverterUInt32.lower(batchSize),$0
+    )
+})
+}
+    
+open func getAttrs(id: UInt64)throws  -> [String: AttrValue]  {
+    return try  FfiConverterDictionaryStringTypeAttrValue.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_get_attrs(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),$0
+    )
+})
+}
+    
+open func getCapacity() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_get_capacity(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getDefaultEfSearch() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_get_default_ef_search(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getDimension() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_get_dimension(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getDimensionWeights() -> [Float]?  {
+    return try!  FfiConverterOptionSequenceFloat.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_get_dimension_weights(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getDistance() -> DistanceType  {
+    return try!  FfiConverterTypeDistanceType_lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_get_distance(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getDocumentChunks(documentId: UInt64)throws  -> [UInt64]  {
+    return try  FfiConverterSequenceUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_get_document_chunks(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(documentId),$0
+    )
+})
+}
+    
+open func getDocumentId(id: UInt64)throws  -> UInt64?  {
+    return try  FfiConverterOptionUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_get_document_id(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),$0
+    )
+})
+}
+    
+open func getEntryPoint()throws  -> UInt64?  {
+    return try  FfiConverterOptionUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_get_entry_point(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getExtendCandidates() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_get_extend_candidates(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getIdForKey(key: String)throws  -> UInt64?  {
+    return try  FfiConverterOptionUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_get_id_for_key(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(key),$0
+    )
+})
+}
+    
+open func getKeepPruned() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_get_keep_pruned(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getKey(id: UInt64)throws  -> String?  {
+    return try  FfiConverterOptionString.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_get_key(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),$0
+    )
+})
+}
+    
+open func getMaxLayer()throws  -> UInt32  {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_get_max_layer(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getNamespace(id: UInt64)throws  -> UInt32?  {
+    return try  FfiConverterOptionUInt32.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_get_namespace(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),$0
+    )
+})
+}
+    
+open func getPayload(id: UInt64)throws  -> Data?  {
+    return try  FfiConverterOptionData.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_get_payload(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),$0
+    )
+})
+}
+    
+open func getPointData(id: UInt64)throws  -> [Float]?  {
+    return try  FfiConverterOptionSequenceFloat.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_get_point_data(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),$0
+    )
+})
+}
+    
+open func getPrivacyEpsilon() -> Double?  {
+    return try!  FfiConverterOptionDouble.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_get_privacy_epsilon(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getReducer() -> DimReducer?  {
+    return try!  FfiConverterOptionTypeDimReducer.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_get_reducer(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func getTimestamp(id: UInt64)throws  -> Int64?  {
+    return try  FfiConverterOptionInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_get_timestamp(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),$0
+    )
+})
+}
+    
+open func graphNeighbors(id: UInt64, layer: UInt32)throws  -> [UInt64]  {
+    return try  FfiConverterSequenceUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_graph_neighbors(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),
+        FfiConverterUInt32.lower(layer),$0
+    )
+})
+}
+    
+open func growTo(newMax: UInt64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_grow_to(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(newMax),$0
+    )
+}
+}
+    
+open func handleMemoryPressure(level: MemoryPressureLevel)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_handle_memory_pressure(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeMemoryPressureLevel_lower(level),$0
+    )
+})
+}
+    
+open func hasBinarySketches()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_has_binary_sketches(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func healthCheck()throws  -> HealthReport  {
+    return try  FfiConverterTypeHealthReport_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_health_check(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func hiddenIds()throws  -> [UInt64]  {
+    return try  FfiConverterSequenceUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_hidden_ids(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func importArrow(path: String, vectorColumn: String, idColumn: String)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_import_arrow(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(vectorColumn),
+        FfiConverterString.lower(idColumn),$0
+    )
+})
+}
+    
+open func importFaissFlat(path: String, listener: ProgressListener)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_import_faiss_flat(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(path),
+        FfiConverterCallbackInterfaceProgressListener_lower(listener),$0
+    )
+})
+}
+    
+open func importJsonl(path: String, vectorField: String, idField: String, payloadFields: [String])throws  -> JsonlImportReport  {
+    return try  FfiConverterTypeJsonlImportReport_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_import_jsonl(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(vectorField),
+        FfiConverterString.lower(idField),
+        FfiConverterSequenceString.lower(payloadFields),$0
+    )
+})
+}
+    
+open func importNpy(path: String, idOffset: UInt64)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_import_npy(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(path),
+        FfiConverterUInt64.lower(idOffset),$0
+    )
+})
+}
+    
+open func importNpz(path: String, vectorsName: String, idsName: String?, idOffset: UInt64)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_import_npz(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(path),
+        FfiConverterString.lower(vectorsName),
+        FfiConverterOptionString.lower(idsName),
+        FfiConverterUInt64.lower(idOffset),$0
+    )
+})
+}
+    
+open func insert(data: [Float], id: UInt64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(data),
+        FfiConverterUInt64.lower(id),$0
+    )
+}
+}
+    
+open func insertBatch(data: [[Float]], ids: [UInt64])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_batch(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(data),
+        FfiConverterSequenceUInt64.lower(ids),$0
+    )
+}
+}
+    
+open func insertBatchCancellable(data: [[Float]], ids: [UInt64], token: CancellationToken)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_batch_cancellable(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(data),
+        FfiConverterSequenceUInt64.lower(ids),
+        FfiConverterTypeCancellationToken_lower(token),$0
+    )
+}
+}
+    
+open func insertBatchFlat(data: [Float], ids: [UInt64])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_batch_flat(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(data),
+        FfiConverterSequenceUInt64.lower(ids),$0
+    )
+}
+}
+    
+open func insertBatchKeyed(data: [[Float]], keys: [String])throws  -> [UInt64]  {
+    return try  FfiConverterSequenceUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_batch_keyed(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(data),
+        FfiConverterSequenceString.lower(keys),$0
+    )
+})
+}
+    
+open func insertBatchSerial(data: [[Float]], ids: [UInt64])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_batch_serial(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(data),
+        FfiConverterSequenceUInt64.lower(ids),$0
+    )
+}
+}
+    
+open func insertBatchWithNamespace(data: [[Float]], ids: [UInt64], namespace: UInt32)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_batch_with_namespace(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(data),
+        FfiConverterSequenceUInt64.lower(ids),
+        FfiConverterUInt32.lower(namespace),$0
+    )
+}
+}
+    
+open func insertBatchWithProgress(data: [[Float]], ids: [UInt64], listener: ProgressListener)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_batch_with_progress(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(data),
+        FfiConverterSequenceUInt64.lower(ids),
+        FfiConverterCallbackInterfaceProgressListener_lower(listener),$0
+    )
+}
+}
+    
+open func insertBatchWithQos(data: [[Float]], ids: [UInt64], qos: ThreadQos)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_batch_with_qos(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(data),
+        FfiConverterSequenceUInt64.lower(ids),
+        FfiConverterTypeThreadQos_lower(qos),$0
+    )
+}
+}
+    
+open func insertDocument(documentId: UInt64, chunks: [[Float]])throws  -> [UInt64]  {
+    return try  FfiConverterSequenceUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_document(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(documentId),
+        FfiConverterSequenceSequenceFloat.lower(chunks),$0
+    )
+})
+}
+    
+open func insertKeyed(data: [Float], key: String)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_keyed(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(data),
+        FfiConverterString.lower(key),$0
+    )
+})
+}
+    
+open func insertSession(chunkSize: UInt32)throws  -> InsertSession  {
+    return try  FfiConverterTypeInsertSession_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_session(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(chunkSize),$0
+    )
+})
+}
+    
+open func insertText(text: String, id: UInt64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_text(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(text),
+        FfiConverterUInt64.lower(id),$0
+    )
+}
+}
+    
+open func insertTexts(texts: [String], ids: [UInt64])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_texts(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceString.lower(texts),
+        FfiConverterSequenceUInt64.lower(ids),$0
+    )
+}
+}
+    
+open func insertWithAttrs(data: [Float], id: UInt64, attrs: [String: AttrValue])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_with_attrs(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(data),
+        FfiConverterUInt64.lower(id),
+        FfiConverterDictionaryStringTypeAttrValue.lower(attrs),$0
+    )
+}
+}
+    
+open func insertWithNamespace(data: [Float], id: UInt64, namespace: UInt32)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_with_namespace(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(data),
+        FfiConverterUInt64.lower(id),
+        FfiConverterUInt32.lower(namespace),$0
+    )
+}
+}
+    
+open func insertWithPayload(data: [Float], id: UInt64, payload: Data)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_with_payload(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(data),
+        FfiConverterUInt64.lower(id),
+        FfiConverterData.lower(payload),$0
+    )
+}
+}
+    
+open func insertWithTimestamp(data: [Float], id: UInt64, timestamp: Int64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_insert_with_timestamp(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(data),
+        FfiConverterUInt64.lower(id),
+        FfiConverterInt64.lower(timestamp),$0
+    )
+}
+}
+    
+open func isEmpty()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_is_empty(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func isHidden(id: UInt64)throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_is_hidden(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),$0
+    )
+})
+}
+    
+open func isLoaded()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_is_loaded(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func isMmapped() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_is_mmapped(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func isReadOnly() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_is_read_only(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func kmeans(k: UInt32, maxIters: UInt32, useGraph: Bool)throws  -> [ClusterAssignment]  {
+    return try  FfiConverterSequenceTypeClusterAssignment.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_kmeans(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(maxIters),
+        FfiConverterBool.lower(useGraph),$0
+    )
+})
+}
+    
+open func len()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_len(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func maintain(budgetMs: UInt64)throws  -> MaintenanceReport  {
+    return try  FfiConverterTypeMaintenanceReport_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_maintain(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(budgetMs),$0
+    )
+})
+}
+    
+open func memoryFootprint()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_memory_footprint(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func migrate(embedder: MigrationEmbedder, newDimension: UInt32, newConfig: HnswIndexConfig, directory: String, basename: String, listener: ProgressListener, token: CancellationToken?)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_migrate(
+            self.uniffiCloneHandle(),
+        FfiConverterCallbackInterfaceMigrationEmbedder_lower(embedder),
+        FfiConverterUInt32.lower(newDimension),
+        FfiConverterTypeHnswIndexConfig_lower(newConfig),
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),
+        FfiConverterCallbackInterfaceProgressListener_lower(listener),
+        FfiConverterOptionTypeCancellationToken.lower(token),$0
+    )
+})
+}
+    
+open func purgeOlderThan(timestamp: Int64)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_purge_older_than(
+            self.uniffiCloneHandle(),
+        FfiConverterInt64.lower(timestamp),$0
+    )
+})
+}
+    
+open func rebuild(newConfig: HnswIndexConfig, listener: ProgressListener, token: CancellationToken?)throws  -> HnswIndex  {
+    return try  FfiConverterTypeHnswIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_rebuild(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeHnswIndexConfig_lower(newConfig),
+        FfiConverterCallbackInterfaceProgressListener_lower(listener),
+        FfiConverterOptionTypeCancellationToken.lower(token),$0
+    )
+})
+}
+    
+open func reloadIfChanged()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_reload_if_changed(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func remainingCapacity()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_remaining_capacity(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func removeBatch(ids: [UInt64])throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_remove_batch(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceUInt64.lower(ids),$0
+    )
+})
+}
+    
+open func repairGraph(maxWork: UInt64)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_repair_graph(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(maxWork),$0
+    )
+})
+}
+    
+open func reserve(additional: UInt64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_reserve(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(additional),$0
+    )
+}
+}
+    
+open func residencyStats()throws  -> ResidencyStats  {
+    return try  FfiConverterTypeResidencyStats_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_residency_stats(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func save(directory: String, basename: String)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_save(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+}
+}
+    
+open func saveBundle(path: String)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_save_bundle(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(path),$0
+    )
+}
+}
+    
+open func saveCancellable(directory: String, basename: String, token: CancellationToken)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_save_cancellable(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),
+        FfiConverterTypeCancellationToken_lower(token),$0
+    )
+}
+}
+    
+open func saveUsearch(path: String)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_save_usearch(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(path),$0
+    )
+}
+}
+    
+open func saveWithQos(directory: String, basename: String, qos: ThreadQos)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_save_with_qos(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),
+        FfiConverterTypeThreadQos_lower(qos),$0
+    )
+}
+}
+    
+open func search(query: [Float], k: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func searchAuto(query: [Float], k: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_auto(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func searchBatchFlat(queries: [Float], numQueries: UInt32, k: UInt32, efSearch: UInt32)throws  -> FlatSearchResults  {
+    return try  FfiConverterTypeFlatSearchResults_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_batch_flat(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(queries),
+        FfiConverterUInt32.lower(numQueries),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func searchBounded(query: [Float], k: UInt32, efSearch: UInt32, limits: SearchLimits)throws  -> BoundedSearchResults  {
+    return try  FfiConverterTypeBoundedSearchResults_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_bounded(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),
+        FfiConverterTypeSearchLimits_lower(limits),$0
+    )
+})
+}
+    
+open func searchBq(query: [Float], k: UInt32, rerankFactor: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_bq(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(rerankFactor),$0
+    )
+})
+}
+    
+open func searchCentroid(ids: [UInt64], k: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_centroid(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceUInt64.lower(ids),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func searchDocuments(query: [Float], k: UInt32, efSearch: UInt32, aggregation: DocumentAggregation)throws  -> [DocumentSearchResult]  {
+    return try  FfiConverterSequenceTypeDocumentSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_documents(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),
+        FfiConverterTypeDocumentAggregation_lower(aggregation),$0
+    )
+})
+}
+    
+open func searchExact(query: [Float], k: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_exact(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),$0
+    )
+})
+}
+    
+open func searchExpression(positives: [[Float]], negatives: [[Float]], k: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_expression(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(positives),
+        FfiConverterSequenceSequenceFloat.lower(negatives),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func searchFiltered(query: [Float], k: UInt32, efSearch: UInt32, filter: String)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_filtered(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),
+        FfiConverterString.lower(filter),$0
+    )
+})
+}
+    
+open func searchFlat(query: [Float], k: UInt32, efSearch: UInt32)throws  -> FlatSearchResults  {
+    return try  FfiConverterTypeFlatSearchResults_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_flat(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func searchGrouped(query: [Float], groupByAttr: String, groupsK: UInt32, perGroupK: UInt32, efSearch: UInt32)throws  -> [GroupSearchResult]  {
+    return try  FfiConverterSequenceTypeGroupSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_grouped(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterString.lower(groupByAttr),
+        FfiConverterUInt32.lower(groupsK),
+        FfiConverterUInt32.lower(perGroupK),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func searchInNamespace(query: [Float], namespace: UInt32, k: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_in_namespace(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(namespace),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func searchKeyed(query: [Float], k: UInt32, efSearch: UInt32)throws  -> [KeyedSearchResult]  {
+    return try  FfiConverterSequenceTypeKeyedSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_keyed(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func searchPage(query: [Float], k: UInt32, offset: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_page(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(offset),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func searchSince(query: [Float], k: UInt32, efSearch: UInt32, minTimestamp: Int64)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_since(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),
+        FfiConverterInt64.lower(minTimestamp),$0
+    )
+})
+}
+    
+open func searchText(text: String, k: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_text(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(text),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func searchWithOptions(query: [Float], k: UInt32, efSearch: UInt32, options: SearchOptions)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_with_options(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),
+        FfiConverterTypeSearchOptions_lower(options),$0
+    )
+})
+}
+    
+open func searchWithStats(query: [Float], k: UInt32, efSearch: UInt32)throws  -> SearchWithStats  {
+    return try  FfiConverterTypeSearchWithStats_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_search_with_stats(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func setAttrs(id: UInt64, attrs: [String: AttrValue])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_set_attrs(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),
+        FfiConverterDictionaryStringTypeAttrValue.lower(attrs),$0
+    )
+}
+}
+    
+open func setAutoGrow(enabled: Bool)  {try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_set_auto_grow(
+            self.uniffiCloneHandle(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setBinarySketches(enabled: Bool)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_set_binary_sketches(
+            self.uniffiCloneHandle(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setDefaultEfSearch(efSearch: UInt32)  {try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_set_default_ef_search(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+}
+}
+    
+open func setDimensionWeights(weights: [Float]?)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_set_dimension_weights(
+            self.uniffiCloneHandle(),
+        FfiConverterOptionSequenceFloat.lower(weights),$0
+    )
+}
+}
+    
+open func setEmbedder(embedder: Embedder?)  {try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_set_embedder(
+            self.uniffiCloneHandle(),
+        FfiConverterOptionCallbackInterfaceEmbedder.lower(embedder),$0
+    )
+}
+}
+    
+open func setExtendCandidates(enabled: Bool)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_set_extend_candidates(
+            self.uniffiCloneHandle(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setHidden(id: UInt64, hidden: Bool)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_set_hidden(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),
+        FfiConverterBool.lower(hidden),$0
+    )
+}
+}
+    
+open func setKeepPruned(enabled: Bool)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_set_keep_pruned(
+            self.uniffiCloneHandle(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setMaxWaitingSearches(limit: UInt32)  {try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_set_max_waiting_searches(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(limit),$0
+    )
+}
+}
+    
+open func setMemoryPressureHandler(enabled: Bool)  {try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindex_set_memory_pressure_handler(
+            self.uniffiCloneHandle(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setObserver(observer: IndexObserver?)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_set_observer(
+            self.uniffiCloneHandle(),
+        FfiConverterOptionCallbackInterfaceIndexObserver.lower(observer),$0
+    )
+}
+}
+    
+open func setPayload(id: UInt64, payload: Data?)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_set_payload(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),
+        FfiConverterOptionData.lower(payload),$0
+    )
+}
+}
+    
+open func setPrivacyEpsilon(epsilon: Double?)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_set_privacy_epsilon(
+            self.uniffiCloneHandle(),
+        FfiConverterOptionDouble.lower(epsilon),$0
+    )
+}
+}
+    
+open func setQueryTransform(matrix: [[Float]], bias: [Float])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_set_query_transform(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(matrix),
+        FfiConverterSequenceFloat.lower(bias),$0
+    )
+}
+}
+    
+open func setSearchingMode(enabled: Bool)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_set_searching_mode(
+            self.uniffiCloneHandle(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setTimestamp(id: UInt64, timestamp: Int64?)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_set_timestamp(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),
+        FfiConverterOptionInt64.lower(timestamp),$0
+    )
+}
+}
+    
+open func shrinkToFit()throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_shrink_to_fit(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
+    
+open func toBytes()throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_to_bytes(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func trySearch(query: [Float], k: UInt32, efSearch: UInt32, timeoutMs: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_try_search(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),
+        FfiConverterUInt32.lower(timeoutMs),$0
+    )
+})
+}
+    
+open func tuneEfSearch(sampleQueries: [[Float]], k: UInt32, targetRecall: Float, apply: Bool)throws  -> UInt32  {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_tune_ef_search(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(sampleQueries),
+        FfiConverterUInt32.lower(k),
+        FfiConverterFloat.lower(targetRecall),
+        FfiConverterBool.lower(apply),$0
+    )
+})
+}
+    
+open func verifyIntegrity(deep: Bool)throws  -> IntegrityReport  {
+    return try  FfiConverterTypeIntegrityReport_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindex_verify_integrity(
+            self.uniffiCloneHandle(),
+        FfiConverterBool.lower(deep),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHnswIndex: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = HnswIndex
+
+    public static func lift(_ handle: UInt64) throws -> HnswIndex {
+        return HnswIndex(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: HnswIndex) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswIndex {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: HnswIndex, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswIndex_lift(_ handle: UInt64) throws -> HnswIndex {
+    return try FfiConverterTypeHnswIndex.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswIndex_lower(_ value: HnswIndex) -> UInt64 {
+    return FfiConverterTypeHnswIndex.lower(value)
+}
+
+
+
+
+
+
+public protocol HnswIndexViewProtocol: AnyObject, Sendable {
+    
+    func distance()  -> DistanceType
+    
+    func search(query: [Float], k: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    
+}
+open class HnswIndexView: HnswIndexViewProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_hnswindexview(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_hnswindexview(handle, $0) }
+    }
+
+    
+
+    
+open func distance() -> DistanceType  {
+    return try!  FfiConverterTypeDistanceType_lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswindexview_distance(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func search(query: [Float], k: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswindexview_search(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHnswIndexView: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = HnswIndexView
+
+    public static func lift(_ handle: UInt64) throws -> HnswIndexView {
+        return HnswIndexView(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: HnswIndexView) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswIndexView {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: HnswIndexView, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswIndexView_lift(_ handle: UInt64) throws -> HnswIndexView {
+    return try FfiConverterTypeHnswIndexView.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswIndexView_lower(_ value: HnswIndexView) -> UInt64 {
+    return FfiConverterTypeHnswIndexView.lower(value)
+}
+
+
+
+
+
+
+public protocol HnswPqIndexProtocol: AnyObject, Sendable {
+    
+    func getDimension()  -> UInt32
+    
+    func insert(data: [Float], id: UInt64) throws 
+    
+    func insertBatch(data: [[Float]], ids: [UInt64]) throws 
+    
+    func isEmpty() throws  -> Bool
+    
+    func isTrained() throws  -> Bool
+    
+    func len() throws  -> UInt64
+    
+    func save(directory: String, basename: String) throws 
+    
+    func search(query: [Float], k: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    
+    func trainPq(sampleVectors: [[Float]], m: UInt32, nbits: UInt32) throws 
+    
+}
+open class HnswPqIndex: HnswPqIndexProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_hnswpqindex(self.handle, $0) }
+    }
+public convenience init(config: HnswIndexConfig) {
+    let handle =
+        try! rustCall() {
+    uniffi_hnsw_fn_constructor_hnswpqindex_new(
+        FfiConverterTypeHnswIndexConfig_lower(config),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_hnswpqindex(handle, $0) }
+    }
+
+    
+public static func load(directory: String, basename: String)throws  -> HnswPqIndex  {
+    return try  FfiConverterTypeHnswPqIndex_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswpqindex_load(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+})
+}
+    
+
+    
+open func getDimension() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswpqindex_get_dimension(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func insert(data: [Float], id: UInt64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswpqindex_insert(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(data),
+        FfiConverterUInt64.lower(id),$0
+    )
+}
+}
+    
+open func insertBatch(data: [[Float]], ids: [UInt64])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswpqindex_insert_batch(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(data),
+        FfiConverterSequenceUInt64.lower(ids),$0
+    )
+}
+}
+    
+open func isEmpty()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswpqindex_is_empty(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func isTrained()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswpqindex_is_trained(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func len()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswpqindex_len(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func save(directory: String, basename: String)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswpqindex_save(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+}
+}
+    
+open func search(query: [Float], k: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswpqindex_search(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+open func trainPq(sampleVectors: [[Float]], m: UInt32, nbits: UInt32)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswpqindex_train_pq(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(sampleVectors),
+        FfiConverterUInt32.lower(m),
+        FfiConverterUInt32.lower(nbits),$0
+    )
+}
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHnswPqIndex: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = HnswPqIndex
+
+    public static func lift(_ handle: UInt64) throws -> HnswPqIndex {
+        return HnswPqIndex(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: HnswPqIndex) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswPqIndex {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: HnswPqIndex, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswPqIndex_lift(_ handle: UInt64) throws -> HnswPqIndex {
+    return try FfiConverterTypeHnswPqIndex.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswPqIndex_lower(_ value: HnswPqIndex) -> UInt64 {
+    return FfiConverterTypeHnswPqIndex.lower(value)
+}
+
+
+
+
+
+
+public protocol HnswSq8IndexProtocol: AnyObject, Sendable {
+    
+    func getDimension()  -> UInt32
+    
+    func insert(data: [Float], id: UInt64) throws 
+    
+    func insertBatch(data: [[Float]], ids: [UInt64]) throws 
+    
+    func isEmpty() throws  -> Bool
+    
+    func isTrained() throws  -> Bool
+    
+    func len() throws  -> UInt64
+    
+    func save(directory: String, basename: String) throws 
+    
+    func search(query: [Float], k: UInt32, efSearch: UInt32, rerank: Bool) throws  -> [SearchResult]
+    
+    func train(samples: [[Float]]) throws 
+    
+}
+open class HnswSq8Index: HnswSq8IndexProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_hnswsq8index(self.handle, $0) }
+    }
+public convenience init(config: HnswIndexConfig, trainingSize: UInt64, keepOriginals: Bool) {
+    let handle =
+        try! rustCall() {
+    uniffi_hnsw_fn_constructor_hnswsq8index_new(
+        FfiConverterTypeHnswIndexConfig_lower(config),
+        FfiConverterUInt64.lower(trainingSize),
+        FfiConverterBool.lower(keepOriginals),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_hnswsq8index(handle, $0) }
+    }
+
+    
+public static func load(directory: String, basename: String)throws  -> HnswSq8Index  {
+    return try  FfiConverterTypeHnswSq8Index_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswsq8index_load(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+})
+}
+    
+
+    
+open func getDimension() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswsq8index_get_dimension(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func insert(data: [Float], id: UInt64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswsq8index_insert(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(data),
+        FfiConverterUInt64.lower(id),$0
+    )
+}
+}
+    
+open func insertBatch(data: [[Float]], ids: [UInt64])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswsq8index_insert_batch(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(data),
+        FfiConverterSequenceUInt64.lower(ids),$0
+    )
+}
+}
+    
+open func isEmpty()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswsq8index_is_empty(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func isTrained()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswsq8index_is_trained(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func len()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswsq8index_len(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func save(directory: String, basename: String)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswsq8index_save(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+}
+}
+    
+open func search(query: [Float], k: UInt32, efSearch: UInt32, rerank: Bool)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswsq8index_search(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),
+        FfiConverterBool.lower(rerank),$0
+    )
+})
+}
+    
+open func train(samples: [[Float]])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswsq8index_train(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceFloat.lower(samples),$0
+    )
+}
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHnswSq8Index: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = HnswSq8Index
+
+    public static func lift(_ handle: UInt64) throws -> HnswSq8Index {
+        return HnswSq8Index(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: HnswSq8Index) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswSq8Index {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: HnswSq8Index, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswSq8Index_lift(_ handle: UInt64) throws -> HnswSq8Index {
+    return try FfiConverterTypeHnswSq8Index.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswSq8Index_lower(_ value: HnswSq8Index) -> UInt64 {
+    return FfiConverterTypeHnswSq8Index.lower(value)
+}
+
+
+
+
+
+
+public protocol HnswU16IndexProtocol: AnyObject, Sendable {
+    
+    func getDimension()  -> UInt32
+    
+    func insert(data: [UInt16], id: UInt64) throws 
+    
+    func insertBatch(data: [[UInt16]], ids: [UInt64]) throws 
+    
+    func isEmpty() throws  -> Bool
+    
+    func len() throws  -> UInt64
+    
+    func save(directory: String, basename: String) throws 
+    
+    func search(query: [UInt16], k: UInt32, efSearch: UInt32) throws  -> [SearchResult]
+    
+}
+open class HnswU16Index: HnswU16IndexProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_hnswu16index(self.handle, $0) }
+    }
+public convenience init(config: HnswIndexConfig)throws  {
+    let handle =
+        try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswu16index_new(
+        FfiConverterTypeHnswIndexConfig_lower(config),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_hnswu16index(handle, $0) }
+    }
+
+    
+public static func load(directory: String, basename: String)throws  -> HnswU16Index  {
+    return try  FfiConverterTypeHnswU16Index_lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hnswu16index_load(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+})
+}
+    
+
+    
+open func getDimension() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_hnswu16index_get_dimension(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func insert(data: [UInt16], id: UInt64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswu16index_insert(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceUInt16.lower(data),
+        FfiConverterUInt64.lower(id),$0
+    )
+}
+}
+    
+open func insertBatch(data: [[UInt16]], ids: [UInt64])throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswu16index_insert_batch(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceSequenceUInt16.lower(data),
+        FfiConverterSequenceUInt64.lower(ids),$0
+    )
+}
+}
+    
+open func isEmpty()throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswu16index_is_empty(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func len()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswu16index_len(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func save(directory: String, basename: String)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswu16index_save(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+}
+}
+    
+open func search(query: [UInt16], k: UInt32, efSearch: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hnswu16index_search(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceUInt16.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHnswU16Index: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = HnswU16Index
+
+    public static func lift(_ handle: UInt64) throws -> HnswU16Index {
+        return HnswU16Index(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: HnswU16Index) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswU16Index {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: HnswU16Index, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswU16Index_lift(_ handle: UInt64) throws -> HnswU16Index {
+    return try FfiConverterTypeHnswU16Index.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswU16Index_lower(_ value: HnswU16Index) -> UInt64 {
+    return FfiConverterTypeHnswU16Index.lower(value)
+}
+
+
+
+
+
+
+public protocol HybridSearcherProtocol: AnyObject, Sendable {
+    
+    func hybridSearch(queryVector: [Float], queryTerms: [String], k: UInt32, alpha: Float) throws  -> [SearchResult]
+    
+    func refresh() throws 
+    
+    func reindex(id: UInt64) throws 
+    
+}
+open class HybridSearcher: HybridSearcherProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_hybridsearcher(self.handle, $0) }
+    }
+public convenience init(index: HnswIndex)throws  {
+    let handle =
+        try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_hybridsearcher_new(
+        FfiConverterTypeHnswIndex_lower(index),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_hybridsearcher(handle, $0) }
+    }
+
+    
+
+    
+open func hybridSearch(queryVector: [Float], queryTerms: [String], k: UInt32, alpha: Float)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hybridsearcher_hybrid_search(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(queryVector),
+        FfiConverterSequenceString.lower(queryTerms),
+        FfiConverterUInt32.lower(k),
+        FfiConverterFloat.lower(alpha),$0
+    )
+})
+}
+    
+open func refresh()throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hybridsearcher_refresh(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
+    
+open func reindex(id: UInt64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_hybridsearcher_reindex(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt64.lower(id),$0
+    )
+}
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHybridSearcher: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = HybridSearcher
+
+    public static func lift(_ handle: UInt64) throws -> HybridSearcher {
+        return HybridSearcher(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: HybridSearcher) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HybridSearcher {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: HybridSearcher, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHybridSearcher_lift(_ handle: UInt64) throws -> HybridSearcher {
+    return try FfiConverterTypeHybridSearcher.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHybridSearcher_lower(_ value: HybridSearcher) -> UInt64 {
+    return FfiConverterTypeHybridSearcher.lower(value)
+}
+
+
+
+
+
+
+public protocol InsertSessionProtocol: AnyObject, Sendable {
+    
+    func commit() throws  -> UInt64
+    
+    func pending() throws  -> UInt64
+    
+    func push(data: [Float], id: UInt64) throws 
+    
+}
+open class InsertSession: InsertSessionProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_insertsession(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_insertsession(handle, $0) }
+    }
+
+    
+
+    
+open func commit()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_insertsession_commit(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func pending()throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_insertsession_pending(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func push(data: [Float], id: UInt64)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_insertsession_push(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(data),
+        FfiConverterUInt64.lower(id),$0
+    )
+}
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeInsertSession: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = InsertSession
+
+    public static func lift(_ handle: UInt64) throws -> InsertSession {
+        return InsertSession(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: InsertSession) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InsertSession {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: InsertSession, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInsertSession_lift(_ handle: UInt64) throws -> InsertSession {
+    return try FfiConverterTypeInsertSession.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInsertSession_lower(_ value: InsertSession) -> UInt64 {
+    return FfiConverterTypeInsertSession.lower(value)
+}
+
+
+
+
+
+
+public protocol MultiIndexSearcherProtocol: AnyObject, Sendable {
+    
+    func indexCount()  -> UInt32
+    
+    func searchAll(query: [Float], k: UInt32, efSearch: UInt32) throws  -> [MultiSearchResult]
+    
+}
+open class MultiIndexSearcher: MultiIndexSearcherProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_multiindexsearcher(self.handle, $0) }
+    }
+public convenience init(indexes: [HnswIndex])throws  {
+    let handle =
+        try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_constructor_multiindexsearcher_new(
+        FfiConverterSequenceTypeHnswIndex.lower(indexes),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_multiindexsearcher(handle, $0) }
+    }
+
+    
+
+    
+open func indexCount() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_multiindexsearcher_index_count(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func searchAll(query: [Float], k: UInt32, efSearch: UInt32)throws  -> [MultiSearchResult]  {
+    return try  FfiConverterSequenceTypeMultiSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_multiindexsearcher_search_all(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMultiIndexSearcher: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = MultiIndexSearcher
+
+    public static func lift(_ handle: UInt64) throws -> MultiIndexSearcher {
+        return MultiIndexSearcher(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: MultiIndexSearcher) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MultiIndexSearcher {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: MultiIndexSearcher, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMultiIndexSearcher_lift(_ handle: UInt64) throws -> MultiIndexSearcher {
+    return try FfiConverterTypeMultiIndexSearcher.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMultiIndexSearcher_lower(_ value: MultiIndexSearcher) -> UInt64 {
+    return FfiConverterTypeMultiIndexSearcher.lower(value)
+}
+
+
+
+
+
+
+public protocol QuerySessionProtocol: AnyObject, Sendable {
+    
+    func reset() 
+    
+    func search(query: [Float], k: UInt32) throws  -> [SearchResult]
+    
+}
+open class QuerySession: QuerySessionProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_querysession(self.handle, $0) }
+    }
+public convenience init(index: HnswIndex, efSearch: UInt32) {
+    let handle =
+        try! rustCall() {
+    uniffi_hnsw_fn_constructor_querysession_new(
+        FfiConverterTypeHnswIndex_lower(index),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_querysession(handle, $0) }
+    }
+
+    
+
+    
+open func reset()  {try! rustCall() {
+    uniffi_hnsw_fn_method_querysession_reset(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
+    
+open func search(query: [Float], k: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_querysession_search(
+            self.uniffiCloneHandle(),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(k),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeQuerySession: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = QuerySession
+
+    public static func lift(_ handle: UInt64) throws -> QuerySession {
+        return QuerySession(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: QuerySession) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> QuerySession {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: QuerySession, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeQuerySession_lift(_ handle: UInt64) throws -> QuerySession {
+    return try FfiConverterTypeQuerySession.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeQuerySession_lower(_ value: QuerySession) -> UInt64 {
+    return FfiConverterTypeQuerySession.lower(value)
+}
+
+
+
+
+
+
+public protocol SearchCursorProtocol: AnyObject, Sendable {
+    
+    func isExhausted()  -> Bool
+    
+    func nextPage(k: UInt32) throws  -> [SearchResult]
+    
+    func position()  -> UInt64
+    
+}
+open class SearchCursor: SearchCursorProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_hnsw_fn_clone_searchcursor(self.handle, $0) }
+    }
+public convenience init(index: HnswIndex, query: [Float], efSearch: UInt32) {
+    let handle =
+        try! rustCall() {
+    uniffi_hnsw_fn_constructor_searchcursor_new(
+        FfiConverterTypeHnswIndex_lower(index),
+        FfiConverterSequenceFloat.lower(query),
+        FfiConverterUInt32.lower(efSearch),$0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        try! rustCall { uniffi_hnsw_fn_free_searchcursor(handle, $0) }
+    }
+
+    
+
+    
+open func isExhausted() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_searchcursor_is_exhausted(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+open func nextPage(k: UInt32)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_method_searchcursor_next_page(
+            self.uniffiCloneHandle(),
+        FfiConverterUInt32.lower(k),$0
+    )
+})
+}
+    
+open func position() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_hnsw_fn_method_searchcursor_position(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchCursor: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = SearchCursor
+
+    public static func lift(_ handle: UInt64) throws -> SearchCursor {
+        return SearchCursor(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: SearchCursor) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchCursor {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: SearchCursor, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchCursor_lift(_ handle: UInt64) throws -> SearchCursor {
+    return try FfiConverterTypeSearchCursor.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchCursor_lower(_ value: SearchCursor) -> UInt64 {
+    return FfiConverterTypeSearchCursor.lower(value)
+}
+
+
+
+
+public struct BoundedSearchResults: Equatable, Hashable {
+    public var results: [SearchResult]
+    public var truncated: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(results: [SearchResult], truncated: Bool) {
+        self.results = results
+        self.truncated = truncated
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension BoundedSearchResults: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBoundedSearchResults: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BoundedSearchResults {
+        return
+            try BoundedSearchResults(
+                results: FfiConverterSequenceTypeSearchResult.read(from: &buf), 
+                truncated: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: BoundedSearchResults, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeSearchResult.write(value.results, into: &buf)
+        FfiConverterBool.write(value.truncated, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBoundedSearchResults_lift(_ buf: RustBuffer) throws -> BoundedSearchResults {
+    return try FfiConverterTypeBoundedSearchResults.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBoundedSearchResults_lower(_ value: BoundedSearchResults) -> RustBuffer {
+    return FfiConverterTypeBoundedSearchResults.lower(value)
+}
+
+
+public struct ChangeApplyReport: Equatable, Hashable {
+    public var version: UInt64
+    public var inserted: UInt64
+    public var updated: UInt64
+    public var removed: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(version: UInt64, inserted: UInt64, updated: UInt64, removed: UInt64) {
+        self.version = version
+        self.inserted = inserted
+        self.updated = updated
+        self.removed = removed
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension ChangeApplyReport: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeChangeApplyReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ChangeApplyReport {
+        return
+            try ChangeApplyReport(
+                version: FfiConverterUInt64.read(from: &buf), 
+                inserted: FfiConverterUInt64.read(from: &buf), 
+                updated: FfiConverterUInt64.read(from: &buf), 
+                removed: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ChangeApplyReport, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.version, into: &buf)
+        FfiConverterUInt64.write(value.inserted, into: &buf)
+        FfiConverterUInt64.write(value.updated, into: &buf)
+        FfiConverterUInt64.write(value.removed, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChangeApplyReport_lift(_ buf: RustBuffer) throws -> ChangeApplyReport {
+    return try FfiConverterTypeChangeApplyReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChangeApplyReport_lower(_ value: ChangeApplyReport) -> RustBuffer {
+    return FfiConverterTypeChangeApplyReport.lower(value)
+}
+
+
+public struct ClusterAssignment: Equatable, Hashable {
+    public var id: UInt64
+    public var cluster: UInt32
+    public var distance: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: UInt64, cluster: UInt32, distance: Float) {
+        self.id = id
+        self.cluster = cluster
+        self.distance = distance
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension ClusterAssignment: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeClusterAssignment: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ClusterAssignment {
+        return
+            try ClusterAssignment(
+                id: FfiConverterUInt64.read(from: &buf), 
+                cluster: FfiConverterUInt32.read(from: &buf), 
+                distance: FfiConverterFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ClusterAssignment, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.id, into: &buf)
+        FfiConverterUInt32.write(value.cluster, into: &buf)
+        FfiConverterFloat.write(value.distance, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeClusterAssignment_lift(_ buf: RustBuffer) throws -> ClusterAssignment {
+    return try FfiConverterTypeClusterAssignment.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeClusterAssignment_lower(_ value: ClusterAssignment) -> RustBuffer {
+    return FfiConverterTypeClusterAssignment.lower(value)
+}
+
+
+public struct DocumentSearchResult: Equatable, Hashable {
+    public var documentId: UInt64
+    public var distance: Float
+    public var chunks: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(documentId: UInt64, distance: Float, chunks: UInt32) {
+        self.documentId = documentId
+        self.distance = distance
+        self.chunks = chunks
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension DocumentSearchResult: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDocumentSearchResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DocumentSearchResult {
+        return
+            try DocumentSearchResult(
+                documentId: FfiConverterUInt64.read(from: &buf), 
+                distance: FfiConverterFloat.read(from: &buf), 
+                chunks: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DocumentSearchResult, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.documentId, into: &buf)
+        FfiConverterFloat.write(value.distance, into: &buf)
+        FfiConverterUInt32.write(value.chunks, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDocumentSearchResult_lift(_ buf: RustBuffer) throws -> DocumentSearchResult {
+    return try FfiConverterTypeDocumentSearchResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDocumentSearchResult_lower(_ value: DocumentSearchResult) -> RustBuffer {
+    return FfiConverterTypeDocumentSearchResult.lower(value)
+}
+
+
+public struct DuplicatePair: Equatable, Hashable {
+    public var first: UInt64
+    public var second: UInt64
+    public var distance: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(first: UInt64, second: UInt64, distance: Float) {
+        self.first = first
+        self.second = second
+        self.distance = distance
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension DuplicatePair: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDuplicatePair: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DuplicatePair {
+        return
+            try DuplicatePair(
+                first: FfiConverterUInt64.read(from: &buf), 
+                second: FfiConverterUInt64.read(from: &buf), 
+                distance: FfiConverterFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DuplicatePair, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.first, into: &buf)
+        FfiConverterUInt64.write(value.second, into: &buf)
+        FfiConverterFloat.write(value.distance, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDuplicatePair_lift(_ buf: RustBuffer) throws -> DuplicatePair {
+    return try FfiConverterTypeDuplicatePair.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDuplicatePair_lower(_ value: DuplicatePair) -> RustBuffer {
+    return FfiConverterTypeDuplicatePair.lower(value)
+}
+
+
+public struct FlatSearchResults: Equatable, Hashable {
+    public var ids: [UInt64]
+    public var distances: [Float]
+    public var counts: [UInt32]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(ids: [UInt64], distances: [Float], counts: [UInt32]) {
+        self.ids = ids
+        self.distances = distances
+        self.counts = counts
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension FlatSearchResults: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFlatSearchResults: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FlatSearchResults {
+        return
+            try FlatSearchResults(
+                ids: FfiConverterSequenceUInt64.read(from: &buf), 
+                distances: FfiConverterSequenceFloat.read(from: &buf), 
+                counts: FfiConverterSequenceUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FlatSearchResults, into buf: inout [UInt8]) {
+        FfiConverterSequenceUInt64.write(value.ids, into: &buf)
+        FfiConverterSequenceFloat.write(value.distances, into: &buf)
+        FfiConverterSequenceUInt32.write(value.counts, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFlatSearchResults_lift(_ buf: RustBuffer) throws -> FlatSearchResults {
+    return try FfiConverterTypeFlatSearchResults.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFlatSearchResults_lower(_ value: FlatSearchResults) -> RustBuffer {
+    return FfiConverterTypeFlatSearchResults.lower(value)
+}
+
+
+public struct GroupSearchResult: Equatable, Hashable {
+    public var group: AttrValue
+    public var hits: [SearchResult]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(group: AttrValue, hits: [SearchResult]) {
+        self.group = group
+        self.hits = hits
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension GroupSearchResult: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGroupSearchResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GroupSearchResult {
+        return
+            try GroupSearchResult(
+                group: FfiConverterTypeAttrValue.read(from: &buf), 
+                hits: FfiConverterSequenceTypeSearchResult.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: GroupSearchResult, into buf: inout [UInt8]) {
+        FfiConverterTypeAttrValue.write(value.group, into: &buf)
+        FfiConverterSequenceTypeSearchResult.write(value.hits, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGroupSearchResult_lift(_ buf: RustBuffer) throws -> GroupSearchResult {
+    return try FfiConverterTypeGroupSearchResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGroupSearchResult_lower(_ value: GroupSearchResult) -> RustBuffer {
+    return FfiConverterTypeGroupSearchResult.lower(value)
+}
+
+
+public struct HealthReport: Equatable, Hashable {
+    public var usable: Bool
+    public var poisonedLocks: [String]
+    public var graphPoints: UInt64
+    public var knownIds: UInt64
+    public var loaded: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(usable: Bool, poisonedLocks: [String], graphPoints: UInt64, knownIds: UInt64, loaded: Bool) {
+        self.usable = usable
+        self.poisonedLocks = poisonedLocks
+        self.graphPoints = graphPoints
+        self.knownIds = knownIds
+        self.loaded = loaded
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension HealthReport: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHealthReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HealthReport {
+        return
+            try HealthReport(
+                usable: FfiConverterBool.read(from: &buf), 
+                poisonedLocks: FfiConverterSequenceString.read(from: &buf), 
+                graphPoints: FfiConverterUInt64.read(from: &buf), 
+                knownIds: FfiConverterUInt64.read(from: &buf), 
+                loaded: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HealthReport, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.usable, into: &buf)
+        FfiConverterSequenceString.write(value.poisonedLocks, into: &buf)
+        FfiConverterUInt64.write(value.graphPoints, into: &buf)
+        FfiConverterUInt64.write(value.knownIds, into: &buf)
+        FfiConverterBool.write(value.loaded, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHealthReport_lift(_ buf: RustBuffer) throws -> HealthReport {
+    return try FfiConverterTypeHealthReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHealthReport_lower(_ value: HealthReport) -> RustBuffer {
+    return FfiConverterTypeHealthReport.lower(value)
+}
+
+
+public struct HnswIndexConfig: Equatable, Hashable {
+    public var maxNbConnection: UInt32
+    public var maxElements: UInt64
+    public var maxLayer: UInt32
+    public var efConstruction: UInt32
+    public var dimension: UInt32
+    public var distance: DistanceType
+    public var normalizeVectors: Bool
+    public var levelScale: Double?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(maxNbConnection: UInt32, maxElements: UInt64, maxLayer: UInt32, efConstruction: UInt32, dimension: UInt32, distance: DistanceType, normalizeVectors: Bool = false, levelScale: Double? = nil) {
+        self.maxNbConnection = maxNbConnection
+        self.maxElements = maxElements
+        self.maxLayer = maxLayer
+        self.efConstruction = efConstruction
+        self.dimension = dimension
+        self.distance = distance
+        self.normalizeVectors = normalizeVectors
+        self.levelScale = levelScale
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension HnswIndexConfig: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHnswIndexConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswIndexConfig {
+        return
+            try HnswIndexConfig(
+                maxNbConnection: FfiConverterUInt32.read(from: &buf), 
+                maxElements: FfiConverterUInt64.read(from: &buf), 
+                maxLayer: FfiConverterUInt32.read(from: &buf), 
+                efConstruction: FfiConverterUInt32.read(from: &buf), 
+                dimension: FfiConverterUInt32.read(from: &buf), 
+                distance: FfiConverterTypeDistanceType.read(from: &buf), 
+                normalizeVectors: FfiConverterBool.read(from: &buf), 
+                levelScale: FfiConverterOptionDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HnswIndexConfig, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.maxNbConnection, into: &buf)
+        FfiConverterUInt64.write(value.maxElements, into: &buf)
+        FfiConverterUInt32.write(value.maxLayer, into: &buf)
+        FfiConverterUInt32.write(value.efConstruction, into: &buf)
+        FfiConverterUInt32.write(value.dimension, into: &buf)
+        FfiConverterTypeDistanceType.write(value.distance, into: &buf)
+        FfiConverterBool.write(value.normalizeVectors, into: &buf)
+        FfiConverterOptionDouble.write(value.levelScale, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswIndexConfig_lift(_ buf: RustBuffer) throws -> HnswIndexConfig {
+    return try FfiConverterTypeHnswIndexConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswIndexConfig_lower(_ value: HnswIndexConfig) -> RustBuffer {
+    return FfiConverterTypeHnswIndexConfig.lower(value)
+}
+
+
+public struct IntegrityReport: Equatable, Hashable {
+    public var ok: Bool
+    public var deep: Bool
+    public var graphPoints: UInt64
+    public var duplicateIds: [UInt64]
+    public var missingIds: [UInt64]
+    public var unlistedIds: [UInt64]
+    public var badVectors: [UInt64]
+    public var danglingLinks: UInt64
+    public var unreachableIds: [UInt64]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(ok: Bool, deep: Bool, graphPoints: UInt64, duplicateIds: [UInt64], missingIds: [UInt64], unlistedIds: [UInt64], badVectors: [UInt64], danglingLinks: UInt64, unreachableIds: [UInt64]) {
+        self.ok = ok
+        self.deep = deep
+        self.graphPoints = graphPoints
+        self.duplicateIds = duplicateIds
+        self.missingIds = missingIds
+        self.unlistedIds = unlistedIds
+        self.badVectors = badVectors
+        self.danglingLinks = danglingLinks
+        self.unreachableIds = unreachableIds
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension IntegrityReport: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeIntegrityReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IntegrityReport {
+        return
+            try IntegrityReport(
+                ok: FfiConverterBool.read(from: &buf), 
+                deep: FfiConverterBool.read(from: &buf), 
+                graphPoints: FfiConverterUInt64.read(from: &buf), 
+                duplicateIds: FfiConverterSequenceUInt64.read(from: &buf), 
+                missingIds: FfiConverterSequenceUInt64.read(from: &buf), 
+                unlistedIds: FfiConverterSequenceUInt64.read(from: &buf), 
+                badVectors: FfiConverterSequenceUInt64.read(from: &buf), 
+                danglingLinks: FfiConverterUInt64.read(from: &buf), 
+                unreachableIds: FfiConverterSequenceUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: IntegrityReport, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.ok, into: &buf)
+        FfiConverterBool.write(value.deep, into: &buf)
+        FfiConverterUInt64.write(value.graphPoints, into: &buf)
+        FfiConverterSequenceUInt64.write(value.duplicateIds, into: &buf)
+        FfiConverterSequenceUInt64.write(value.missingIds, into: &buf)
+        FfiConverterSequenceUInt64.write(value.unlistedIds, into: &buf)
+        FfiConverterSequenceUInt64.write(value.badVectors, into: &buf)
+        FfiConverterUInt64.write(value.danglingLinks, into: &buf)
+        FfiConverterSequenceUInt64.write(value.unreachableIds, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIntegrityReport_lift(_ buf: RustBuffer) throws -> IntegrityReport {
+    return try FfiConverterTypeIntegrityReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIntegrityReport_lower(_ value: IntegrityReport) -> RustBuffer {
+    return FfiConverterTypeIntegrityReport.lower(value)
+}
+
+
+public struct JsonlImportReport: Equatable, Hashable {
+    public var imported: UInt64
+    public var errors: [JsonlLineError]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(imported: UInt64, errors: [JsonlLineError]) {
+        self.imported = imported
+        self.errors = errors
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension JsonlImportReport: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeJsonlImportReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> JsonlImportReport {
+        return
+            try JsonlImportReport(
+                imported: FfiConverterUInt64.read(from: &buf), 
+                errors: FfiConverterSequenceTypeJsonlLineError.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: JsonlImportReport, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.imported, into: &buf)
+        FfiConverterSequenceTypeJsonlLineError.write(value.errors, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeJsonlImportReport_lift(_ buf: RustBuffer) throws -> JsonlImportReport {
+    return try FfiConverterTypeJsonlImportReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeJsonlImportReport_lower(_ value: JsonlImportReport) -> RustBuffer {
+    return FfiConverterTypeJsonlImportReport.lower(value)
+}
+
+
+public struct JsonlLineError: Equatable, Hashable {
+    public var line: UInt64
+    public var message: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(line: UInt64, message: String) {
+        self.line = line
+        self.message = message
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension JsonlLineError: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeJsonlLineError: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> JsonlLineError {
+        return
+            try JsonlLineError(
+                line: FfiConverterUInt64.read(from: &buf), 
+                message: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: JsonlLineError, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.line, into: &buf)
+        FfiConverterString.write(value.message, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeJsonlLineError_lift(_ buf: RustBuffer) throws -> JsonlLineError {
+    return try FfiConverterTypeJsonlLineError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeJsonlLineError_lower(_ value: JsonlLineError) -> RustBuffer {
+    return FfiConverterTypeJsonlLineError.lower(value)
+}
+
+
+public struct KeyedSearchResult: Equatable, Hashable {
+    public var key: String
+    public var distance: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(key: String, distance: Float) {
+        self.key = key
+        self.distance = distance
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension KeyedSearchResult: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeKeyedSearchResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> KeyedSearchResult {
+        return
+            try KeyedSearchResult(
+                key: FfiConverterString.read(from: &buf), 
+                distance: FfiConverterFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: KeyedSearchResult, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.key, into: &buf)
+        FfiConverterFloat.write(value.distance, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeKeyedSearchResult_lift(_ buf: RustBuffer) throws -> KeyedSearchResult {
+    return try FfiConverterTypeKeyedSearchResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeKeyedSearchResult_lower(_ value: KeyedSearchResult) -> RustBuffer {
+    return FfiConverterTypeKeyedSearchResult.lower(value)
+}
+
+
+public struct LogEvent: Equatable, Hashable {
+    public var level: LogLevel
+    public var event: String
+    public var durationUs: UInt64?
+    public var fields: [String: String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(level: LogLevel, event: String, durationUs: UInt64?, fields: [String: String]) {
+        self.level = level
+        self.event = event
+        self.durationUs = durationUs
+        self.fields = fields
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension LogEvent: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLogEvent: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LogEvent {
+        return
+            try LogEvent(
+                level: FfiConverterTypeLogLevel.read(from: &buf), 
+                event: FfiConverterString.read(from: &buf), 
+                durationUs: FfiConverterOptionUInt64.read(from: &buf), 
+                fields: FfiConverterDictionaryStringString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: LogEvent, into buf: inout [UInt8]) {
+        FfiConverterTypeLogLevel.write(value.level, into: &buf)
+        FfiConverterString.write(value.event, into: &buf)
+        FfiConverterOptionUInt64.write(value.durationUs, into: &buf)
+        FfiConverterDictionaryStringString.write(value.fields, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLogEvent_lift(_ buf: RustBuffer) throws -> LogEvent {
+    return try FfiConverterTypeLogEvent.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLogEvent_lower(_ value: LogEvent) -> RustBuffer {
+    return FfiConverterTypeLogEvent.lower(value)
+}
+
+
+public struct MaintenanceReport: Equatable, Hashable {
+    public var performed: [String]
+    public var finished: Bool
+    public var elapsedMs: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(performed: [String], finished: Bool, elapsedMs: UInt64) {
+        self.performed = performed
+        self.finished = finished
+        self.elapsedMs = elapsedMs
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension MaintenanceReport: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMaintenanceReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MaintenanceReport {
+        return
+            try MaintenanceReport(
+                performed: FfiConverterSequenceString.read(from: &buf), 
+                finished: FfiConverterBool.read(from: &buf), 
+                elapsedMs: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: MaintenanceReport, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.performed, into: &buf)
+        FfiConverterBool.write(value.finished, into: &buf)
+        FfiConverterUInt64.write(value.elapsedMs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMaintenanceReport_lift(_ buf: RustBuffer) throws -> MaintenanceReport {
+    return try FfiConverterTypeMaintenanceReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMaintenanceReport_lower(_ value: MaintenanceReport) -> RustBuffer {
+    return FfiConverterTypeMaintenanceReport.lower(value)
+}
+
+
+public struct MultiSearchResult: Equatable, Hashable {
+    public var index: UInt32
+    public var id: UInt64
+    public var distance: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(index: UInt32, id: UInt64, distance: Float) {
+        self.index = index
+        self.id = id
+        self.distance = distance
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension MultiSearchResult: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMultiSearchResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MultiSearchResult {
+        return
+            try MultiSearchResult(
+                index: FfiConverterUInt32.read(from: &buf), 
+                id: FfiConverterUInt64.read(from: &buf), 
+                distance: FfiConverterFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: MultiSearchResult, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.index, into: &buf)
+        FfiConverterUInt64.write(value.id, into: &buf)
+        FfiConverterFloat.write(value.distance, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMultiSearchResult_lift(_ buf: RustBuffer) throws -> MultiSearchResult {
+    return try FfiConverterTypeMultiSearchResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMultiSearchResult_lower(_ value: MultiSearchResult) -> RustBuffer {
+    return FfiConverterTypeMultiSearchResult.lower(value)
+}
+
+
+public struct RecallReport: Equatable, Hashable {
+    public var queryCount: UInt32
+    public var k: UInt32
+    public var efSearch: UInt32
+    public var recallAtK: Float
+    public var meanQueryLatencyUs: Double
+    public var meanExactLatencyUs: Double
+    public var meanDistanceError: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(queryCount: UInt32, k: UInt32, efSearch: UInt32, recallAtK: Float, meanQueryLatencyUs: Double, meanExactLatencyUs: Double, meanDistanceError: Float) {
+        self.queryCount = queryCount
+        self.k = k
+        self.efSearch = efSearch
+        self.recallAtK = recallAtK
+        self.meanQueryLatencyUs = meanQueryLatencyUs
+        self.meanExactLatencyUs = meanExactLatencyUs
+        self.meanDistanceError = meanDistanceError
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension RecallReport: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRecallReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RecallReport {
+        return
+            try RecallReport(
+                queryCount: FfiConverterUInt32.read(from: &buf), 
+                k: FfiConverterUInt32.read(from: &buf), 
+                efSearch: FfiConverterUInt32.read(from: &buf), 
+                recallAtK: FfiConverterFloat.read(from: &buf), 
+                meanQueryLatencyUs: FfiConverterDouble.read(from: &buf), 
+                meanExactLatencyUs: FfiConverterDouble.read(from: &buf), 
+                meanDistanceError: FfiConverterFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RecallReport, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.queryCount, into: &buf)
+        FfiConverterUInt32.write(value.k, into: &buf)
+        FfiConverterUInt32.write(value.efSearch, into: &buf)
+        FfiConverterFloat.write(value.recallAtK, into: &buf)
+        FfiConverterDouble.write(value.meanQueryLatencyUs, into: &buf)
+        FfiConverterDouble.write(value.meanExactLatencyUs, into: &buf)
+        FfiConverterFloat.write(value.meanDistanceError, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecallReport_lift(_ buf: RustBuffer) throws -> RecallReport {
+    return try FfiConverterTypeRecallReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecallReport_lower(_ value: RecallReport) -> RustBuffer {
+    return FfiConverterTypeRecallReport.lower(value)
+}
+
+
+public struct ResidencyStats: Equatable, Hashable {
+    public var mmapped: Bool
+    public var fileBytes: UInt64
+    public var residentBytes: UInt64
+    public var residentFraction: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(mmapped: Bool, fileBytes: UInt64, residentBytes: UInt64, residentFraction: Double) {
+        self.mmapped = mmapped
+        self.fileBytes = fileBytes
+        self.residentBytes = residentBytes
+        self.residentFraction = residentFraction
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension ResidencyStats: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeResidencyStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ResidencyStats {
+        return
+            try ResidencyStats(
+                mmapped: FfiConverterBool.read(from: &buf), 
+                fileBytes: FfiConverterUInt64.read(from: &buf), 
+                residentBytes: FfiConverterUInt64.read(from: &buf), 
+                residentFraction: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ResidencyStats, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.mmapped, into: &buf)
+        FfiConverterUInt64.write(value.fileBytes, into: &buf)
+        FfiConverterUInt64.write(value.residentBytes, into: &buf)
+        FfiConverterDouble.write(value.residentFraction, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeResidencyStats_lift(_ buf: RustBuffer) throws -> ResidencyStats {
+    return try FfiConverterTypeResidencyStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeResidencyStats_lower(_ value: ResidencyStats) -> RustBuffer {
+    return FfiConverterTypeResidencyStats.lower(value)
+}
+
+
+public struct SearchLimits: Equatable, Hashable {
+    public var maxVisitedNodes: UInt64?
+    public var timeBudgetUs: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(maxVisitedNodes: UInt64? = nil, timeBudgetUs: UInt64? = nil) {
+        self.maxVisitedNodes = maxVisitedNodes
+        self.timeBudgetUs = timeBudgetUs
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension SearchLimits: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchLimits: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchLimits {
+        return
+            try SearchLimits(
+                maxVisitedNodes: FfiConverterOptionUInt64.read(from: &buf), 
+                timeBudgetUs: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SearchLimits, into buf: inout [UInt8]) {
+        FfiConverterOptionUInt64.write(value.maxVisitedNodes, into: &buf)
+        FfiConverterOptionUInt64.write(value.timeBudgetUs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchLimits_lift(_ buf: RustBuffer) throws -> SearchLimits {
+    return try FfiConverterTypeSearchLimits.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchLimits_lower(_ value: SearchLimits) -> RustBuffer {
+    return FfiConverterTypeSearchLimits.lower(value)
+}
+
+
+public struct SearchOptions: Equatable, Hashable {
+    public var includeRank: Bool
+    public var includeScore: Bool
+    public var includePayload: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(includeRank: Bool = false, includeScore: Bool = false, includePayload: Bool = false) {
+        self.includeRank = includeRank
+        self.includeScore = includeScore
+        self.includePayload = includePayload
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension SearchOptions: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchOptions {
+        return
+            try SearchOptions(
+                includeRank: FfiConverterBool.read(from: &buf), 
+                includeScore: FfiConverterBool.read(from: &buf), 
+                includePayload: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SearchOptions, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.includeRank, into: &buf)
+        FfiConverterBool.write(value.includeScore, into: &buf)
+        FfiConverterBool.write(value.includePayload, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchOptions_lift(_ buf: RustBuffer) throws -> SearchOptions {
+    return try FfiConverterTypeSearchOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchOptions_lower(_ value: SearchOptions) -> RustBuffer {
+    return FfiConverterTypeSearchOptions.lower(value)
+}
+
+
+public struct SearchResult: Equatable, Hashable {
+    public var id: UInt64
+    public var distance: Float
+    public var rank: UInt32?
+    public var score: Float?
+    public var payload: Data?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: UInt64, distance: Float, rank: UInt32? = nil, score: Float? = nil, payload: Data? = nil) {
+        self.id = id
+        self.distance = distance
+        self.rank = rank
+        self.score = score
+        self.payload = payload
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension SearchResult: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchResult {
+        return
+            try SearchResult(
+                id: FfiConverterUInt64.read(from: &buf), 
+                distance: FfiConverterFloat.read(from: &buf), 
+                rank: FfiConverterOptionUInt32.read(from: &buf), 
+                score: FfiConverterOptionFloat.read(from: &buf), 
+                payload: FfiConverterOptionData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SearchResult, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.id, into: &buf)
+        FfiConverterFloat.write(value.distance, into: &buf)
+        FfiConverterOptionUInt32.write(value.rank, into: &buf)
+        FfiConverterOptionFloat.write(value.score, into: &buf)
+        FfiConverterOptionData.write(value.payload, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchResult_lift(_ buf: RustBuffer) throws -> SearchResult {
+    return try FfiConverterTypeSearchResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchResult_lower(_ value: SearchResult) -> RustBuffer {
+    return FfiConverterTypeSearchResult.lower(value)
+}
+
+
+public struct SearchStats: Equatable, Hashable {
+    public var nodesVisited: UInt64
+    public var distanceComputations: UInt64
+    public var layersDescended: UInt32
+    public var timeUs: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(nodesVisited: UInt64, distanceComputations: UInt64, layersDescended: UInt32, timeUs: UInt64) {
+        self.nodesVisited = nodesVisited
+        self.distanceComputations = distanceComputations
+        self.layersDescended = layersDescended
+        self.timeUs = timeUs
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension SearchStats: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchStats {
+        return
+            try SearchStats(
+                nodesVisited: FfiConverterUInt64.read(from: &buf), 
+                distanceComputations: FfiConverterUInt64.read(from: &buf), 
+                layersDescended: FfiConverterUInt32.read(from: &buf), 
+                timeUs: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SearchStats, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.nodesVisited, into: &buf)
+        FfiConverterUInt64.write(value.distanceComputations, into: &buf)
+        FfiConverterUInt32.write(value.layersDescended, into: &buf)
+        FfiConverterUInt64.write(value.timeUs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchStats_lift(_ buf: RustBuffer) throws -> SearchStats {
+    return try FfiConverterTypeSearchStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchStats_lower(_ value: SearchStats) -> RustBuffer {
+    return FfiConverterTypeSearchStats.lower(value)
+}
+
+
+public struct SearchWithStats: Equatable, Hashable {
+    public var results: [SearchResult]
+    public var stats: SearchStats
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(results: [SearchResult], stats: SearchStats) {
+        self.results = results
+        self.stats = stats
+    }
+
+    
+}
+
+#if compiler(>=6)
+extension SearchWithStats: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSearchWithStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SearchWithStats {
+        return
+            try SearchWithStats(
+                results: FfiConverterSequenceTypeSearchResult.read(from: &buf), 
+                stats: FfiConverterTypeSearchStats.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SearchWithStats, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeSearchResult.write(value.results, into: &buf)
+        FfiConverterTypeSearchStats.write(value.stats, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchWithStats_lift(_ buf: RustBuffer) throws -> SearchWithStats {
+    return try FfiConverterTypeSearchWithStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSearchWithStats_lower(_ value: SearchWithStats) -> RustBuffer {
+    return FfiConverterTypeSearchWithStats.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum AttrValue: Equatable, Hashable {
+    
+    case int(value: Int64
+    )
+    case float(value: Double
+    )
+    case text(value: String
+    )
+    case bool(value: Bool
+    )
+
+
+
+}
+
+#if compiler(>=6)
+extension AttrValue: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAttrValue: FfiConverterRustBuffer {
+    typealias SwiftType = AttrValue
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AttrValue {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .int(value: try FfiConverterInt64.read(from: &buf)
+        )
+        
+        case 2: return .float(value: try FfiConverterDouble.read(from: &buf)
+        )
+        
+        case 3: return .text(value: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 4: return .bool(value: try FfiConverterBool.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: AttrValue, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .int(value):
+            writeInt(&buf, Int32(1))
+            FfiConverterInt64.write(value, into: &buf)
+            
+        
+        case let .float(value):
+            writeInt(&buf, Int32(2))
+            FfiConverterDouble.write(value, into: &buf)
+            
+        
+        case let .text(value):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(value, into: &buf)
+            
+        
+        case let .bool(value):
+            writeInt(&buf, Int32(4))
+            FfiConverterBool.write(value, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAttrValue_lift(_ buf: RustBuffer) throws -> AttrValue {
+    return try FfiConverterTypeAttrValue.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAttrValue_lower(_ value: AttrValue) -> RustBuffer {
+    return FfiConverterTypeAttrValue.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum DistanceType: Equatable, Hashable {
+    
+    case l2
+    case cosine
+    case l1
+    case dot
+
+
+
+}
+
+#if compiler(>=6)
+extension DistanceType: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDistanceType: FfiConverterRustBuffer {
+    typealias SwiftType = DistanceType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DistanceType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .l2
+        
+        case 2: return .cosine
+        
+        case 3: return .l1
+        
+        case 4: return .dot
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DistanceType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .l2:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .cosine:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .l1:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .dot:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDistanceType_lift(_ buf: RustBuffer) throws -> DistanceType {
+    return try FfiConverterTypeDistanceType.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDistanceType_lower(_ value: DistanceType) -> RustBuffer {
+    return FfiConverterTypeDistanceType.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum DocumentAggregation: Equatable, Hashable {
+    
+    case max
+    case mean
+
+
+
+}
+
+#if compiler(>=6)
+extension DocumentAggregation: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDocumentAggregation: FfiConverterRustBuffer {
+    typealias SwiftType = DocumentAggregation
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DocumentAggregation {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .max
+        
+        case 2: return .mean
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DocumentAggregation, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .max:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .mean:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDocumentAggregation_lift(_ buf: RustBuffer) throws -> DocumentAggregation {
+    return try FfiConverterTypeDocumentAggregation.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDocumentAggregation_lower(_ value: DocumentAggregation) -> RustBuffer {
+    return FfiConverterTypeDocumentAggregation.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ElementType: Equatable, Hashable {
+    
+    case f32
+    case f64
+    case u16
+    case i32
+    case u8
+
+
+
+}
+
+#if compiler(>=6)
+extension ElementType: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeElementType: FfiConverterRustBuffer {
+    typealias SwiftType = ElementType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ElementType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .f32
+        
+        case 2: return .f64
+        
+        case 3: return .u16
+        
+        case 4: return .i32
+        
+        case 5: return .u8
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ElementType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .f32:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .f64:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .u16:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .i32:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .u8:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeElementType_lift(_ buf: RustBuffer) throws -> ElementType {
+    return try FfiConverterTypeElementType.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeElementType_lower(_ value: ElementType) -> RustBuffer {
+    return FfiConverterTypeElementType.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ExportFormat: Equatable, Hashable {
+    
+    case npz
+    case jsonl
+    case arrow
+
+
+
+}
+
+#if compiler(>=6)
+extension ExportFormat: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeExportFormat: FfiConverterRustBuffer {
+    typealias SwiftType = ExportFormat
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ExportFormat {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .npz
+        
+        case 2: return .jsonl
+        
+        case 3: return .arrow
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ExportFormat, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .npz:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .jsonl:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .arrow:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeExportFormat_lift(_ buf: RustBuffer) throws -> ExportFormat {
+    return try FfiConverterTypeExportFormat.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeExportFormat_lower(_ value: ExportFormat) -> RustBuffer {
+    return FfiConverterTypeExportFormat.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum FusionStrategy: Equatable, Hashable {
+    
+    case reciprocalRank(weights: [Float]
+    )
+    case weightedScore(weights: [Float]
+    )
+
+
+
+}
+
+#if compiler(>=6)
+extension FusionStrategy: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFusionStrategy: FfiConverterRustBuffer {
+    typealias SwiftType = FusionStrategy
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FusionStrategy {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .reciprocalRank(weights: try FfiConverterSequenceFloat.read(from: &buf)
+        )
+        
+        case 2: return .weightedScore(weights: try FfiConverterSequenceFloat.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FusionStrategy, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .reciprocalRank(weights):
+            writeInt(&buf, Int32(1))
+            FfiConverterSequenceFloat.write(weights, into: &buf)
+            
+        
+        case let .weightedScore(weights):
+            writeInt(&buf, Int32(2))
+            FfiConverterSequenceFloat.write(weights, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFusionStrategy_lift(_ buf: RustBuffer) throws -> FusionStrategy {
+    return try FfiConverterTypeFusionStrategy.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFusionStrategy_lower(_ value: FusionStrategy) -> RustBuffer {
+    return FfiConverterTypeFusionStrategy.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum GraphFormat: Equatable, Hashable {
+    
+    case graphMl
+    case dot
+    case json
+
+
+
+}
+
+#if compiler(>=6)
+extension GraphFormat: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeGraphFormat: FfiConverterRustBuffer {
+    typealias SwiftType = GraphFormat
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> GraphFormat {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .graphMl
+        
+        case 2: return .dot
+        
+        case 3: return .json
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: GraphFormat, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .graphMl:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .dot:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .json:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGraphFormat_lift(_ buf: RustBuffer) throws -> GraphFormat {
+    return try FfiConverterTypeGraphFormat.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeGraphFormat_lower(_ value: GraphFormat) -> RustBuffer {
+    return FfiConverterTypeGraphFormat.lower(value)
+}
+
+
+
+public enum HnswError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+
+    
+    
+    case IoError(message: String)
+    
+    case LockError(message: String)
+    
+    case EmptyIndex(message: String)
+    
+    case DistanceMismatch(message: String)
+    
+    case DimensionMismatch(message: String)
+    
+    case ReloadError(message: String)
+    
+    case DumpError(message: String)
+    
+    case IndexNotFound(message: String)
+    
+    case IndexAlreadyExists(message: String)
+    
+    case InvalidName(message: String)
+    
+    case ManifestError(message: String)
+    
+    case Cancelled(message: String)
+    
+    case ThreadPoolError(message: String)
+    
+    case InvalidArgument(message: String)
+    
+    case NotTrained(message: String)
+    
+    case ZeroVector(message: String)
+    
+    case InvalidVector(message: String)
+    
+    case DuplicateId(message: String)
+    
+    case DuplicateKey(message: String)
+    
+    case ReadOnly(message: String)
+    
+    case LengthMismatch(message: String)
+    
+    case CapacityExceeded(message: String)
+    
+    case Corrupted(message: String)
+    
+    case Internal(message: String)
+    
+    case UnsupportedVersion(message: String)
+    
+    case Busy(message: String)
+    
+
+    
+
+    
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
+}
+
+#if compiler(>=6)
+extension HnswError: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHnswError: FfiConverterRustBuffer {
+    typealias SwiftType = HnswError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HnswError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .IoError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .LockError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .EmptyIndex(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 4: return .DistanceMismatch(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 5: return .DimensionMismatch(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 6: return .ReloadError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 7: return .DumpError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 8: return .IndexNotFound(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 9: return .IndexAlreadyExists(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 10: return .InvalidName(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 11: return .ManifestError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 12: return .Cancelled(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 13: return .ThreadPoolError(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 14: return .InvalidArgument(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 15: return .NotTrained(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 16: return .ZeroVector(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 17: return .InvalidVector(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 18: return .DuplicateId(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 19: return .DuplicateKey(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 20: return .ReadOnly(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 21: return .LengthMismatch(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 22: return .CapacityExceeded(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 23: return .Corrupted(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 24: return .Internal(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 25: return .UnsupportedVersion(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 26: return .Busy(
+            message: try FfiConverterString.read(from: &buf)
+        )
+        
+
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: HnswError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        case .IoError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(1))
+        case .LockError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(2))
+        case .EmptyIndex(_ /* message is ignored*/):
+            writeInt(&buf, Int32(3))
+        case .DistanceMismatch(_ /* message is ignored*/):
+            writeInt(&buf, Int32(4))
+        case .DimensionMismatch(_ /* message is ignored*/):
+            writeInt(&buf, Int32(5))
+        case .ReloadError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(6))
+        case .DumpError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(7))
+        case .IndexNotFound(_ /* message is ignored*/):
+            writeInt(&buf, Int32(8))
+        case .IndexAlreadyExists(_ /* message is ignored*/):
+            writeInt(&buf, Int32(9))
+        case .InvalidName(_ /* message is ignored*/):
+            writeInt(&buf, Int32(10))
+        case .ManifestError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(11))
+        case .Cancelled(_ /* message is ignored*/):
+            writeInt(&buf, Int32(12))
+        case .ThreadPoolError(_ /* message is ignored*/):
+            writeInt(&buf, Int32(13))
+        case .InvalidArgument(_ /* message is ignored*/):
+            writeInt(&buf, Int32(14))
+        case .NotTrained(_ /* message is ignored*/):
+            writeInt(&buf, Int32(15))
+        case .ZeroVector(_ /* message is ignored*/):
+            writeInt(&buf, Int32(16))
+        case .InvalidVector(_ /* message is ignored*/):
+            writeInt(&buf, Int32(17))
+        case .DuplicateId(_ /* message is ignored*/):
+            writeInt(&buf, Int32(18))
+        case .DuplicateKey(_ /* message is ignored*/):
+            writeInt(&buf, Int32(19))
+        case .ReadOnly(_ /* message is ignored*/):
+            writeInt(&buf, Int32(20))
+        case .LengthMismatch(_ /* message is ignored*/):
+            writeInt(&buf, Int32(21))
+        case .CapacityExceeded(_ /* message is ignored*/):
+            writeInt(&buf, Int32(22))
+        case .Corrupted(_ /* message is ignored*/):
+            writeInt(&buf, Int32(23))
+        case .Internal(_ /* message is ignored*/):
+            writeInt(&buf, Int32(24))
+        case .UnsupportedVersion(_ /* message is ignored*/):
+            writeInt(&buf, Int32(25))
+        case .Busy(_ /* message is ignored*/):
+            writeInt(&buf, Int32(26))
+
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswError_lift(_ buf: RustBuffer) throws -> HnswError {
+    return try FfiConverterTypeHnswError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHnswError_lower(_ value: HnswError) -> RustBuffer {
+    return FfiConverterTypeHnswError.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum LogLevel: Equatable, Hashable {
+    
+    case trace
+    case debug
+    case info
+    case warn
+    case error
+
+
+
+}
+
+#if compiler(>=6)
+extension LogLevel: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLogLevel: FfiConverterRustBuffer {
+    typealias SwiftType = LogLevel
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LogLevel {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .trace
+        
+        case 2: return .debug
+        
+        case 3: return .info
+        
+        case 4: return .warn
+        
+        case 5: return .error
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: LogLevel, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .trace:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .debug:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .info:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .warn:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .error:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLogLevel_lift(_ buf: RustBuffer) throws -> LogLevel {
+    return try FfiConverterTypeLogLevel.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLogLevel_lower(_ value: LogLevel) -> RustBuffer {
+    return FfiConverterTypeLogLevel.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum MemoryPressureLevel: Equatable, Hashable {
+    
+    case warning
+    case critical
+
+
+
+}
+
+#if compiler(>=6)
+extension MemoryPressureLevel: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMemoryPressureLevel: FfiConverterRustBuffer {
+    typealias SwiftType = MemoryPressureLevel
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MemoryPressureLevel {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .warning
+        
+        case 2: return .critical
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: MemoryPressureLevel, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .warning:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .critical:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMemoryPressureLevel_lift(_ buf: RustBuffer) throws -> MemoryPressureLevel {
+    return try FfiConverterTypeMemoryPressureLevel.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMemoryPressureLevel_lower(_ value: MemoryPressureLevel) -> RustBuffer {
+    return FfiConverterTypeMemoryPressureLevel.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ThreadQos: Equatable, Hashable {
+    
+    case `default`
+    case utility
+    case background
+
+
+
+}
+
+#if compiler(>=6)
+extension ThreadQos: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeThreadQos: FfiConverterRustBuffer {
+    typealias SwiftType = ThreadQos
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ThreadQos {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .`default`
+        
+        case 2: return .utility
+        
+        case 3: return .background
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ThreadQos, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .`default`:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .utility:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .background:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeThreadQos_lift(_ buf: RustBuffer) throws -> ThreadQos {
+    return try FfiConverterTypeThreadQos.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeThreadQos_lower(_ value: ThreadQos) -> RustBuffer {
+    return FfiConverterTypeThreadQos.lower(value)
+}
+
+
+
+
+
+public protocol Embedder: AnyObject, Sendable {
+    
+    func embed(text: String)  -> [Float]
+    
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceEmbedder {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceEmbedder] = [UniffiVTableCallbackInterfaceEmbedder(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterCallbackInterfaceEmbedder.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface Embedder: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterCallbackInterfaceEmbedder.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface Embedder: handle missing in uniffiClone")
+            }
+        },
+        embed: { (
+            uniffiHandle: UInt64,
+            text: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> [Float] in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceEmbedder.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.embed(
+                     text: try FfiConverterString.lift(text)
+                )
+            }
+
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterSequenceFloat.lower($0) }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitEmbedder() {
+    uniffi_hnsw_fn_init_callback_vtable_embedder(UniffiCallbackInterfaceEmbedder.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceEmbedder {
+    fileprivate static let handleMap = UniffiHandleMap<Embedder>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceEmbedder : FfiConverter {
+    typealias SwiftType = Embedder
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceEmbedder_lift(_ handle: UInt64) throws -> Embedder {
+    return try FfiConverterCallbackInterfaceEmbedder.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceEmbedder_lower(_ v: Embedder) -> UInt64 {
+    return FfiConverterCallbackInterfaceEmbedder.lower(v)
+}
+
+
+
+
+public protocol IndexObserver: AnyObject, Sendable {
+    
+    func onInsert(ids: [UInt64]) 
+    
+    func onRemove(ids: [UInt64]) 
+    
+    func onSave(directory: String, basename: String) 
+    
+    func onCapacityWarning(len: UInt64, capacity: UInt64) 
+    
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceIndexObserver {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceIndexObserver] = [UniffiVTableCallbackInterfaceIndexObserver(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterCallbackInterfaceIndexObserver.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface IndexObserver: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterCallbackInterfaceIndexObserver.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface IndexObserver: handle missing in uniffiClone")
+            }
+        },
+        onInsert: { (
+            uniffiHandle: UInt64,
+            ids: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceIndexObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onInsert(
+                     ids: try FfiConverterSequenceUInt64.lift(ids)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onRemove: { (
+            uniffiHandle: UInt64,
+            ids: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceIndexObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onRemove(
+                     ids: try FfiConverterSequenceUInt64.lift(ids)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onSave: { (
+            uniffiHandle: UInt64,
+            directory: RustBuffer,
+            basename: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceIndexObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onSave(
+                     directory: try FfiConverterString.lift(directory),
+                     basename: try FfiConverterString.lift(basename)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        onCapacityWarning: { (
+            uniffiHandle: UInt64,
+            len: UInt64,
+            capacity: UInt64,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceIndexObserver.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onCapacityWarning(
+                     len: try FfiConverterUInt64.lift(len),
+                     capacity: try FfiConverterUInt64.lift(capacity)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitIndexObserver() {
+    uniffi_hnsw_fn_init_callback_vtable_indexobserver(UniffiCallbackInterfaceIndexObserver.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceIndexObserver {
+    fileprivate static let handleMap = UniffiHandleMap<IndexObserver>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceIndexObserver : FfiConverter {
+    typealias SwiftType = IndexObserver
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceIndexObserver_lift(_ handle: UInt64) throws -> IndexObserver {
+    return try FfiConverterCallbackInterfaceIndexObserver.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceIndexObserver_lower(_ v: IndexObserver) -> UInt64 {
+    return FfiConverterCallbackInterfaceIndexObserver.lower(v)
+}
+
+
+
+
+public protocol LogListener: AnyObject, Sendable {
+    
+    func onLog(event: LogEvent) 
+    
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceLogListener {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceLogListener] = [UniffiVTableCallbackInterfaceLogListener(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterCallbackInterfaceLogListener.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface LogListener: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterCallbackInterfaceLogListener.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface LogListener: handle missing in uniffiClone")
+            }
+        },
+        onLog: { (
+            uniffiHandle: UInt64,
+            event: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceLogListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onLog(
+                     event: try FfiConverterTypeLogEvent_lift(event)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitLogListener() {
+    uniffi_hnsw_fn_init_callback_vtable_loglistener(UniffiCallbackInterfaceLogListener.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceLogListener {
+    fileprivate static let handleMap = UniffiHandleMap<LogListener>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceLogListener : FfiConverter {
+    typealias SwiftType = LogListener
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceLogListener_lift(_ handle: UInt64) throws -> LogListener {
+    return try FfiConverterCallbackInterfaceLogListener.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceLogListener_lower(_ v: LogListener) -> UInt64 {
+    return FfiConverterCallbackInterfaceLogListener.lower(v)
+}
+
+
+
+
+public protocol MigrationEmbedder: AnyObject, Sendable {
+    
+    func embed(id: UInt64, key: String?, payload: Data?)  -> [Float]
+    
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceMigrationEmbedder {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceMigrationEmbedder] = [UniffiVTableCallbackInterfaceMigrationEmbedder(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterCallbackInterfaceMigrationEmbedder.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface MigrationEmbedder: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterCallbackInterfaceMigrationEmbedder.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface MigrationEmbedder: handle missing in uniffiClone")
+            }
+        },
+        embed: { (
+            uniffiHandle: UInt64,
+            id: UInt64,
+            key: RustBuffer,
+            payload: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> [Float] in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceMigrationEmbedder.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.embed(
+                     id: try FfiConverterUInt64.lift(id),
+                     key: try FfiConverterOptionString.lift(key),
+                     payload: try FfiConverterOptionData.lift(payload)
+                )
+            }
+
+            
+            let writeReturn = { uniffiOutReturn.pointee = FfiConverterSequenceFloat.lower($0) }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitMigrationEmbedder() {
+    uniffi_hnsw_fn_init_callback_vtable_migrationembedder(UniffiCallbackInterfaceMigrationEmbedder.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceMigrationEmbedder {
+    fileprivate static let handleMap = UniffiHandleMap<MigrationEmbedder>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceMigrationEmbedder : FfiConverter {
+    typealias SwiftType = MigrationEmbedder
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceMigrationEmbedder_lift(_ handle: UInt64) throws -> MigrationEmbedder {
+    return try FfiConverterCallbackInterfaceMigrationEmbedder.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceMigrationEmbedder_lower(_ v: MigrationEmbedder) -> UInt64 {
+    return FfiConverterCallbackInterfaceMigrationEmbedder.lower(v)
+}
+
+
+
+
+public protocol ProgressListener: AnyObject, Sendable {
+    
+    func onProgress(done: UInt64, total: UInt64) 
+    
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceProgressListener {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceProgressListener] = [UniffiVTableCallbackInterfaceProgressListener(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterCallbackInterfaceProgressListener.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface ProgressListener: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterCallbackInterfaceProgressListener.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface ProgressListener: handle missing in uniffiClone")
+            }
+        },
+        onProgress: { (
+            uniffiHandle: UInt64,
+            done: UInt64,
+            total: UInt64,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceProgressListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onProgress(
+                     done: try FfiConverterUInt64.lift(done),
+                     total: try FfiConverterUInt64.lift(total)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitProgressListener() {
+    uniffi_hnsw_fn_init_callback_vtable_progresslistener(UniffiCallbackInterfaceProgressListener.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceProgressListener {
+    fileprivate static let handleMap = UniffiHandleMap<ProgressListener>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceProgressListener : FfiConverter {
+    typealias SwiftType = ProgressListener
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceProgressListener_lift(_ handle: UInt64) throws -> ProgressListener {
+    return try FfiConverterCallbackInterfaceProgressListener.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceProgressListener_lower(_ v: ProgressListener) -> UInt64 {
+    return FfiConverterCallbackInterfaceProgressListener.lower(v)
+}
+
+
+
+
+public protocol SignpostListener: AnyObject, Sendable {
+    
+    func beginInterval(name: String, intervalId: UInt64) 
+    
+    func endInterval(name: String, intervalId: UInt64) 
+    
+}
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceSignpostListener {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceSignpostListener] = [UniffiVTableCallbackInterfaceSignpostListener(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterCallbackInterfaceSignpostListener.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface SignpostListener: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterCallbackInterfaceSignpostListener.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface SignpostListener: handle missing in uniffiClone")
+            }
+        },
+        beginInterval: { (
+            uniffiHandle: UInt64,
+            name: RustBuffer,
+            intervalId: UInt64,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceSignpostListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.beginInterval(
+                     name: try FfiConverterString.lift(name),
+                     intervalId: try FfiConverterUInt64.lift(intervalId)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        endInterval: { (
+            uniffiHandle: UInt64,
+            name: RustBuffer,
+            intervalId: UInt64,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceSignpostListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.endInterval(
+                     name: try FfiConverterString.lift(name),
+                     intervalId: try FfiConverterUInt64.lift(intervalId)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitSignpostListener() {
+    uniffi_hnsw_fn_init_callback_vtable_signpostlistener(UniffiCallbackInterfaceSignpostListener.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceSignpostListener {
+    fileprivate static let handleMap = UniffiHandleMap<SignpostListener>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceSignpostListener : FfiConverter {
+    typealias SwiftType = SignpostListener
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceSignpostListener_lift(_ handle: UInt64) throws -> SignpostListener {
+    return try FfiConverterCallbackInterfaceSignpostListener.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterCallbackInterfaceSignpostListener_lower(_ v: SignpostListener) -> UInt64 {
+    return FfiConverterCallbackInterfaceSignpostListener.lower(v)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionInt64: FfiConverterRustBuffer {
+    typealias SwiftType = Int64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionFloat: FfiConverterRustBuffer {
+    typealias SwiftType = Float?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterFloat.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterFloat.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
+    typealias SwiftType = Double?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDouble.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDouble.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
+    typealias SwiftType = Data?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterData.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterData.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeCancellationToken: FfiConverterRustBuffer {
+    typealias SwiftType = CancellationToken?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeCancellationToken.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeCancellationToken.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeDimReducer: FfiConverterRustBuffer {
+    typealias SwiftType = DimReducer?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeDimReducer.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeDimReducer.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionCallbackInterfaceEmbedder: FfiConverterRustBuffer {
+    typealias SwiftType = Embedder?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterCallbackInterfaceEmbedder.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterCallbackInterfaceEmbedder.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionCallbackInterfaceIndexObserver: FfiConverterRustBuffer {
+    typealias SwiftType = IndexObserver?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterCallbackInterfaceIndexObserver.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterCallbackInterfaceIndexObserver.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionSequenceFloat: FfiConverterRustBuffer {
+    typealias SwiftType = [Float]?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterSequenceFloat.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterSequenceFloat.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceUInt16: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt16]
+
+    public static func write(_ value: [UInt16], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt16.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt16] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt16]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt16.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt32]
+
+    public static func write(_ value: [UInt32], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt32.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt32] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt32]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt32.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [Int32]
+
+    public static func write(_ value: [Int32], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterInt32.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Int32] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Int32]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterInt32.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt64]
+
+    public static func write(_ value: [UInt64], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt64.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt64] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt64]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt64.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceFloat: FfiConverterRustBuffer {
+    typealias SwiftType = [Float]
+
+    public static func write(_ value: [Float], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterFloat.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Float] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Float]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterFloat.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceDouble: FfiConverterRustBuffer {
+    typealias SwiftType = [Double]
+
+    public static func write(_ value: [Double], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterDouble.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Double] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Double]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterDouble.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
+
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeHnswIndex: FfiConverterRustBuffer {
+    typealias SwiftType = [HnswIndex]
+
+    public static func write(_ value: [HnswIndex], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeHnswIndex.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [HnswIndex] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [HnswIndex]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeHnswIndex.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeClusterAssignment: FfiConverterRustBuffer {
+    typealias SwiftType = [ClusterAssignment]
+
+    public static func write(_ value: [ClusterAssignment], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeClusterAssignment.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ClusterAssignment] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ClusterAssignment]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeClusterAssignment.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeDocumentSearchResult: FfiConverterRustBuffer {
+    typealias SwiftType = [DocumentSearchResult]
+
+    public static func write(_ value: [DocumentSearchResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDocumentSearchResult.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DocumentSearchResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DocumentSearchResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDocumentSearchResult.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeDuplicatePair: FfiConverterRustBuffer {
+    typealias SwiftType = [DuplicatePair]
+
+    public static func write(_ value: [DuplicatePair], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDuplicatePair.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DuplicatePair] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DuplicatePair]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDuplicatePair.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeGroupSearchResult: FfiConverterRustBuffer {
+    typealias SwiftType = [GroupSearchResult]
+
+    public static func write(_ value: [GroupSearchResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeGroupSearchResult.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [GroupSearchResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [GroupSearchResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeGroupSearchResult.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeJsonlLineError: FfiConverterRustBuffer {
+    typealias SwiftType = [JsonlLineError]
+
+    public static func write(_ value: [JsonlLineError], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeJsonlLineError.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [JsonlLineError] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [JsonlLineError]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeJsonlLineError.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeKeyedSearchResult: FfiConverterRustBuffer {
+    typealias SwiftType = [KeyedSearchResult]
+
+    public static func write(_ value: [KeyedSearchResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeKeyedSearchResult.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [KeyedSearchResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [KeyedSearchResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeKeyedSearchResult.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeMultiSearchResult: FfiConverterRustBuffer {
+    typealias SwiftType = [MultiSearchResult]
+
+    public static func write(_ value: [MultiSearchResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeMultiSearchResult.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [MultiSearchResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [MultiSearchResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeMultiSearchResult.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeSearchResult: FfiConverterRustBuffer {
+    typealias SwiftType = [SearchResult]
+
+    public static func write(_ value: [SearchResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSearchResult.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SearchResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SearchResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSearchResult.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceSequenceUInt16: FfiConverterRustBuffer {
+    typealias SwiftType = [[UInt16]]
+
+    public static func write(_ value: [[UInt16]], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterSequenceUInt16.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [[UInt16]] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [[UInt16]]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterSequenceUInt16.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceSequenceInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [[Int32]]
+
+    public static func write(_ value: [[Int32]], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterSequenceInt32.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [[Int32]] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [[Int32]]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterSequenceInt32.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceSequenceFloat: FfiConverterRustBuffer {
+    typealias SwiftType = [[Float]]
+
+    public static func write(_ value: [[Float]], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterSequenceFloat.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [[Float]] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [[Float]]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterSequenceFloat.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceSequenceDouble: FfiConverterRustBuffer {
+    typealias SwiftType = [[Double]]
+
+    public static func write(_ value: [[Double]], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterSequenceDouble.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [[Double]] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [[Double]]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterSequenceDouble.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceSequenceTypeSearchResult: FfiConverterRustBuffer {
+    typealias SwiftType = [[SearchResult]]
+
+    public static func write(_ value: [[SearchResult]], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterSequenceTypeSearchResult.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [[SearchResult]] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [[SearchResult]]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterSequenceTypeSearchResult.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDictionaryStringString: FfiConverterRustBuffer {
+    public static func write(_ value: [String: String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterString.write(value, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: String] {
+        let len: Int32 = try readInt(&buf)
+        var dict = [String: String]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterString.read(from: &buf)
+            dict[key] = value
+        }
+        return dict
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDictionaryStringTypeAttrValue: FfiConverterRustBuffer {
+    public static func write(_ value: [String: AttrValue], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterTypeAttrValue.write(value, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: AttrValue] {
+        let len: Int32 = try readInt(&buf)
+        var dict = [String: AttrValue]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterTypeAttrValue.read(from: &buf)
+            dict[key] = value
+        }
+        return dict
+    }
+}
+private let UNIFFI_RUST_FUTURE_POLL_READY: Int8 = 0
+private let UNIFFI_RUST_FUTURE_POLL_WAKE: Int8 = 1
+
+fileprivate let uniffiContinuationHandleMap = UniffiHandleMap<UnsafeContinuation<Int8, Never>>()
+
+fileprivate func uniffiRustCallAsync<F, T>(
+    rustFutureFunc: () -> UInt64,
+    pollFunc: (UInt64, @escaping UniffiRustFutureContinuationCallback, UInt64) -> (),
+    completeFunc: (UInt64, UnsafeMutablePointer<RustCallStatus>) -> F,
+    freeFunc: (UInt64) -> (),
+    liftFunc: (F) throws -> T,
+    errorHandler: ((RustBuffer) throws -> Swift.Error)?
+) async throws -> T {
+    // Make sure to call the ensure init function since future creation doesn't have a
+    // RustCallStatus param, so doesn't use makeRustCall()
+    uniffiEnsureHnswInitialized()
+    let rustFuture = rustFutureFunc()
+    defer {
+        freeFunc(rustFuture)
+    }
+    var pollResult: Int8;
+    repeat {
+        pollResult = await withUnsafeContinuation {
+            pollFunc(
+                rustFuture,
+                { handle, pollResult in
+                    uniffiFutureContinuationCallback(handle: handle, pollResult: pollResult)
+                },
+                uniffiContinuationHandleMap.insert(obj: $0)
+            )
+        }
+    } while pollResult != UNIFFI_RUST_FUTURE_POLL_READY
+
+    return try liftFunc(makeRustCall(
+        { completeFunc(rustFuture, $0) },
+        errorHandler: errorHandler
+    ))
+}
+
+// Callback handlers for an async calls.  These are invoked by Rust when the future is ready.  They
+// lift the return value or error and resume the suspended function.
+fileprivate func uniffiFutureContinuationCallback(handle: UInt64, pollResult: Int8) {
+    if let continuation = try? uniffiContinuationHandleMap.remove(handle: handle) {
+        continuation.resume(returning: pollResult)
+    } else {
+        print("uniffiFutureContinuationCallback invalid handle")
+    }
+}
+public func clearLogCallback()  {try! rustCall() {
+    uniffi_hnsw_fn_func_clear_log_callback($0
+    )
+}
+}
+public func clearSignpostListener()  {try! rustCall() {
+    uniffi_hnsw_fn_func_clear_signpost_listener($0
+    )
+}
+}
+public func cosineSimilarity(a: [Float], b: [Float])throws  -> Float  {
+    return try  FfiConverterFloat.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_func_cosine_similarity(
+        FfiConverterSequenceFloat.lower(a),
+        FfiConverterSequenceFloat.lower(b),$0
+    )
+})
+}
+public func destroyDump(directory: String, basename: String)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_func_destroy_dump(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+})
+}
+public func distance(a: [Float], b: [Float], distanceType: DistanceType)throws  -> Float  {
+    return try  FfiConverterFloat.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_func_distance(
+        FfiConverterSequenceFloat.lower(a),
+        FfiConverterSequenceFloat.lower(b),
+        FfiConverterTypeDistanceType_lower(distanceType),$0
+    )
+})
+}
+public func dumpFormatVersion(directory: String, basename: String)throws  -> UInt32  {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_func_dump_format_version(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+})
+}
+public func estimateMemoryBytes(dimension: UInt32, count: UInt64, maxNbConnection: UInt32, elementType: ElementType) -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_hnsw_fn_func_estimate_memory_bytes(
+        FfiConverterUInt32.lower(dimension),
+        FfiConverterUInt64.lower(count),
+        FfiConverterUInt32.lower(maxNbConnection),
+        FfiConverterTypeElementType_lower(elementType),$0
+    )
+})
+}
+public func fuseResults(results: [[SearchResult]], k: UInt32, strategy: FusionStrategy)throws  -> [SearchResult]  {
+    return try  FfiConverterSequenceTypeSearchResult.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_func_fuse_results(
+        FfiConverterSequenceSequenceTypeSearchResult.lower(results),
+        FfiConverterUInt32.lower(k),
+        FfiConverterTypeFusionStrategy_lower(strategy),$0
+    )
+})
+}
+public func getNumThreads() -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_hnsw_fn_func_get_num_threads($0
+    )
+})
+}
+public func loadBundleAsync(path: String)async throws  -> HnswIndex  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hnsw_fn_func_load_bundle_async(FfiConverterString.lower(path)
+                )
+            },
+            pollFunc: ffi_hnsw_rust_future_poll_u64,
+            completeFunc: ffi_hnsw_rust_future_complete_u64,
+            freeFunc: ffi_hnsw_rust_future_free_u64,
+            liftFunc: FfiConverterTypeHnswIndex_lift,
+            errorHandler: FfiConverterTypeHnswError_lift
+        )
+}
+public func loadIndexAsync(directory: String, basename: String, config: HnswIndexConfig, readOnly: Bool)async throws  -> HnswIndex  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hnsw_fn_func_load_index_async(FfiConverterString.lower(directory),FfiConverterString.lower(basename),FfiConverterTypeHnswIndexConfig_lower(config),FfiConverterBool.lower(readOnly)
+                )
+            },
+            pollFunc: ffi_hnsw_rust_future_poll_u64,
+            completeFunc: ffi_hnsw_rust_future_complete_u64,
+            freeFunc: ffi_hnsw_rust_future_free_u64,
+            liftFunc: FfiConverterTypeHnswIndex_lift,
+            errorHandler: FfiConverterTypeHnswError_lift
+        )
+}
+public func migrateDump(directory: String, basename: String)throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_func_migrate_dump(
+        FfiConverterString.lower(directory),
+        FfiConverterString.lower(basename),$0
+    )
+})
+}
+public func notifyMemoryPressure(level: MemoryPressureLevel)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_func_notify_memory_pressure(
+        FfiConverterTypeMemoryPressureLevel_lower(level),$0
+    )
+})
+}
+public func setLogCallback(listener: LogListener, level: LogLevel)  {try! rustCall() {
+    uniffi_hnsw_fn_func_set_log_callback(
+        FfiConverterCallbackInterfaceLogListener_lower(listener),
+        FfiConverterTypeLogLevel_lower(level),$0
+    )
+}
+}
+public func setNumThreads(numThreads: UInt32)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_func_set_num_threads(
+        FfiConverterUInt32.lower(numThreads),$0
+    )
+}
+}
+public func setSignpostListener(listener: SignpostListener)  {try! rustCall() {
+    uniffi_hnsw_fn_func_set_signpost_listener(
+        FfiConverterCallbackInterfaceSignpostListener_lower(listener),$0
+    )
+}
+}
+public func setTempDirectory(directory: String)  {try! rustCall() {
+    uniffi_hnsw_fn_func_set_temp_directory(
+        FfiConverterString.lower(directory),$0
+    )
+}
+}
+public func setThreadQos(qos: ThreadQos)throws   {try rustCallWithError(FfiConverterTypeHnswError_lift) {
+    uniffi_hnsw_fn_func_set_thread_qos(
+        FfiConverterTypeThreadQos_lower(qos),$0
+    )
+}
+}
+
+private enum InitializationResult {
+    case ok
+    case contractVersionMismatch
+    case apiChecksumMismatch
+}
+// Use a global variable to perform the versioning checks. Swift ensures that
+// the code inside is only computed once.
+private let initializationResult: InitializationResult = {
+    // Get the bindings contract version from our ComponentInterface
+    let bindings_contract_version = 30
+    // Get the scaffolding contract version by calling the into the dylib
+    let scaffolding_contract_version = ffi_hnsw_uniffi_contract_version()
+    if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
-    if (uniffi_hnsw_checksum_method_hnswindex_compact() != 58096) {
+    if (uniffi_hnsw_checksum_func_clear_log_callback() != 17714) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_clear_signpost_listener() != 4519) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_cosine_similarity() != 20103) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_destroy_dump() != 22520) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_distance() != 42361) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_dump_format_version() != 47332) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_estimate_memory_bytes() != 15923) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_fuse_results() != 61764) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_get_num_threads() != 65489) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_load_bundle_async() != 26303) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_load_index_async() != 43221) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_migrate_dump() != 18955) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_notify_memory_pressure() != 25957) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_set_log_callback() != 60247) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_set_num_threads() != 22214) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_set_signpost_listener() != 10470) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_set_temp_directory() != 64510) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_func_set_thread_qos() != 7312) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_cancellationtoken_cancel() != 21565) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_cancellationtoken_is_cancelled() != 51369) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_cancellationtoken_reset() != 31676) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_dimreducer_input_dimension() != 4138) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_dimreducer_output_dimension() != 13221) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_dimreducer_transform() != 15380) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswcollection_contains() != 54352) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswcollection_create() != 36826) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswcollection_drop_index() != 10432) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswcollection_get() != 12018) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswcollection_get_config() != 40104) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswcollection_list() != 37160) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswcollection_load_all() != 4453) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswcollection_save() != 6906) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswcollection_save_all() != 46662) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswf64index_get_dimension() != 44564) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswf64index_insert() != 49796) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswf64index_insert_batch() != 29185) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswf64index_is_empty() != 62963) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswf64index_len() != 1916) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswf64index_save() != 54631) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswf64index_search() != 40216) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswi32index_get_dimension() != 59965) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswi32index_insert() != 26318) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswi32index_insert_batch() != 14323) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswi32index_is_empty() != 41713) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswi32index_len() != 54390) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswi32index_save() != 40804) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswi32index_search() != 43373) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_apply_changes() != 33117) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_centroid() != 54616) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_change_version() != 20360) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_clear_query_transform() != 55295) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_compact() != 58096) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_contains() != 5689) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_count() != 25622) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_count_filtered() != 56745) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_count_within() != 48041) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_create_view() != 39170) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_destroy() != 28484) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_disable_query_cache() != 10000) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_enable_query_cache() != 37203) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_evaluate_recall() != 25763) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_evict_vectors() != 21078) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_export_all() != 8886) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_export_arrow() != 20811) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_export_changes() != 50528) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_export_graph() != 59942) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_find_duplicates() != 39486) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_attrs() != 61858) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_capacity() != 29152) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_default_ef_search() != 41495) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_dimension() != 59616) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_dimension_weights() != 28181) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_distance() != 47461) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_document_chunks() != 39287) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_document_id() != 34416) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_entry_point() != 40166) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_extend_candidates() != 38954) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_id_for_key() != 56033) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_keep_pruned() != 65035) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_key() != 59546) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_max_layer() != 62539) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_namespace() != 17806) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_payload() != 48888) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_point_data() != 51263) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_privacy_epsilon() != 28604) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_reducer() != 22104) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_get_timestamp() != 13433) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_graph_neighbors() != 55074) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_grow_to() != 62404) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_handle_memory_pressure() != 56908) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_has_binary_sketches() != 57592) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_health_check() != 41602) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_hidden_ids() != 31425) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_import_arrow() != 33485) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_import_faiss_flat() != 36722) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_import_jsonl() != 34023) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_import_npy() != 40209) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_import_npz() != 15816) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert() != 53129) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_batch() != 13453) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_batch_cancellable() != 29911) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_batch_flat() != 64363) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_batch_keyed() != 45321) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_batch_serial() != 59973) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_batch_with_namespace() != 2219) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_batch_with_progress() != 52002) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_batch_with_qos() != 45117) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_document() != 51636) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_keyed() != 55782) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_session() != 29095) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_text() != 21101) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_texts() != 55430) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_with_attrs() != 65443) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_with_namespace() != 53134) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_with_payload() != 19626) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_insert_with_timestamp() != 46299) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_is_empty() != 40873) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_is_hidden() != 55217) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_is_loaded() != 58650) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_is_mmapped() != 53409) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_is_read_only() != 9315) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_kmeans() != 46217) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_len() != 807) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_maintain() != 47396) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_memory_footprint() != 62328) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hnsw_checksum_method_hnswindex_get_dimension() != 59616) {
+    if (uniffi_hnsw_checksum_method_hnswindex_migrate() != 54150) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hnsw_checksum_method_hnswindex_insert() != 53129) {
+    if (uniffi_hnsw_checksum_method_hnswindex_purge_older_than() != 21731) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hnsw_checksum_method_hnswindex_insert_batch() != 13453) {
+    if (uniffi_hnsw_checksum_method_hnswindex_rebuild() != 44828) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hnsw_checksum_method_hnswindex_is_empty() != 40873) {
+    if (uniffi_hnsw_checksum_method_hnswindex_reload_if_changed() != 56567) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hnsw_checksum_method_hnswindex_len() != 807) {
+    if (uniffi_hnsw_checksum_method_hnswindex_remaining_capacity() != 3809) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_remove_batch() != 33015) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_repair_graph() != 29646) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_reserve() != 30525) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_residency_stats() != 54312) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_hnsw_checksum_method_hnswindex_save() != 38703) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_hnsw_checksum_method_hnswindex_save_bundle() != 14363) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_save_cancellable() != 28753) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_save_usearch() != 19287) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_save_with_qos() != 7694) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_hnsw_checksum_method_hnswindex_search() != 8428) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_auto() != 32491) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_batch_flat() != 44603) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_bounded() != 19825) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_bq() != 2142) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_centroid() != 6764) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_documents() != 10699) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_exact() != 19200) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_expression() != 17699) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_filtered() != 52982) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_flat() != 60650) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_grouped() != 53708) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_in_namespace() != 52322) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_keyed() != 15272) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_page() != 51688) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_since() != 6538) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_text() != 7496) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_with_options() != 15642) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_search_with_stats() != 42836) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_attrs() != 56581) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_auto_grow() != 27101) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_binary_sketches() != 65240) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_default_ef_search() != 64021) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_dimension_weights() != 34950) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_embedder() != 54557) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_extend_candidates() != 55850) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_hidden() != 41092) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_keep_pruned() != 53697) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_max_waiting_searches() != 29260) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_memory_pressure_handler() != 14601) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_observer() != 64297) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_payload() != 30178) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_privacy_epsilon() != 57042) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_query_transform() != 33103) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_hnsw_checksum_method_hnswindex_set_searching_mode() != 1259) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_hnsw_checksum_method_hnswindex_set_timestamp() != 32976) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_shrink_to_fit() != 15842) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_to_bytes() != 56561) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_try_search() != 43883) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_tune_ef_search() != 22473) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindex_verify_integrity() != 15850) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindexview_distance() != 13689) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswindexview_search() != 15175) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswpqindex_get_dimension() != 60050) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswpqindex_insert() != 15670) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswpqindex_insert_batch() != 1496) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswpqindex_is_empty() != 19092) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswpqindex_is_trained() != 59686) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswpqindex_len() != 16632) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswpqindex_save() != 12930) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswpqindex_search() != 9189) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswpqindex_train_pq() != 23065) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswsq8index_get_dimension() != 30639) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswsq8index_insert() != 35660) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswsq8index_insert_batch() != 23053) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswsq8index_is_empty() != 51794) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswsq8index_is_trained() != 23789) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswsq8index_len() != 8334) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswsq8index_save() != 11382) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswsq8index_search() != 10031) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswsq8index_train() != 59384) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswu16index_get_dimension() != 35327) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswu16index_insert() != 57686) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswu16index_insert_batch() != 35682) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswu16index_is_empty() != 49162) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswu16index_len() != 58778) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswu16index_save() != 53278) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hnswu16index_search() != 19630) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hybridsearcher_hybrid_search() != 55733) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hybridsearcher_refresh() != 5883) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_hybridsearcher_reindex() != 30566) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_insertsession_commit() != 40696) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_insertsession_pending() != 41040) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_insertsession_push() != 46153) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_multiindexsearcher_index_count() != 54410) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_multiindexsearcher_search_all() != 7145) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_querysession_reset() != 12237) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_querysession_search() != 37585) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_searchcursor_is_exhausted() != 17262) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_searchcursor_next_page() != 13347) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_searchcursor_position() != 27357) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_cancellationtoken_new() != 46761) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_dimreducer_random_projection() != 37551) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_dimreducer_train_pca() != 9391) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswcollection_open() != 10706) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswf64index_load() != 28684) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswf64index_new() != 38949) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswi32index_load() != 18906) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswi32index_new() != 20422) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswindex_from_bytes() != 4392) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswindex_from_hnswlib() != 13905) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_hnsw_checksum_constructor_hnswindex_load() != 19516) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_hnsw_checksum_constructor_hnswindex_load_bundle() != 60484) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswindex_load_cancellable() != 61738) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswindex_load_lazy() != 49308) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswindex_load_read_only() != 49531) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswindex_load_resource() != 60329) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswindex_load_usearch() != 16176) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswindex_load_with_progress() != 61055) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_hnsw_checksum_constructor_hnswindex_new() != 3397) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_hnsw_checksum_constructor_hnswindex_new_with_reducer() != 15914) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswpqindex_load() != 38008) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswpqindex_new() != 23455) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswsq8index_load() != 34664) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswsq8index_new() != 53081) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswu16index_load() != 47926) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hnswu16index_new() != 1946) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_hybridsearcher_new() != 39323) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_multiindexsearcher_new() != 56753) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_querysession_new() != 5015) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_constructor_searchcursor_new() != 55689) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_embedder_embed() != 46139) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_indexobserver_on_insert() != 63304) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_indexobserver_on_remove() != 44237) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_indexobserver_on_save() != 18649) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_indexobserver_on_capacity_warning() != 45607) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_loglistener_on_log() != 22450) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_migrationembedder_embed() != 12955) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_progresslistener_on_progress() != 52552) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_signpostlistener_begin_interval() != 45881) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hnsw_checksum_method_signpostlistener_end_interval() != 48121) {
+        return InitializationResult.apiChecksumMismatch
+    }
 
+    uniffiCallbackInitEmbedder()
+    uniffiCallbackInitIndexObserver()
+    uniffiCallbackInitLogListener()
+    uniffiCallbackInitMigrationEmbedder()
+    uniffiCallbackInitProgressListener()
+    uniffiCallbackInitSignpostListener()
     return InitializationResult.ok
 }()
 
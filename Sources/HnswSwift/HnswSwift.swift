@@ -23,6 +23,22 @@ public struct HnswSearchResult: Sendable {
 }
 
 public typealias HnswIndexConfig = HnswFFI.HnswIndexConfig
+public typealias HnswExportFormat = HnswFFI.ExportFormat
+public typealias HnswJsonlImportReport = HnswFFI.JsonlImportReport
+public typealias HnswChangeApplyReport = HnswFFI.ChangeApplyReport
+public typealias HnswIntegrityReport = HnswFFI.IntegrityReport
+
+private final class ProgressRelay: ProgressListener {
+    private let handler: @Sendable (UInt64, UInt64) -> Void
+
+    init(_ handler: @escaping @Sendable (UInt64, UInt64) -> Void) {
+        self.handler = handler
+    }
+
+    func onProgress(done: UInt64, total: UInt64) {
+        handler(done, total)
+    }
+}
 
 public extension HnswFFI.HnswIndexConfig {
     init(
@@ -31,7 +47,9 @@ public extension HnswFFI.HnswIndexConfig {
         maxLayers: UInt32 = 16,
         efConstruction: UInt32 = 200,
         dimension: UInt32,
-        distanceType: HnswFFI.DistanceType = .cosine
+        distanceType: HnswFFI.DistanceType = .cosine,
+        normalizeVectors: Bool = false,
+        levelScale: Double? = nil
     ) {
         self.init(
             maxNbConnection: maxConnections,
@@ -39,7 +57,9 @@ public extension HnswFFI.HnswIndexConfig {
             maxLayer: maxLayers,
             efConstruction: efConstruction,
             dimension: dimension,
-            distance: distanceType
+            distance: distanceType,
+            normalizeVectors: normalizeVectors,
+            levelScale: levelScale
         )
     }
 }
@@ -56,7 +76,9 @@ public actor HnswIndex {
         maxLayers: UInt32 = 16,
         efConstruction: UInt32 = 200,
         dimension: UInt32,
-        distanceType: HnswDistanceType = .cosine
+        distanceType: HnswDistanceType = .cosine,
+        normalizeVectors: Bool = false,
+        levelScale: Double? = nil
     ) {
         let config = HnswIndexConfig(
             maxConnections: maxConnections,
@@ -64,7 +86,9 @@ public actor HnswIndex {
             maxLayers: maxLayers,
             efConstruction: efConstruction,
             dimension: dimension,
-            distanceType: distanceType
+            distanceType: distanceType,
+            normalizeVectors: normalizeVectors,
+            levelScale: levelScale
         )
         self.index = HnswFFI.HnswIndex(config: config)
         self.distanceType = distanceType
@@ -84,6 +108,20 @@ public actor HnswIndex {
         self.config = config
     }
 
+    private init(index: HnswFFI.HnswIndex) {
+        self.init(index: index, distanceType: index.getDistance(), deletedIds: [], config: nil)
+    }
+
+    // Turns ids passed to delete into removals in the Rust index, for
+    // operations that read or write ids the tombstone set can't filter.
+    private func flushDeletes() throws {
+        guard !deletedIds.isEmpty else {
+            return
+        }
+        _ = try index.removeBatch(ids: Array(deletedIds))
+        deletedIds.removeAll()
+    }
+
     private static func tombstoneURL(directory: String, basename: String) -> URL {
         URL(fileURLWithPath: directory)
             .appendingPathComponent("\(basename).deleted")
@@ -149,17 +187,73 @@ public actor HnswIndex {
         return HnswIndex(index: ffiIndex, distanceType: distanceType, deletedIds: deletedIds, config: config)
     }
     
+    public static func loadBundle(path: String) throws -> HnswIndex {
+        try HnswIndex(index: HnswFFI.HnswIndex.loadBundle(path: path))
+    }
+
+    public static func fromBytes(_ bytes: Data) throws -> HnswIndex {
+        try HnswIndex(index: HnswFFI.HnswIndex.fromBytes(bytes: bytes))
+    }
+
+    public static func fromHnswlib(
+        path: String,
+        dimension: UInt32,
+        distanceType: HnswDistanceType
+    ) throws -> HnswIndex {
+        try HnswIndex(
+            index: HnswFFI.HnswIndex.fromHnswlib(
+                path: path,
+                dimension: dimension,
+                distanceType: distanceType
+            )
+        )
+    }
+
+    public static func loadUsearch(path: String) throws -> HnswIndex {
+        try HnswIndex(index: HnswFFI.HnswIndex.loadUsearch(path: path))
+    }
+    
     public func insert(vector: [Float], id: UInt64) throws {
-        deletedIds.remove(id)
+        if deletedIds.contains(id) {
+            try flushDeletes()
+        }
         try index.insert(data: vector, id: id)
     }
+
+    public func insert(vector: [Float], id: UInt64, payload: Data) throws {
+        if deletedIds.contains(id) {
+            try flushDeletes()
+        }
+        try index.insertWithPayload(data: vector, id: id, payload: payload)
+    }
     
     public func insertBatch(vectors: [[Float]], ids: [UInt64]) throws {
-        for id in ids {
-            deletedIds.remove(id)
+        if ids.contains(where: { deletedIds.contains($0) }) {
+            try flushDeletes()
         }
         try index.insertBatch(data: vectors, ids: ids)
     }
+
+    public func contains(id: UInt64) throws -> Bool {
+        guard !deletedIds.contains(id) else {
+            return false
+        }
+        return try index.contains(id: id)
+    }
+
+    public func vector(id: UInt64) throws -> [Float]? {
+        guard !deletedIds.contains(id) else {
+            return nil
+        }
+        return try index.getPointData(id: id)
+    }
+
+    public func payload(id: UInt64) throws -> Data? {
+        guard !deletedIds.contains(id) else {
+            return nil
+        }
+        return try index.getPayload(id: id)
+    }
     
     public func search(query: [Float], k: UInt32, efSearch: UInt32? = nil) throws -> [HnswSearchResult] {
         let ef = efSearch ?? max(k, 50)
@@ -225,4 +319,89 @@ public actor HnswIndex {
     public func setSearchingMode(enabled: Bool) throws {
         try index.setSearchingMode(enabled: enabled)
     }
+
+    public func saveBundle(path: String) throws {
+        try flushDeletes()
+        try index.saveBundle(path: path)
+    }
+
+    public func toBytes() throws -> Data {
+        try flushDeletes()
+        return try index.toBytes()
+    }
+
+    public func saveUsearch(path: String) throws {
+        try flushDeletes()
+        try index.saveUsearch(path: path)
+    }
+
+    public func importNpy(path: String, idOffset: UInt64 = 0) throws -> UInt64 {
+        try flushDeletes()
+        return try index.importNpy(path: path, idOffset: idOffset)
+    }
+
+    public func importNpz(
+        path: String,
+        vectorsName: String = "vectors",
+        idsName: String? = "ids",
+        idOffset: UInt64 = 0
+    ) throws -> UInt64 {
+        try flushDeletes()
+        return try index.importNpz(path: path, vectorsName: vectorsName, idsName: idsName, idOffset: idOffset)
+    }
+
+    public func importJsonl(
+        path: String,
+        vectorField: String = "vector",
+        idField: String = "id",
+        payloadFields: [String] = []
+    ) throws -> HnswJsonlImportReport {
+        try flushDeletes()
+        return try index.importJsonl(
+            path: path,
+            vectorField: vectorField,
+            idField: idField,
+            payloadFields: payloadFields
+        )
+    }
+
+    public func importArrow(
+        path: String,
+        vectorColumn: String = "vector",
+        idColumn: String = "id"
+    ) throws -> UInt64 {
+        try flushDeletes()
+        return try index.importArrow(path: path, vectorColumn: vectorColumn, idColumn: idColumn)
+    }
+
+    public func importFaissFlat(
+        path: String,
+        onProgress: @escaping @Sendable (UInt64, UInt64) -> Void = { _, _ in }
+    ) throws -> UInt64 {
+        try flushDeletes()
+        return try index.importFaissFlat(path: path, listener: ProgressRelay(onProgress))
+    }
+
+    public func exportAll(path: String, format: HnswExportFormat) throws -> UInt64 {
+        try flushDeletes()
+        return try index.exportAll(path: path, format: format)
+    }
+
+    public func changeVersion() throws -> UInt64 {
+        try index.changeVersion()
+    }
+
+    public func exportChanges(sinceVersion: UInt64 = 0) throws -> Data {
+        try flushDeletes()
+        return try index.exportChanges(sinceVersion: sinceVersion)
+    }
+
+    public func applyChanges(_ changes: Data) throws -> HnswChangeApplyReport {
+        try flushDeletes()
+        return try index.applyChanges(changes: changes)
+    }
+
+    public func verifyIntegrity(deep: Bool = false) throws -> HnswIntegrityReport {
+        try index.verifyIntegrity(deep: deep)
+    }
 }
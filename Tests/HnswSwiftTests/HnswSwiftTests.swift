@@ -209,3 +209,278 @@ import Testing
     await index.delete(id: 0)
     #expect(try await index.count() == 1)
 }
+
+private extension Data {
+    mutating func appendLittleEndian<T: FixedWidthInteger>(_ value: T) {
+        withUnsafeBytes(of: value.littleEndian) { append(contentsOf: $0) }
+    }
+
+    mutating func appendLittleEndian(_ value: Float) {
+        appendLittleEndian(value.bitPattern)
+    }
+
+    mutating func appendLittleEndian(_ value: Double) {
+        appendLittleEndian(value.bitPattern)
+    }
+}
+
+private func makeTestDirectory(_ name: String) throws -> URL {
+    let testDir = FileManager.default.temporaryDirectory
+        .appendingPathComponent("\(name)_\(UUID().uuidString)")
+    try FileManager.default.createDirectory(at: testDir, withIntermediateDirectories: true)
+    return testDir
+}
+
+private func makeIndex() -> HnswIndex {
+    HnswIndex(
+        maxConnections: 16,
+        maxElements: 1000,
+        maxLayers: 16,
+        efConstruction: 200,
+        dimension: 2,
+        distanceType: .l2
+    )
+}
+
+private let sampleVectors: [[Float]] = [[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]
+
+@Test func testReinsertDeletedId() async throws {
+    let index = makeIndex()
+    try await index.insert(vector: [1.0, 0.0], id: 0)
+    try await index.insert(vector: [0.0, 1.0], id: 1)
+
+    await index.delete(id: 1)
+    #expect(try await index.contains(id: 1) == false)
+
+    try await index.insert(vector: [0.5, 0.5], id: 1)
+    #expect(try await index.count() == 2)
+    #expect(try await index.vector(id: 1) == [0.5, 0.5])
+}
+
+@Test func testNpyImport() async throws {
+    let testDir = try makeTestDirectory("hnsw_npy_test")
+    defer {
+        try? FileManager.default.removeItem(at: testDir)
+    }
+
+    var header = "{'descr': '<f4', 'fortran_order': False, 'shape': (3, 2), }"
+    while (10 + header.utf8.count + 1) % 64 != 0 {
+        header += " "
+    }
+    header += "\n"
+    var data = Data([0x93]) + Data("NUMPY".utf8) + Data([1, 0])
+    data.appendLittleEndian(UInt16(header.utf8.count))
+    data.append(contentsOf: header.utf8)
+    for value in sampleVectors.joined() {
+        data.appendLittleEndian(value)
+    }
+    let url = testDir.appendingPathComponent("vectors.npy")
+    try data.write(to: url)
+
+    let index = makeIndex()
+    #expect(try await index.importNpy(path: url.path, idOffset: 10) == 3)
+    #expect(try await index.vector(id: 12) == [1.0, 1.0])
+}
+
+@Test func testExportImportRoundTrip() async throws {
+    let testDir = try makeTestDirectory("hnsw_export_test")
+    defer {
+        try? FileManager.default.removeItem(at: testDir)
+    }
+
+    let index = makeIndex()
+    try await index.insertBatch(vectors: sampleVectors, ids: [0, 1, 2])
+    await index.delete(id: 1)
+
+    let formats: [HnswExportFormat] = [.npz, .jsonl, .arrow]
+    for format in formats {
+        let path = testDir.appendingPathComponent("export.\(format)").path
+        #expect(try await index.exportAll(path: path, format: format) == 2)
+
+        let imported = makeIndex()
+        switch format {
+        case .npz:
+            #expect(try await imported.importNpz(path: path) == 2)
+        case .jsonl:
+            let report = try await imported.importJsonl(path: path)
+            #expect(report.imported == 2)
+            #expect(report.errors.isEmpty)
+        case .arrow:
+            #expect(try await imported.importArrow(path: path) == 2)
+        }
+        #expect(try await imported.count() == 2)
+        #expect(try await imported.vector(id: 2) == [1.0, 1.0])
+        #expect(try await imported.contains(id: 1) == false)
+    }
+}
+
+@Test func testFaissFlatImport() async throws {
+    let testDir = try makeTestDirectory("hnsw_faiss_test")
+    defer {
+        try? FileManager.default.removeItem(at: testDir)
+    }
+
+    // IndexIDMap2 around IndexFlatL2, as faiss.write_index lays them out.
+    func appendHeader(_ data: inout Data, fourcc: String) {
+        data.append(contentsOf: fourcc.utf8)
+        data.appendLittleEndian(Int32(2))
+        data.appendLittleEndian(Int64(3))
+        data.append(Data(count: 16))
+        data.append(UInt8(1))
+        data.appendLittleEndian(Int32(1))
+    }
+    var data = Data()
+    appendHeader(&data, fourcc: "IxM2")
+    appendHeader(&data, fourcc: "IxFI")
+    data.appendLittleEndian(UInt64(6))
+    for value in sampleVectors.joined() {
+        data.appendLittleEndian(value)
+    }
+    data.appendLittleEndian(UInt64(3))
+    for id: Int64 in [7, 8, 9] {
+        data.appendLittleEndian(id)
+    }
+    let url = testDir.appendingPathComponent("flat.faiss")
+    try data.write(to: url)
+
+    let index = makeIndex()
+    #expect(try await index.importFaissFlat(path: url.path) == 3)
+    #expect(try await index.vector(id: 9) == [1.0, 1.0])
+}
+
+@Test func testHnswlibImport() async throws {
+    let testDir = try makeTestDirectory("hnsw_hnswlib_test")
+    defer {
+        try? FileManager.default.removeItem(at: testDir)
+    }
+
+    let m = 4
+    let maxM0 = 2 * m
+    let offsetData = maxM0 * 4 + 4
+    let labelOffset = offsetData + 2 * MemoryLayout<Float>.size
+    let sizePerElement = labelOffset + 8
+    var data = Data()
+    for value in [0, 3, 3, sizePerElement, labelOffset, offsetData] {
+        data.appendLittleEndian(UInt64(value))
+    }
+    data.appendLittleEndian(Int32(0))
+    data.appendLittleEndian(UInt32(0))
+    for value in [m, maxM0, m] {
+        data.appendLittleEndian(UInt64(value))
+    }
+    data.appendLittleEndian(1.0 / log(Double(m)))
+    data.appendLittleEndian(UInt64(100))
+    for (n, vector) in sampleVectors.enumerated() {
+        var element = Data(count: offsetData)
+        // Element 1 carries hnswlib's delete mark.
+        if n == 1 {
+            element[2] = 1
+        }
+        for value in vector {
+            element.appendLittleEndian(value)
+        }
+        element.appendLittleEndian(UInt64(100 + n))
+        data.append(element)
+    }
+    for _ in sampleVectors {
+        data.appendLittleEndian(UInt32(0))
+    }
+    let url = testDir.appendingPathComponent("index.hnswlib")
+    try data.write(to: url)
+
+    let index = try HnswIndex.fromHnswlib(path: url.path, dimension: 2, distanceType: .l2)
+    #expect(try await index.count() == 2)
+    #expect(try await index.vector(id: 102) == [1.0, 1.0])
+    #expect(try await index.contains(id: 101) == false)
+}
+
+@Test func testUsearchRoundTrip() async throws {
+    let testDir = try makeTestDirectory("hnsw_usearch_test")
+    defer {
+        try? FileManager.default.removeItem(at: testDir)
+    }
+
+    let index = makeIndex()
+    try await index.insertBatch(vectors: sampleVectors, ids: [0, 1, 2])
+    await index.delete(id: 0)
+    let path = testDir.appendingPathComponent("index.usearch").path
+    try await index.saveUsearch(path: path)
+
+    let loaded = try HnswIndex.loadUsearch(path: path)
+    #expect(try await loaded.count() == 2)
+    #expect(try await loaded.vector(id: 2) == [1.0, 1.0])
+    #expect(try await loaded.contains(id: 0) == false)
+}
+
+@Test func testBundleRoundTrip() async throws {
+    let testDir = try makeTestDirectory("hnsw_bundle_test")
+    defer {
+        try? FileManager.default.removeItem(at: testDir)
+    }
+
+    let index = makeIndex()
+    try await index.insert(vector: [1.0, 0.0], id: 0, payload: Data("zero".utf8))
+    try await index.insertBatch(vectors: [[0.0, 1.0], [1.0, 1.0]], ids: [1, 2])
+    await index.delete(id: 1)
+
+    let path = testDir.appendingPathComponent("index.hnswbundle").path
+    try await index.saveBundle(path: path)
+    let fromFile = try HnswIndex.loadBundle(path: path)
+    let bytes = try await index.toBytes()
+    let fromBytes = try HnswIndex.fromBytes(bytes)
+
+    for loaded in [fromFile, fromBytes] {
+        #expect(await loaded.getDimension() == 2)
+        #expect(try await loaded.count() == 2)
+        #expect(try await loaded.contains(id: 1) == false)
+        #expect(try await loaded.payload(id: 0) == Data("zero".utf8))
+        let results = try await loaded.search(query: [1.0, 1.0], k: 1)
+        #expect(results.first?.id == 2)
+        #expect(try await loaded.verifyIntegrity(deep: true).ok)
+    }
+}
+
+@Test func testSaveAndLoadAfterRemoval() async throws {
+    let testDir = try makeTestDirectory("hnsw_removal_test")
+    defer {
+        try? FileManager.default.removeItem(at: testDir)
+    }
+
+    let index = makeIndex()
+    try await index.insertBatch(vectors: sampleVectors, ids: [0, 1, 2])
+    try await index.insert(vector: [-1.0, 0.0], id: 3)
+    await index.delete(id: 1)
+    await index.delete(id: 0)
+    // Reinserting turns the pending deletes into removals in the index.
+    try await index.insert(vector: [0.5, 0.5], id: 0)
+    try await index.save(directory: testDir.path, basename: "removal")
+
+    let loaded = try HnswIndex.load(
+        directory: testDir.path,
+        basename: "removal",
+        dimension: 2,
+        distanceType: .l2
+    )
+    #expect(try await loaded.count() == 3)
+    #expect(try await loaded.contains(id: 1) == false)
+    #expect(try await loaded.vector(id: 0) == [0.5, 0.5])
+    #expect(try await loaded.verifyIntegrity(deep: true).ok)
+}
+
+@Test func testSyncRoundTrip() async throws {
+    let source = makeIndex()
+    try await source.insertBatch(vectors: sampleVectors, ids: [0, 1, 2])
+
+    let replica = makeIndex()
+    let changes = try await source.exportChanges()
+    let first = try await replica.applyChanges(changes)
+    #expect(first.inserted == 3)
+    #expect(try await replica.count() == 3)
+
+    await source.delete(id: 1)
+    let newer = try await source.exportChanges(sinceVersion: first.version)
+    let second = try await replica.applyChanges(newer)
+    #expect(second.removed == 1)
+    #expect(try await replica.count() == 2)
+    #expect(try await replica.contains(id: 1) == false)
+}
@@ -0,0 +1,39 @@
+#!/bin/bash
+
+set -ex
+
+# Android ABIs to build for, as cargo-ndk names them
+ABIS_LIST="arm64-v8a armeabi-v7a x86_64"
+IFS=' ' read -r -a ABIS <<< "${ABIS_LIST}"
+
+OUT_DIR="./out-android"
+BUILD_DIR="./build-android"
+LIB_BASENAME="hnsw"
+
+KOTLIN_DIR="${BUILD_DIR}/kotlin"
+JNILIBS_DIR="${BUILD_DIR}/jniLibs"
+
+case "$(uname -s)" in
+  Darwin) HOST_LIB="lib${LIB_BASENAME}.dylib" ;;
+  *) HOST_LIB="lib${LIB_BASENAME}.so" ;;
+esac
+
+# Requires the Android NDK (ANDROID_NDK_HOME) and `cargo install cargo-ndk`.
+rustup target add aarch64-linux-android armv7-linux-androideabi x86_64-linux-android
+
+rm -rf "${BUILD_DIR}"
+rm -rf "${OUT_DIR}"
+
+# Bindings come from the host build; the API is the same on every target.
+cargo build
+cargo run --bin uniffi-bindgen generate --library "./target/debug/${HOST_LIB}" --language kotlin --out-dir "${OUT_DIR}"
+
+NDK_ARGS=()
+for abi in "${ABIS[@]}"; do
+  NDK_ARGS+=(-t "${abi}")
+done
+# 16 KB page alignment for the 64-bit targets comes from .cargo/config.toml.
+cargo ndk "${NDK_ARGS[@]}" -o "${JNILIBS_DIR}" build --release
+
+mkdir -p "${KOTLIN_DIR}"
+cp -R "${OUT_DIR}/." "${KOTLIN_DIR}/"
@@ -0,0 +1,56 @@
+/* C interface to hnsw-swift, built with `cargo build --features capi`.
+ * Matches src/capi.rs. */
+#ifndef HNSW_H
+#define HNSW_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define HNSW_DISTANCE_L2 0
+#define HNSW_DISTANCE_COSINE 1
+#define HNSW_DISTANCE_L1 2
+#define HNSW_DISTANCE_DOT 3
+
+typedef struct HnswIndex HnswIndex;
+
+typedef struct HnswConfig {
+    uint32_t dimension;
+    uint64_t max_elements;
+    uint32_t max_nb_connection;
+    uint32_t max_layer;
+    uint32_t ef_construction;
+    uint32_t distance; /* HNSW_DISTANCE_* */
+    bool normalize_vectors;
+} HnswConfig;
+
+/* Calls returning int32_t give 0 or a positive count on success and -1 on
+ * failure; the pointer-returning ones give NULL on failure. The message for
+ * the last failure on the calling thread stays valid until the next one. */
+const char *hnsw_last_error(void);
+
+HnswIndex *hnsw_new(const HnswConfig *config);
+HnswIndex *hnsw_load(const char *directory, const char *basename, const HnswConfig *config);
+int32_t hnsw_save(const HnswIndex *index, const char *directory, const char *basename);
+
+int32_t hnsw_insert(const HnswIndex *index, const float *data, size_t len, uint64_t id);
+
+/* Fills out_ids and out_distances, each with room for k values, best first,
+ * and returns the number of results. */
+int32_t hnsw_search(const HnswIndex *index, const float *query, size_t len, uint32_t k,
+                    uint32_t ef_search, uint64_t *out_ids, float *out_distances);
+
+int64_t hnsw_len(const HnswIndex *index);
+
+/* Safe to call with NULL. The index must not be used afterwards. */
+void hnsw_free(HnswIndex *index);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* HNSW_H */
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+use std::sync::PoisonError;
+
+use hnsw_rs::prelude::*;
+
+use crate::{DistanceType, HnswError, HnswIndex, HnswIndexInner, SearchResult, guarded, simd};
+
+// Rocchio's usual ratio: negatives pull the query away a quarter as hard as
+// positives pull it towards them.
+const NEGATIVE_WEIGHT: f32 = 0.25;
+
+fn mean(vectors: &[Vec<f32>], dimension: usize) -> Vec<f32> {
+    let mut sum = vec![0f32; dimension];
+    for vector in vectors {
+        sum.iter_mut().zip(vector).for_each(|(s, x)| *s += x);
+    }
+    if !vectors.is_empty() {
+        sum.iter_mut().for_each(|s| *s /= vectors.len() as f32);
+    }
+    sum
+}
+
+impl HnswIndex {
+    // Search with a vector already in stored form (reduced, normalized),
+    // skipping hidden points and `exclude`.
+    pub(crate) fn search_stored(
+        &self,
+        query: &[f32],
+        k: u32,
+        ef_search: u32,
+        exclude: &HashSet<u64>,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let guard = self.lock_inner()?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let filter = |id: &DataId| meta.visible(*id as u64) && !exclude.contains(&(*id as u64));
+        let filter: Option<&dyn FilterT> = Some(&filter);
+        let (k, ef) = (
+            k as usize,
+            (self.resolve_ef(ef_search) as usize).max(k as usize),
+        );
+        let hits = guarded("search", || match &*guard {
+            HnswIndexInner::L2(inner) => inner.hnsw.search_filter(query, k, ef, filter),
+            HnswIndexInner::Cosine(inner) => inner.hnsw.search_filter(query, k, ef, filter),
+            HnswIndexInner::Dot(inner) => inner.hnsw.search_filter(query, k, ef, filter),
+            HnswIndexInner::L1(inner) => inner.hnsw.search_filter(query, k, ef, filter),
+        })?;
+        Ok(hits.into_iter().map(SearchResult::from).collect())
+    }
+
+    // Dot distance assumes unit vectors and normalizing indexes store them,
+    // so a mean of stored vectors is put back on the unit sphere there.
+    pub(crate) fn restore_norm(&self, vector: &mut [f32]) {
+        if self.normalize || self.distance == DistanceType::Dot {
+            let norm = simd::dot(vector, vector).sqrt();
+            if norm > 0.0 {
+                vector.iter_mut().for_each(|x| *x /= norm);
+            }
+        }
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Mean of the stored vectors of `ids`. With a reducer attached it is in
+    // the reduced space, as stored.
+    #[uniffi::method]
+    pub fn centroid(&self, ids: Vec<u64>) -> Result<Vec<f32>, HnswError> {
+        if ids.is_empty() {
+            return Err(HnswError::InvalidArgument("No ids given".to_string()));
+        }
+        let guard = self.lock_inner()?;
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        // Removed ids can still have a point in the graph until it is purged.
+        let wanted: HashSet<u64> = ids
+            .iter()
+            .copied()
+            .filter(|id| known.contains(id))
+            .collect();
+        let vectors = guard.vectors(&wanted);
+        drop((known, guard));
+        if let Some(missing) = ids.iter().find(|id| !vectors.contains_key(id)) {
+            return Err(HnswError::InvalidArgument(format!("Unknown id: {missing}")));
+        }
+        let vectors: Vec<Vec<f32>> = vectors.into_values().collect();
+        let mut centroid = mean(&vectors, self.dimension as usize);
+        self.restore_norm(&mut centroid);
+        Ok(centroid)
+    }
+
+    // Points like the selection as a whole, not counting the selection.
+    #[uniffi::method]
+    pub fn search_centroid(
+        &self,
+        ids: Vec<u64>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let centroid = self.centroid(ids.clone())?;
+        let selected: HashSet<u64> = ids.into_iter().collect();
+        self.search_stored(&centroid, k, ef_search, &selected)
+    }
+
+    // Relevance feedback: searches from the mean of `positives` moved away
+    // from the mean of `negatives`. Examples are raw vectors, validated and
+    // transformed like any query.
+    #[uniffi::method]
+    pub fn search_expression(
+        &self,
+        positives: Vec<Vec<f32>>,
+        negatives: Vec<Vec<f32>>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        if positives.is_empty() {
+            return Err(HnswError::InvalidArgument(
+                "At least one positive example is needed".to_string(),
+            ));
+        }
+        let prepare = |vectors: Vec<Vec<f32>>| -> Result<Vec<Vec<f32>>, HnswError> {
+            vectors
+                .into_iter()
+                .enumerate()
+                .map(|(i, vector)| self.prepare(vector, i))
+                .collect()
+        };
+        let dimension = self.dimension as usize;
+        let mut query = mean(&prepare(positives)?, dimension);
+        if !negatives.is_empty() {
+            let away = mean(&prepare(negatives)?, dimension);
+            query
+                .iter_mut()
+                .zip(away)
+                .for_each(|(q, n)| *q -= NEGATIVE_WEIGHT * n);
+        }
+        self.restore_norm(&mut query);
+        self.search_stored(&query, k, ef_search, &HashSet::new())
+    }
+}
@@ -0,0 +1,177 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float32Type, Int32Type, Int64Type, UInt32Type, UInt64Type};
+use arrow_array::{
+    Array, ArrayRef, BinaryArray, FixedSizeListArray, Float32Array, RecordBatch, UInt64Array,
+};
+use arrow_ipc::reader::FileReader;
+use arrow_ipc::writer::FileWriter;
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+
+use crate::export::ExportRows;
+use crate::{HnswError, HnswIndex};
+
+const BATCH_ROWS: usize = 1024;
+
+fn arrow_error(e: ArrowError) -> HnswError {
+    HnswError::IoError(e.to_string())
+}
+
+fn invalid(message: impl Into<String>) -> HnswError {
+    HnswError::InvalidArgument(message.into())
+}
+
+fn column<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a ArrayRef, HnswError> {
+    batch
+        .column_by_name(name)
+        .ok_or_else(|| invalid(format!("no column named '{name}'")))
+}
+
+// Accepts FixedSizeList, List and LargeList columns of non-null float32.
+fn vector_rows(column: &ArrayRef) -> Result<Vec<Vec<f32>>, HnswError> {
+    let rows: Vec<ArrayRef> = if let Some(list) = column.as_fixed_size_list_opt() {
+        (0..list.len()).map(|i| list.value(i)).collect()
+    } else if let Some(list) = column.as_list_opt::<i32>() {
+        (0..list.len()).map(|i| list.value(i)).collect()
+    } else if let Some(list) = column.as_list_opt::<i64>() {
+        (0..list.len()).map(|i| list.value(i)).collect()
+    } else {
+        return Err(invalid(format!(
+            "vector column must be a list of float32, got {:?}",
+            column.data_type()
+        )));
+    };
+    rows.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            if column.is_null(i) {
+                return Err(invalid(format!("null vector at row {i}")));
+            }
+            match row.as_primitive_opt::<Float32Type>() {
+                Some(values) if values.null_count() == 0 => Ok(values.values().to_vec()),
+                Some(_) => Err(invalid(format!("vector at row {i} contains nulls"))),
+                None => Err(invalid(format!(
+                    "vector column must be a list of float32, got {:?}",
+                    column.data_type()
+                ))),
+            }
+        })
+        .collect()
+}
+
+fn id_values(column: &ArrayRef) -> Result<Vec<u64>, HnswError> {
+    if column.null_count() > 0 {
+        return Err(invalid("id column contains nulls"));
+    }
+    let negative = |id: i64| invalid(format!("negative id: {id}"));
+    if let Some(ids) = column.as_primitive_opt::<UInt64Type>() {
+        Ok(ids.values().to_vec())
+    } else if let Some(ids) = column.as_primitive_opt::<UInt32Type>() {
+        Ok(ids.values().iter().map(|&id| u64::from(id)).collect())
+    } else if let Some(ids) = column.as_primitive_opt::<Int64Type>() {
+        ids.values()
+            .iter()
+            .map(|&id| u64::try_from(id).map_err(|_| negative(id)))
+            .collect()
+    } else if let Some(ids) = column.as_primitive_opt::<Int32Type>() {
+        ids.values()
+            .iter()
+            .map(|&id| u64::try_from(id).map_err(|_| negative(id.into())))
+            .collect()
+    } else {
+        Err(invalid(format!(
+            "id column must be an integer type, got {:?}",
+            column.data_type()
+        )))
+    }
+}
+
+// With `payloads` a nullable `payload: binary` column follows `id` and
+// `vector`.
+pub(crate) fn write_arrow(
+    path: &str,
+    dimension: u32,
+    rows: &ExportRows,
+    payloads: bool,
+) -> Result<(), HnswError> {
+    let dimension = dimension as i32;
+    let item = Arc::new(Field::new_list_field(DataType::Float32, false));
+    let mut fields = vec![
+        Field::new("id", DataType::UInt64, false),
+        Field::new(
+            "vector",
+            DataType::FixedSizeList(Arc::clone(&item), dimension),
+            false,
+        ),
+    ];
+    if payloads {
+        fields.push(Field::new("payload", DataType::Binary, true));
+    }
+    let schema = Arc::new(Schema::new(fields));
+    let mut writer =
+        FileWriter::try_new(BufWriter::new(File::create(path)?), &schema).map_err(arrow_error)?;
+    for chunk in rows.chunks(BATCH_ROWS) {
+        let ids = UInt64Array::from(chunk.iter().map(|row| row.id).collect::<Vec<u64>>());
+        let values = Float32Array::from(
+            chunk
+                .iter()
+                .flat_map(|row| row.vector.iter().copied())
+                .collect::<Vec<f32>>(),
+        );
+        let vectors =
+            FixedSizeListArray::try_new(Arc::clone(&item), dimension, Arc::new(values), None)
+                .map_err(arrow_error)?;
+        let mut columns: Vec<ArrayRef> = vec![Arc::new(ids), Arc::new(vectors)];
+        if payloads {
+            columns.push(Arc::new(BinaryArray::from(
+                chunk
+                    .iter()
+                    .map(|row| row.payload.as_deref())
+                    .collect::<Vec<Option<&[u8]>>>(),
+            )));
+        }
+        let batch = RecordBatch::try_new(Arc::clone(&schema), columns).map_err(arrow_error)?;
+        writer.write(&batch).map_err(arrow_error)?;
+    }
+    writer.finish().map_err(arrow_error)?;
+    Ok(())
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Reads an Arrow IPC file one record batch at a time. Batches already
+    // inserted stay in the index if a later one fails. Returns the row count.
+    #[uniffi::method]
+    pub fn import_arrow(
+        &self,
+        path: String,
+        vector_column: String,
+        id_column: String,
+    ) -> Result<u64, HnswError> {
+        self.check_writable()?;
+        let reader =
+            FileReader::try_new(BufReader::new(File::open(path)?), None).map_err(arrow_error)?;
+        let mut imported = 0;
+        for batch in reader {
+            let batch = batch.map_err(arrow_error)?;
+            let data = vector_rows(column(&batch, &vector_column)?)?;
+            let ids = id_values(column(&batch, &id_column)?)?;
+            imported += data.len() as u64;
+            self.insert_batch(data, ids)?;
+        }
+        Ok(imported)
+    }
+
+    // Writes `id: uint64` and `vector: fixed_size_list<float32>` columns.
+    // Vectors are written as stored, i.e. after normalization and dimension
+    // reduction.
+    #[uniffi::method]
+    pub fn export_arrow(&self, path: String) -> Result<u64, HnswError> {
+        let rows = self.export_rows()?;
+        write_arrow(&path, self.dimension, &rows, false)?;
+        Ok(rows.ids().len() as u64)
+    }
+}
@@ -0,0 +1,388 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::Chars;
+use std::sync::PoisonError;
+
+use hnsw_rs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{HnswError, HnswIndex, HnswIndexInner, SearchResult, guarded};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, uniffi::Enum)]
+pub enum AttrValue {
+    Int { value: i64 },
+    Float { value: f64 },
+    Text { value: String },
+    Bool { value: bool },
+}
+
+impl AttrValue {
+    // Ints and floats compare numerically with each other; any other mix of
+    // types is incomparable.
+    fn compare(&self, other: &AttrValue) -> Option<Ordering> {
+        match (self, other) {
+            (AttrValue::Int { value: a }, AttrValue::Int { value: b }) => Some(a.cmp(b)),
+            (AttrValue::Int { value: a }, AttrValue::Float { value: b }) => {
+                (*a as f64).partial_cmp(b)
+            }
+            (AttrValue::Float { value: a }, AttrValue::Int { value: b }) => {
+                a.partial_cmp(&(*b as f64))
+            }
+            (AttrValue::Float { value: a }, AttrValue::Float { value: b }) => a.partial_cmp(b),
+            (AttrValue::Text { value: a }, AttrValue::Text { value: b }) => Some(a.cmp(b)),
+            (AttrValue::Bool { value: a }, AttrValue::Bool { value: b }) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+
+    fn heap_bytes(&self) -> usize {
+        match self {
+            AttrValue::Text { value } => value.len(),
+            _ => 0,
+        }
+    }
+}
+
+pub(crate) type Attrs = HashMap<String, AttrValue>;
+
+pub(crate) fn attrs_heap_bytes(attrs: &Attrs) -> usize {
+    attrs.capacity() * 48
+        + attrs
+            .iter()
+            .map(|(key, value)| key.len() + value.heap_bytes())
+            .sum::<usize>()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Text(String),
+    Number(String),
+    Op(Op),
+    Open,
+    Close,
+}
+
+// Parsed form of a filter expression such as
+//   lang == "en" AND (ts > 1700000000 OR NOT archived == true)
+// Keys are bare identifiers or quoted strings; values are quoted strings,
+// numbers, or true/false. A comparison against a missing attribute or a
+// value of another type is false.
+#[derive(Debug, Clone)]
+pub(crate) enum Filter {
+    Compare(String, Op, AttrValue),
+    Has(String),
+    And(Vec<Filter>),
+    Or(Vec<Filter>),
+    Not(Box<Filter>),
+}
+
+impl Filter {
+    pub(crate) fn parse(expression: &str) -> Result<Filter, HnswError> {
+        let tokens = tokenize(expression)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let filter = parser.or()?;
+        if parser.pos != parser.tokens.len() {
+            return Err(invalid(format!(
+                "unexpected {:?} in filter",
+                parser.tokens[parser.pos]
+            )));
+        }
+        Ok(filter)
+    }
+
+    pub(crate) fn matches(&self, attrs: Option<&Attrs>) -> bool {
+        match self {
+            Filter::Compare(key, op, value) => {
+                let Some(ordering) = attrs
+                    .and_then(|a| a.get(key))
+                    .and_then(|stored| stored.compare(value))
+                else {
+                    return false;
+                };
+                match op {
+                    Op::Eq => ordering.is_eq(),
+                    Op::Ne => ordering.is_ne(),
+                    Op::Lt => ordering.is_lt(),
+                    Op::Le => ordering.is_le(),
+                    Op::Gt => ordering.is_gt(),
+                    Op::Ge => ordering.is_ge(),
+                }
+            }
+            Filter::Has(key) => attrs.is_some_and(|a| a.contains_key(key)),
+            Filter::And(filters) => filters.iter().all(|f| f.matches(attrs)),
+            Filter::Or(filters) => filters.iter().any(|f| f.matches(attrs)),
+            Filter::Not(filter) => !filter.matches(attrs),
+        }
+    }
+}
+
+fn invalid(msg: String) -> HnswError {
+    HnswError::InvalidArgument(msg)
+}
+
+fn quoted(chars: &mut Peekable<Chars>) -> Result<String, HnswError> {
+    let mut text = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(text),
+            Some('\\') => match chars.next() {
+                Some(c) => text.push(c),
+                None => break,
+            },
+            Some(c) => text.push(c),
+            None => break,
+        }
+    }
+    Err(invalid("unterminated string in filter".to_string()))
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token>, HnswError> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        let token = match c {
+            c if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            '(' | ')' => {
+                chars.next();
+                if c == '(' { Token::Open } else { Token::Close }
+            }
+            '"' => {
+                chars.next();
+                Token::Text(quoted(&mut chars)?)
+            }
+            '=' | '!' | '<' | '>' => {
+                chars.next();
+                let eq = chars.next_if_eq(&'=').is_some();
+                Token::Op(match (c, eq) {
+                    ('=', true) => Op::Eq,
+                    ('!', true) => Op::Ne,
+                    ('<', false) => Op::Lt,
+                    ('<', true) => Op::Le,
+                    ('>', false) => Op::Gt,
+                    ('>', true) => Op::Ge,
+                    _ => return Err(invalid(format!("unknown operator '{c}' in filter"))),
+                })
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let mut number = String::new();
+                while let Some(c) =
+                    chars.next_if(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'))
+                {
+                    number.push(c);
+                }
+                Token::Number(number)
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut ident = String::new();
+                while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || matches!(c, '_' | '.'))
+                {
+                    ident.push(c);
+                }
+                Token::Ident(ident)
+            }
+            c => return Err(invalid(format!("unexpected '{c}' in filter"))),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn keyword(&mut self, word: &str) -> bool {
+        match self.tokens.get(self.pos) {
+            Some(Token::Ident(ident)) if ident.eq_ignore_ascii_case(word) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn or(&mut self) -> Result<Filter, HnswError> {
+        let mut terms = vec![self.and()?];
+        while self.keyword("or") {
+            terms.push(self.and()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Filter::Or(terms)
+        })
+    }
+
+    fn and(&mut self) -> Result<Filter, HnswError> {
+        let mut terms = vec![self.unary()?];
+        while self.keyword("and") {
+            terms.push(self.unary()?);
+        }
+        Ok(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Filter::And(terms)
+        })
+    }
+
+    fn unary(&mut self) -> Result<Filter, HnswError> {
+        if self.keyword("not") {
+            return Ok(Filter::Not(Box::new(self.unary()?)));
+        }
+        if self.keyword("has") {
+            return Ok(Filter::Has(self.key()?));
+        }
+        if self.tokens.get(self.pos) == Some(&Token::Open) {
+            self.pos += 1;
+            let inner = self.or()?;
+            if self.next() != Some(Token::Close) {
+                return Err(invalid("missing ')' in filter".to_string()));
+            }
+            return Ok(inner);
+        }
+        let key = self.key()?;
+        let Some(Token::Op(op)) = self.next() else {
+            return Err(invalid(format!("expected an operator after '{key}'")));
+        };
+        Ok(Filter::Compare(key, op, self.value()?))
+    }
+
+    fn key(&mut self) -> Result<String, HnswError> {
+        match self.next() {
+            Some(Token::Ident(key) | Token::Text(key)) => Ok(key),
+            other => Err(invalid(format!(
+                "expected an attribute name, got {other:?}"
+            ))),
+        }
+    }
+
+    fn value(&mut self) -> Result<AttrValue, HnswError> {
+        match self.next() {
+            Some(Token::Text(value)) => Ok(AttrValue::Text { value }),
+            Some(Token::Ident(word)) if word == "true" || word == "false" => Ok(AttrValue::Bool {
+                value: word == "true",
+            }),
+            Some(Token::Number(number)) => {
+                if let Ok(value) = number.parse::<i64>() {
+                    Ok(AttrValue::Int { value })
+                } else {
+                    number
+                        .parse::<f64>()
+                        .map(|value| AttrValue::Float { value })
+                        .map_err(|_| invalid(format!("invalid number '{number}' in filter")))
+                }
+            }
+            other => Err(invalid(format!("expected a value, got {other:?}"))),
+        }
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    #[uniffi::method]
+    pub fn insert_with_attrs(
+        &self,
+        data: Vec<f32>,
+        id: u64,
+        attrs: HashMap<String, AttrValue>,
+    ) -> Result<(), HnswError> {
+        self.insert_with(data, id, |meta| {
+            meta.attrs.insert(id, attrs);
+        })
+    }
+
+    // Replaces all attributes of `id`; an empty map clears them.
+    #[uniffi::method]
+    pub fn set_attrs(&self, id: u64, attrs: HashMap<String, AttrValue>) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let _guard = self.lock_inner()?;
+        if !self
+            .ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains(&id)
+        {
+            return Err(HnswError::InvalidArgument(format!("Unknown id: {id}")));
+        }
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        if attrs.is_empty() {
+            meta.attrs.remove(&id);
+        } else {
+            meta.attrs.insert(id, attrs);
+        }
+        meta.touch(&[id]);
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn get_attrs(&self, id: u64) -> Result<HashMap<String, AttrValue>, HnswError> {
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(meta.attrs.get(&id).cloned().unwrap_or_default())
+    }
+
+    // Visible points matching a search_filtered expression, without running a
+    // search. Walks every point, so cost is linear in the index size.
+    #[uniffi::method]
+    pub fn count_filtered(&self, filter: String) -> Result<u64, HnswError> {
+        let parsed = Filter::parse(&filter)?;
+        let _guard = self.lock_inner()?;
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(known
+            .iter()
+            .filter(|&&id| meta.visible(id) && parsed.matches(meta.attrs.get(&id)))
+            .count() as u64)
+    }
+
+    // The filter is checked during graph traversal rather than on the
+    // results, but the traversal still gives up after ef_search candidates,
+    // so a filter that few points pass can return fewer than k. A larger
+    // ef_search finds more of them.
+    #[uniffi::method]
+    pub fn search_filtered(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+        filter: String,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let parsed = Filter::parse(&filter)?;
+        let query = self.prepare_query(query, 0)?;
+        let guard = self.lock_inner()?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let filter =
+            |id: &DataId| meta.visible(*id as u64) && parsed.matches(meta.attrs.get(&(*id as u64)));
+        let filter: Option<&dyn FilterT> = Some(&filter);
+        let (k, ef_search) = (k as usize, self.resolve_ef(ef_search) as usize);
+        let results = guarded("search_filtered", || match &*guard {
+            HnswIndexInner::L2(inner) => inner.hnsw.search_filter(&query, k, ef_search, filter),
+            HnswIndexInner::Cosine(inner) => inner.hnsw.search_filter(&query, k, ef_search, filter),
+            HnswIndexInner::Dot(inner) => inner.hnsw.search_filter(&query, k, ef_search, filter),
+            HnswIndexInner::L1(inner) => inner.hnsw.search_filter(&query, k, ef_search, filter),
+        })?;
+        Ok(results.into_iter().map(SearchResult::from).collect())
+    }
+}
@@ -0,0 +1,85 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+use crate::{HnswError, HnswIndex, HnswIndexConfig, guarded};
+
+struct Shared<T> {
+    result: Option<Result<T, HnswError>>,
+    waker: Option<Waker>,
+}
+
+// Runs blocking work on its own thread and resolves when it's done. The
+// foreign executor only polls it, so an awaiting Swift task or Kotlin
+// coroutine never has its thread blocked by the work.
+struct Background<T>(Arc<Mutex<Shared<T>>>);
+
+impl<T: Send + 'static> Background<T> {
+    fn spawn(
+        operation: &'static str,
+        f: impl FnOnce() -> Result<T, HnswError> + Send + 'static,
+    ) -> Self {
+        let shared = Arc::new(Mutex::new(Shared {
+            result: None,
+            waker: None,
+        }));
+        let done = Arc::clone(&shared);
+        let finish = move |result| {
+            let mut shared = done.lock().unwrap_or_else(PoisonError::into_inner);
+            shared.result = Some(result);
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        };
+        let spawned = thread::Builder::new()
+            .name(format!("hnsw-{operation}"))
+            .spawn({
+                let finish = finish.clone();
+                move || finish(guarded(operation, f).and_then(|result| result))
+            });
+        if let Err(e) = spawned {
+            finish(Err(e.into()));
+        }
+        Background(shared)
+    }
+}
+
+impl<T> Future for Background<T> {
+    type Output = Result<T, HnswError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        match shared.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// `load` or `load_read_only` without blocking the caller, so app startup can
+// await the index instead of stalling the main thread on a large dump.
+#[uniffi::export]
+pub async fn load_index_async(
+    directory: String,
+    basename: String,
+    config: HnswIndexConfig,
+    read_only: bool,
+) -> Result<Arc<HnswIndex>, HnswError> {
+    Background::spawn("load", move || {
+        HnswIndex::open(directory, basename, config, read_only).map(Arc::new)
+    })
+    .await
+}
+
+#[uniffi::export]
+pub async fn load_bundle_async(path: String) -> Result<Arc<HnswIndex>, HnswError> {
+    Background::spawn("load-bundle", move || {
+        HnswIndex::load_bundle(path).map(Arc::new)
+    })
+    .await
+}
@@ -0,0 +1,315 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::PoisonError;
+
+use hnsw_rs::hnsw::{Hnsw, PointId};
+use hnsw_rs::prelude::*;
+use rayon::prelude::*;
+
+use crate::{
+    DistanceType, HnswError, HnswIndex, HnswIndexInner, PointMeta, SearchResult, graph_points, simd,
+};
+
+// One bit per dimension, set when the component lies above the threshold for
+// that dimension. Inserts append their sketches against the thresholds of the
+// last full build. A point count that doesn't match the graph's means some
+// change wasn't tracked, and the sketches are rebuilt from the stored vectors.
+pub(crate) struct BinarySketches {
+    nb_point: usize,
+    thresholds: Vec<f32>,
+    entries: Vec<(u64, Vec<u64>)>,
+    // Where each sketched point sits in the graph, so reranking reads just the
+    // candidates' vectors. Appended points get theirs on the next refresh.
+    points: HashMap<u64, PointId>,
+}
+
+fn sketch(thresholds: &[f32], v: &[f32]) -> Vec<u64> {
+    let mut words = vec![0u64; v.len().div_ceil(64)];
+    for (i, (&x, &t)) in v.iter().zip(thresholds).enumerate() {
+        if x > t {
+            words[i / 64] |= 1 << (i % 64);
+        }
+    }
+    words
+}
+
+fn hamming(a: &[u64], b: &[u64]) -> u32 {
+    a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum()
+}
+
+impl BinarySketches {
+    fn build<D>(hnsw: &Hnsw<'static, f32, D>, dimension: usize, metric: DistanceType) -> Self
+    where
+        D: Distance<f32> + Send + Sync,
+    {
+        let points: Vec<_> = graph_points(hnsw).collect();
+        // Angular metrics use the sign of each component; L1/L2 split each
+        // dimension at its mean so uncentered data still spreads across bits.
+        let mut thresholds = vec![0.0f32; dimension];
+        if matches!(metric, DistanceType::L2 | DistanceType::L1) && !points.is_empty() {
+            for point in &points {
+                for (t, &x) in thresholds.iter_mut().zip(point.get_v()) {
+                    *t += x;
+                }
+            }
+            for t in &mut thresholds {
+                *t /= points.len() as f32;
+            }
+        }
+        let entries = points
+            .par_iter()
+            .map(|point| {
+                (
+                    point.get_origin_id() as u64,
+                    sketch(&thresholds, point.get_v()),
+                )
+            })
+            .collect();
+        let points = points
+            .iter()
+            .map(|point| (point.get_origin_id() as u64, point.get_point_id()))
+            .collect();
+        BinarySketches {
+            nb_point: hnsw.get_nb_point(),
+            thresholds,
+            entries,
+            points,
+        }
+    }
+
+    fn append(&mut self, pairs: &[(&Vec<f32>, usize)]) {
+        let thresholds = &self.thresholds;
+        let appended: Vec<_> = pairs
+            .par_iter()
+            .map(|&(v, id)| (id as u64, sketch(thresholds, v)))
+            .collect();
+        self.entries.extend(appended);
+        self.nb_point += pairs.len();
+    }
+
+    // For a graph rebuilt from the same vectors less `removed`: points keep
+    // their sketches but move, so their places are looked up again.
+    fn relocate(&mut self, removed: &HashSet<u64>, nb_point: usize) {
+        self.entries.retain(|(id, _)| !removed.contains(id));
+        self.points.clear();
+        self.nb_point = nb_point;
+    }
+
+    pub(crate) fn heap_bytes(&self) -> u64 {
+        let words = self.entries.first().map_or(0, |(_, s)| s.len());
+        (self.thresholds.len() * 4
+            + self.entries.len() * (32 + words * 8)
+            + self.points.capacity() * 17) as u64
+    }
+
+    // Forces a rebuild on next use, for when the graph was swapped out under
+    // the sketches without its point count changing.
+    pub(crate) fn invalidate(&mut self) {
+        self.nb_point = usize::MAX;
+    }
+
+    // Returns whether there was anything to do: a rebuild of stale sketches,
+    // or one pass over the graph to place the points appended since the last.
+    fn refresh<D>(
+        &mut self,
+        hnsw: &Hnsw<'static, f32, D>,
+        dimension: usize,
+        metric: DistanceType,
+    ) -> bool
+    where
+        D: Distance<f32> + Send + Sync,
+    {
+        if self.nb_point != hnsw.get_nb_point() {
+            *self = BinarySketches::build(hnsw, dimension, metric);
+            return true;
+        }
+        if self.points.len() == self.entries.len() {
+            return false;
+        }
+        for point in graph_points(hnsw) {
+            self.points
+                .entry(point.get_origin_id() as u64)
+                .or_insert_with(|| point.get_point_id());
+        }
+        true
+    }
+}
+
+fn search_bq<D>(
+    hnsw: &Hnsw<'static, f32, D>,
+    distance: DistanceType,
+    sketches: &BinarySketches,
+    query: &[f32],
+    k: usize,
+    rerank_factor: usize,
+    meta: &PointMeta,
+) -> Vec<SearchResult>
+where
+    D: Distance<f32> + Send + Sync,
+{
+    let query_sketch = sketch(&sketches.thresholds, query);
+    let n_candidates = (k * rerank_factor.max(1)).min(sketches.entries.len());
+    if n_candidates == 0 {
+        return Vec::new();
+    }
+    let mut scored: Vec<(u32, u64)> = sketches
+        .entries
+        .par_iter()
+        .filter(|(id, _)| meta.visible(*id))
+        .map(|(id, s)| (hamming(&query_sketch, s), *id))
+        .collect();
+    let n_candidates = n_candidates.min(scored.len());
+    if n_candidates == 0 {
+        return Vec::new();
+    }
+    scored.select_nth_unstable_by_key(n_candidates - 1, |&(d, _)| d);
+
+    let indexation = hnsw.get_point_indexation();
+    let mut results: Vec<SearchResult> = scored[..n_candidates]
+        .iter()
+        .filter_map(|&(_, id)| {
+            let v = indexation.get_point_data(sketches.points.get(&id)?)?;
+            Some(SearchResult::new(id, simd::eval(distance, query, &v)))
+        })
+        .collect();
+    results.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+    results.truncate(k);
+    results
+}
+
+impl HnswIndex {
+    // Called by every insert path, with the vectors as stored.
+    pub(crate) fn sketch_inserted(&self, pairs: &[(&Vec<f32>, usize)]) {
+        if let Some(sketches) = self
+            .sketches
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            sketches.append(pairs);
+        }
+    }
+
+    // After `guard` was rebuilt without `removed`.
+    pub(crate) fn relocate_sketches(&self, guard: &HnswIndexInner, removed: &HashSet<u64>) {
+        if let Some(sketches) = self
+            .sketches
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            sketches.relocate(removed, guard.nb_point());
+        }
+    }
+
+    // Brings stale sketches up to date now instead of on the next search_bq.
+    // Returns whether there was anything to do.
+    pub(crate) fn refresh_sketches(&self) -> Result<bool, HnswError> {
+        let guard = self.lock_inner()?;
+        let mut sketches = self.sketches.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(sketches) = sketches.as_mut() else {
+            return Ok(false);
+        };
+        let (dimension, metric) = (self.dimension as usize, self.distance);
+        Ok(match &*guard {
+            HnswIndexInner::L2(inner) => sketches.refresh(&inner.hnsw, dimension, metric),
+            HnswIndexInner::Cosine(inner) => sketches.refresh(&inner.hnsw, dimension, metric),
+            HnswIndexInner::Dot(inner) => sketches.refresh(&inner.hnsw, dimension, metric),
+            HnswIndexInner::L1(inner) => sketches.refresh(&inner.hnsw, dimension, metric),
+        })
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    #[uniffi::method]
+    pub fn set_binary_sketches(&self, enabled: bool) -> Result<(), HnswError> {
+        let guard = self.lock_inner()?;
+        let mut sketches = self.sketches.lock().unwrap_or_else(PoisonError::into_inner);
+        if !enabled {
+            *sketches = None;
+            return Ok(());
+        }
+        let (dimension, metric) = (self.dimension as usize, self.distance);
+        *sketches = Some(match &*guard {
+            HnswIndexInner::L2(inner) => BinarySketches::build(&inner.hnsw, dimension, metric),
+            HnswIndexInner::Cosine(inner) => BinarySketches::build(&inner.hnsw, dimension, metric),
+            HnswIndexInner::Dot(inner) => BinarySketches::build(&inner.hnsw, dimension, metric),
+            HnswIndexInner::L1(inner) => BinarySketches::build(&inner.hnsw, dimension, metric),
+        });
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn has_binary_sketches(&self) -> Result<bool, HnswError> {
+        let sketches = self.sketches.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(sketches.is_some())
+    }
+
+    #[uniffi::method]
+    pub fn search_bq(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        rerank_factor: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let query = self.prepare_query(query, 0)?;
+        let guard = self.lock_inner()?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut sketches = self.sketches.lock().unwrap_or_else(PoisonError::into_inner);
+        let sketches = sketches.as_mut().ok_or_else(|| {
+            HnswError::InvalidArgument("Binary sketches are not enabled".to_string())
+        })?;
+        let (dimension, metric) = (self.dimension as usize, self.distance);
+        let (k, factor) = (k as usize, rerank_factor as usize);
+        Ok(match &*guard {
+            HnswIndexInner::L2(inner) => {
+                sketches.refresh(&inner.hnsw, dimension, metric);
+                search_bq(
+                    &inner.hnsw,
+                    DistanceType::L2,
+                    sketches,
+                    &query,
+                    k,
+                    factor,
+                    &meta,
+                )
+            }
+            HnswIndexInner::Cosine(inner) => {
+                sketches.refresh(&inner.hnsw, dimension, metric);
+                search_bq(
+                    &inner.hnsw,
+                    DistanceType::Cosine,
+                    sketches,
+                    &query,
+                    k,
+                    factor,
+                    &meta,
+                )
+            }
+            HnswIndexInner::Dot(inner) => {
+                sketches.refresh(&inner.hnsw, dimension, metric);
+                search_bq(
+                    &inner.hnsw,
+                    DistanceType::Dot,
+                    sketches,
+                    &query,
+                    k,
+                    factor,
+                    &meta,
+                )
+            }
+            HnswIndexInner::L1(inner) => {
+                sketches.refresh(&inner.hnsw, dimension, metric);
+                search_bq(
+                    &inner.hnsw,
+                    DistanceType::L1,
+                    sketches,
+                    &query,
+                    k,
+                    factor,
+                    &meta,
+                )
+            }
+        })
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::{Arc, PoisonError};
+use std::time::{Duration, Instant};
+
+use hnsw_rs::hnsw::Hnsw;
+use hnsw_rs::prelude::*;
+
+use crate::points::PointMap;
+use crate::stats::Traversal;
+use crate::{HnswError, HnswIndex, HnswIndexInner, PointMeta, SearchResult};
+
+// Either limit stops the search where it is; unset means no limit.
+#[derive(Debug, Clone, Copy, Default, uniffi::Record)]
+pub struct SearchLimits {
+    #[uniffi(default = None)]
+    pub max_visited_nodes: Option<u64>,
+    // Counted from the call, so waiting for the graph lock uses it up too, as
+    // does the first search after a change to the graph, which rebuilds the
+    // lookup of its points in time linear in the index size.
+    #[uniffi(default = None)]
+    pub time_budget_us: Option<u64>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct BoundedSearchResults {
+    pub results: Vec<SearchResult>,
+    // A limit was hit, so the results are the best found so far rather than
+    // what an unbounded search would return.
+    pub truncated: bool,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn search_bounded<D>(
+    hnsw: &Hnsw<'static, f32, D>,
+    points: Arc<PointMap>,
+    meta: &PointMeta,
+    query: &[f32],
+    k: usize,
+    ef: usize,
+    max_visited: usize,
+    deadline: Option<Instant>,
+) -> BoundedSearchResults
+where
+    D: Distance<f32> + Send + Sync,
+{
+    let mut traversal = Traversal::new(hnsw, points, query).with_limits(max_visited, deadline);
+    let best = match traversal.descend() {
+        Some((current, _)) => traversal.beam(vec![current], ef.max(k)),
+        None => Vec::new(),
+    };
+    let results = best
+        .into_iter()
+        .filter(|c| meta.visible(c.point.get_origin_id() as u64))
+        .take(k)
+        .map(|c| SearchResult::new(c.point.get_origin_id() as u64, c.distance))
+        .collect();
+    BoundedSearchResults {
+        results,
+        truncated: traversal.truncated,
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // For latency-critical paths such as keyboard suggestions: the search
+    // gives up on more candidates once it has visited `max_visited_nodes`
+    // points or run past `time_budget_us`, trading recall for a bounded worst
+    // case. Hidden points are dropped from the beam's answer, so they can
+    // leave fewer than k results.
+    #[uniffi::method]
+    pub fn search_bounded(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+        limits: SearchLimits,
+    ) -> Result<BoundedSearchResults, HnswError> {
+        let start = Instant::now();
+        let deadline = limits
+            .time_budget_us
+            .map(|us| start + Duration::from_micros(us));
+        let max_visited = limits
+            .max_visited_nodes
+            .map_or(usize::MAX, |n| n.min(usize::MAX as u64) as usize);
+        let query = self.prepare_query(query, 0)?;
+        let guard = self.lock_inner()?;
+        let points = self.point_map(&guard);
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let (k, ef) = (k as usize, self.resolve_ef(ef_search) as usize);
+        Ok(match &*guard {
+            HnswIndexInner::L2(inner) => search_bounded(
+                &inner.hnsw,
+                points,
+                &meta,
+                &query,
+                k,
+                ef,
+                max_visited,
+                deadline,
+            ),
+            HnswIndexInner::Cosine(inner) => search_bounded(
+                &inner.hnsw,
+                points,
+                &meta,
+                &query,
+                k,
+                ef,
+                max_visited,
+                deadline,
+            ),
+            HnswIndexInner::Dot(inner) => search_bounded(
+                &inner.hnsw,
+                points,
+                &meta,
+                &query,
+                k,
+                ef,
+                max_visited,
+                deadline,
+            ),
+            HnswIndexInner::L1(inner) => search_bounded(
+                &inner.hnsw,
+                points,
+                &meta,
+                &query,
+                k,
+                ef,
+                max_visited,
+                deadline,
+            ),
+        })
+    }
+}
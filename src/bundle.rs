@@ -0,0 +1,278 @@
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::sync::atomic::Ordering;
+use std::sync::{Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{HnswError, HnswIndex, HnswIndexConfig};
+
+// Layout: magic, u32 version, u32 section count, then per section a u32 name
+// length, the name, a u64 byte length, the bytes and a u64 FNV-1a checksum of
+// them. Sections are the files `save` writes, keyed by extension, plus the
+// index config as JSON, so a bundle loads without any other input.
+const BUNDLE_MAGIC: &[u8; 8] = b"HNSWBNDL";
+const BUNDLE_VERSION: u32 = 1;
+const CONFIG_SECTION: &str = "config";
+const FILE_SECTIONS: [&str; 4] = ["hnsw.graph", "hnsw.data", "hnsw.meta", "hnsw.reducer"];
+// Basename of the dump inside a staging directory.
+const STAGED: &str = "index";
+
+fn corrupted(msg: impl Into<String>) -> HnswError {
+    HnswError::Corrupted(msg.into())
+}
+
+// Not cryptographic; catches truncation and bit rot from copying.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 ^ b as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+static TEMP_DIRECTORY: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+// Where bundles are unpacked and packed. Defaults to the system temp dir,
+// which Android apps can't write to; pass Context.cacheDir there.
+#[uniffi::export]
+pub fn set_temp_directory(directory: String) {
+    *TEMP_DIRECTORY
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(PathBuf::from(directory));
+}
+
+// A fresh directory under the temp directory, removed on drop.
+pub(crate) struct Staging(PathBuf);
+
+impl Staging {
+    pub(crate) fn new() -> Result<Self, HnswError> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let parent = TEMP_DIRECTORY
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+            .unwrap_or_else(std::env::temp_dir);
+        let path = parent.join(format!("hnsw-bundle-{}-{nanos}", std::process::id()));
+        fs::create_dir_all(&path)?;
+        Ok(Staging(path))
+    }
+
+    pub(crate) fn directory(&self) -> String {
+        self.0.to_string_lossy().into_owned()
+    }
+
+    fn file(&self, section: &str) -> PathBuf {
+        self.0.join(format!("{STAGED}.{section}"))
+    }
+}
+
+impl Drop for Staging {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn write_section(
+    out: &mut impl Write,
+    name: &str,
+    len: u64,
+    mut data: impl Read,
+) -> Result<(), HnswError> {
+    out.write_all(&(name.len() as u32).to_le_bytes())?;
+    out.write_all(name.as_bytes())?;
+    out.write_all(&len.to_le_bytes())?;
+    let mut hash = Fnv1a::new();
+    let mut buf = vec![0u8; 1 << 16];
+    let mut written = 0u64;
+    loop {
+        let n = data.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hash.update(&buf[..n]);
+        out.write_all(&buf[..n])?;
+        written += n as u64;
+    }
+    if written != len {
+        return Err(HnswError::DumpError(format!(
+            "{name} changed size while bundling"
+        )));
+    }
+    out.write_all(&hash.0.to_le_bytes())?;
+    Ok(())
+}
+
+fn read_u32(reader: &mut impl Read) -> Result<u32, HnswError> {
+    let mut buf = [0u8; 4];
+    reader
+        .read_exact(&mut buf)
+        .map_err(|_| corrupted("bundle is truncated"))?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64(reader: &mut impl Read) -> Result<u64, HnswError> {
+    let mut buf = [0u8; 8];
+    reader
+        .read_exact(&mut buf)
+        .map_err(|_| corrupted("bundle is truncated"))?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+// Unpacks every section into `staging`, verifying checksums, and returns the
+// bundled config.
+pub(crate) fn unpack(
+    mut reader: impl Read,
+    staging: &Staging,
+) -> Result<HnswIndexConfig, HnswError> {
+    let mut magic = [0u8; 8];
+    reader
+        .read_exact(&mut magic)
+        .map_err(|_| corrupted("not an index bundle"))?;
+    if &magic != BUNDLE_MAGIC {
+        return Err(corrupted("not an index bundle"));
+    }
+    let version = read_u32(&mut reader)?;
+    if version > BUNDLE_VERSION {
+        return Err(HnswError::UnsupportedVersion {
+            found: version,
+            supported: BUNDLE_VERSION,
+        });
+    }
+    let mut config = None;
+    for _ in 0..read_u32(&mut reader)? {
+        let name_len = read_u32(&mut reader)? as usize;
+        if name_len > 64 {
+            return Err(corrupted("bad section name"));
+        }
+        let mut name = vec![0u8; name_len];
+        reader
+            .read_exact(&mut name)
+            .map_err(|_| corrupted("bundle is truncated"))?;
+        let name = String::from_utf8(name).map_err(|_| corrupted("bad section name"))?;
+        let len = read_u64(&mut reader)?;
+        let mut section = (&mut reader).take(len);
+        let mut hash = Fnv1a::new();
+        let mut bytes = Vec::new();
+        let mut out = if name == CONFIG_SECTION {
+            None
+        } else if FILE_SECTIONS.contains(&name.as_str()) {
+            Some(BufWriter::new(File::create(staging.file(&name))?))
+        } else {
+            return Err(corrupted(format!("unknown section '{name}'")));
+        };
+        let mut buf = vec![0u8; 1 << 16];
+        let mut read = 0u64;
+        loop {
+            let n = section.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            hash.update(&buf[..n]);
+            match &mut out {
+                Some(out) => out.write_all(&buf[..n])?,
+                None => bytes.extend_from_slice(&buf[..n]),
+            }
+            read += n as u64;
+        }
+        if read != len {
+            return Err(corrupted("bundle is truncated"));
+        }
+        if let Some(mut out) = out {
+            out.flush()?;
+        }
+        if read_u64(&mut reader)? != hash.0 {
+            return Err(corrupted(format!("checksum mismatch in '{name}'")));
+        }
+        if name == CONFIG_SECTION {
+            config = Some(serde_json::from_slice(&bytes).map_err(|e| corrupted(e.to_string()))?);
+        }
+    }
+    config.ok_or_else(|| corrupted("bundle has no config section"))
+}
+
+impl HnswIndex {
+    fn write_bundle(&self, out: &mut impl Write) -> Result<(), HnswError> {
+        let staging = Staging::new()?;
+        self.save(staging.directory(), STAGED.to_string())?;
+        let config = HnswIndexConfig {
+            max_elements: self.capacity.load(Ordering::Relaxed),
+            ..self.config
+        };
+        let config =
+            serde_json::to_vec(&config).map_err(|e| HnswError::DumpError(e.to_string()))?;
+        let present: Vec<&str> = FILE_SECTIONS
+            .into_iter()
+            .filter(|section| staging.file(section).exists())
+            .collect();
+        out.write_all(BUNDLE_MAGIC)?;
+        out.write_all(&BUNDLE_VERSION.to_le_bytes())?;
+        out.write_all(&(present.len() as u32 + 1).to_le_bytes())?;
+        write_section(out, CONFIG_SECTION, config.len() as u64, &config[..])?;
+        for section in present {
+            let file = File::open(staging.file(section))?;
+            let len = file.metadata()?.len();
+            write_section(out, section, len, BufReader::new(file))?;
+        }
+        Ok(())
+    }
+
+    fn read_bundle(reader: impl Read) -> Result<Self, HnswError> {
+        let staging = Staging::new()?;
+        let config = unpack(reader, &staging)?;
+        let index = Self::load(staging.directory(), STAGED.to_string(), config)?;
+        // The staged files go with `staging`; there is nothing to reload from.
+        *index.source.lock().unwrap_or_else(PoisonError::into_inner) = None;
+        Ok(index)
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // One file instead of the .graph/.data/.meta set, written to a temporary
+    // name and renamed into place so a reader never sees half a bundle.
+    #[uniffi::method]
+    pub fn save_bundle(&self, path: String) -> Result<(), HnswError> {
+        let partial = PathBuf::from(format!("{path}.partial"));
+        let mut out = BufWriter::new(File::create(&partial)?);
+        self.write_bundle(&mut out)?;
+        out.into_inner()
+            .map_err(|e| HnswError::IoError(e.to_string()))?
+            .sync_all()?;
+        fs::rename(partial, path)?;
+        Ok(())
+    }
+
+    // The bundle is unpacked to a temporary directory and loaded into memory
+    // from there, so it may sit anywhere, including read-only locations.
+    #[uniffi::constructor]
+    pub fn load_bundle(path: String) -> Result<Self, HnswError> {
+        Self::read_bundle(BufReader::new(File::open(path)?))
+    }
+
+    // The bundle format held in memory, for hosts that keep the index in
+    // their own storage (a database blob, iCloud key-value data) rather than
+    // in files the library names. Not file-free: hnsw_rs only dumps to and
+    // loads from files, so both directions stage the dump in the temp
+    // directory (see set_temp_directory) and need room for it there.
+    #[uniffi::method]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, HnswError> {
+        let mut bytes = Vec::new();
+        self.write_bundle(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    #[uniffi::constructor]
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, HnswError> {
+        Self::read_bundle(&bytes[..])
+    }
+}
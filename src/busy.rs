@@ -0,0 +1,80 @@
+use std::sync::atomic::Ordering;
+use std::sync::{MutexGuard, PoisonError, TryLockError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{HnswError, HnswIndex, HnswIndexInner, SearchResult};
+
+// std's Mutex has no timed lock, so waiting polls with a growing pause.
+const MAX_BACKOFF: Duration = Duration::from_millis(2);
+
+// Drops the try_search count on every exit path.
+struct Waiting<'a>(&'a HnswIndex);
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.0.waiting_searches.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl HnswIndex {
+    // lock_inner, but gives up with Busy at `deadline`. A pending lazy load
+    // still runs to completion once the lock is held.
+    pub(crate) fn try_lock_inner(
+        &self,
+        deadline: Instant,
+    ) -> Result<MutexGuard<'_, HnswIndexInner>, HnswError> {
+        let mut backoff = Duration::from_micros(50);
+        loop {
+            let guard = match self.inner.try_lock() {
+                Ok(guard) => guard,
+                Err(TryLockError::Poisoned(e)) => PoisonError::into_inner(e),
+                Err(TryLockError::WouldBlock) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(HnswError::Busy);
+                    }
+                    thread::sleep(backoff.min(deadline - now));
+                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                    continue;
+                }
+            };
+            return self.load_pending(guard);
+        }
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // search, but fails with Busy instead of waiting more than `timeout_ms`
+    // behind a write, or at once when max_waiting_searches are already
+    // waiting. A timeout of 0 only succeeds if the graph is free right now.
+    #[uniffi::method]
+    pub fn try_search(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+        timeout_ms: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let limit = self.max_waiting_searches.load(Ordering::Relaxed);
+        let waiting = self.waiting_searches.fetch_add(1, Ordering::SeqCst);
+        let _waiting = Waiting(self);
+        if limit > 0 && waiting >= limit {
+            return Err(HnswError::Busy);
+        }
+        self.search_within(
+            query,
+            k,
+            ef_search,
+            Some(Duration::from_millis(timeout_ms as u64)),
+        )
+    }
+
+    // Caps how many try_search calls may wait at once; 0, the default, means
+    // no cap. Plain search is never limited.
+    #[uniffi::method]
+    pub fn set_max_waiting_searches(&self, limit: u32) {
+        self.max_waiting_searches.store(limit, Ordering::Relaxed);
+    }
+}
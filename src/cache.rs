@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::sync::PoisonError;
+
+use crate::{HnswError, HnswIndex, SearchResult};
+
+// Queries are compared bit for bit after preparation, so only an identical
+// repeat hits.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    query: Vec<u32>,
+    k: u32,
+    ef_search: u32,
+    namespace: Option<u32>,
+}
+
+struct CacheEntry {
+    results: Vec<SearchResult>,
+    last_used: u64,
+}
+
+pub(crate) struct QueryCache {
+    capacity: usize,
+    // The meta clock the entries were computed at. Every insert, removal and
+    // metadata change advances it, which empties the cache.
+    clock: u64,
+    uses: u64,
+    entries: HashMap<CacheKey, CacheEntry>,
+}
+
+impl QueryCache {
+    fn get(&mut self, key: &CacheKey, clock: u64) -> Option<Vec<SearchResult>> {
+        if clock != self.clock {
+            self.entries.clear();
+            self.clock = clock;
+            return None;
+        }
+        self.uses += 1;
+        let entry = self.entries.get_mut(key)?;
+        entry.last_used = self.uses;
+        Some(entry.results.clone())
+    }
+
+    fn put(&mut self, key: CacheKey, clock: u64, results: Vec<SearchResult>) {
+        if clock != self.clock {
+            return;
+        }
+        if self.entries.len() >= self.capacity
+            && !self.entries.contains_key(&key)
+            && let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+        {
+            self.entries.remove(&oldest);
+        }
+        self.uses += 1;
+        self.entries.insert(
+            key,
+            CacheEntry {
+                results,
+                last_used: self.uses,
+            },
+        );
+    }
+}
+
+impl HnswIndex {
+    // Runs `search` unless the same prepared query was answered since the
+    // index last changed. The clock is read before searching, so a change
+    // that lands mid-search leaves the stored entry already stale.
+    pub(crate) fn with_query_cache(
+        &self,
+        query: &[f32],
+        k: u32,
+        ef_search: u32,
+        namespace: Option<u32>,
+        search: impl FnOnce() -> Result<Vec<SearchResult>, HnswError>,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        if self
+            .query_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_none()
+        {
+            return search();
+        }
+        let clock = self
+            .meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clock;
+        let key = CacheKey {
+            query: query.iter().map(|x| x.to_bits()).collect(),
+            k,
+            ef_search,
+            namespace,
+        };
+        let hit = self
+            .query_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+            .and_then(|cache| cache.get(&key, clock));
+        if let Some(results) = hit {
+            return Ok(results);
+        }
+        let results = search()?;
+        if let Some(cache) = self
+            .query_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            cache.put(key, clock, results.clone());
+        }
+        Ok(results)
+    }
+
+    pub(crate) fn clear_query_cache(&self) {
+        if let Some(cache) = self
+            .query_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            // Also frees the table, so this serves memory warnings too.
+            cache.entries = HashMap::new();
+        }
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Keeps the results of the last `capacity` distinct searches, for callers
+    // such as typeahead that re-issue identical queries. Covers search,
+    // try_search and search_in_namespace. Any change to the index empties it.
+    #[uniffi::method]
+    pub fn enable_query_cache(&self, capacity: u32) -> Result<(), HnswError> {
+        if capacity == 0 {
+            return Err(HnswError::InvalidArgument(
+                "Cache capacity must be positive".to_string(),
+            ));
+        }
+        *self
+            .query_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(QueryCache {
+            capacity: capacity as usize,
+            clock: 0,
+            uses: 0,
+            entries: HashMap::new(),
+        });
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn disable_query_cache(&self) {
+        *self
+            .query_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
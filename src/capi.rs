@@ -0,0 +1,218 @@
+// Plain C ABI over the same HnswIndex the uniffi bindings use, for hosts that
+// can't take uniffi scaffolding (React Native JSI, C++ engines). The header is
+// include/hnsw.h; keep the two in step.
+//
+// Calls returning int32_t give 0 or a positive count on success and -1 on
+// failure, with the message available from hnsw_last_error on the same
+// thread. An index pointer may be used from several threads at once, but not
+// after hnsw_free.
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char};
+use std::ptr;
+
+use crate::{DistanceType, HnswError, HnswIndex, HnswIndexConfig, guarded};
+
+#[repr(C)]
+pub struct HnswConfig {
+    pub dimension: u32,
+    pub max_elements: u64,
+    pub max_nb_connection: u32,
+    pub max_layer: u32,
+    pub ef_construction: u32,
+    // HNSW_DISTANCE_* from the header.
+    pub distance: u32,
+    pub normalize_vectors: bool,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(error: &HnswError) {
+    let message = CString::new(error.to_string().replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+// Runs `f`, turning panics into HnswError::Internal, and records any error
+// for hnsw_last_error.
+fn call<T>(operation: &str, f: impl FnOnce() -> Result<T, HnswError>) -> Option<T> {
+    match guarded(operation, f).and_then(|result| result) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            set_last_error(&e);
+            None
+        }
+    }
+}
+
+fn invalid(message: &str) -> HnswError {
+    HnswError::InvalidArgument(message.to_string())
+}
+
+unsafe fn config_from(config: *const HnswConfig) -> Result<HnswIndexConfig, HnswError> {
+    let config = unsafe { config.as_ref() }.ok_or_else(|| invalid("config is null"))?;
+    let distance = match config.distance {
+        0 => DistanceType::L2,
+        1 => DistanceType::Cosine,
+        2 => DistanceType::L1,
+        3 => DistanceType::Dot,
+        other => return Err(invalid(&format!("Unknown distance: {other}"))),
+    };
+    Ok(HnswIndexConfig {
+        max_nb_connection: config.max_nb_connection,
+        max_elements: config.max_elements,
+        max_layer: config.max_layer,
+        ef_construction: config.ef_construction,
+        dimension: config.dimension,
+        distance,
+        normalize_vectors: config.normalize_vectors,
+        level_scale: None,
+    })
+}
+
+unsafe fn string_from(s: *const c_char, name: &str) -> Result<String, HnswError> {
+    if s.is_null() {
+        return Err(invalid(&format!("{name} is null")));
+    }
+    unsafe { CStr::from_ptr(s) }
+        .to_str()
+        .map(str::to_string)
+        .map_err(|_| invalid(&format!("{name} is not UTF-8")))
+}
+
+unsafe fn index_from<'a>(index: *const HnswIndex) -> Result<&'a HnswIndex, HnswError> {
+    unsafe { index.as_ref() }.ok_or_else(|| invalid("index is null"))
+}
+
+unsafe fn vector_from(data: *const f32, len: usize) -> Result<Vec<f32>, HnswError> {
+    if data.is_null() {
+        return Err(invalid("vector is null"));
+    }
+    Ok(unsafe { std::slice::from_raw_parts(data, len) }.to_vec())
+}
+
+// The message for the last failed call on this thread, or null. Valid until
+// the next failing call on the same thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn hnsw_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+}
+
+/// # Safety
+/// `config` must point to a valid HnswConfig.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hnsw_new(config: *const HnswConfig) -> *mut HnswIndex {
+    call("hnsw_new", || {
+        let config = unsafe { config_from(config) }?;
+        Ok(Box::into_raw(Box::new(HnswIndex::new(config))))
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+/// # Safety
+/// `directory` and `basename` must be NUL-terminated strings and `config` a
+/// valid HnswConfig.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hnsw_load(
+    directory: *const c_char,
+    basename: *const c_char,
+    config: *const HnswConfig,
+) -> *mut HnswIndex {
+    call("hnsw_load", || {
+        let directory = unsafe { string_from(directory, "directory") }?;
+        let basename = unsafe { string_from(basename, "basename") }?;
+        let config = unsafe { config_from(config) }?;
+        let index = HnswIndex::load(directory, basename, config)?;
+        Ok(Box::into_raw(Box::new(index)))
+    })
+    .unwrap_or(ptr::null_mut())
+}
+
+/// # Safety
+/// `index` must come from hnsw_new or hnsw_load and `directory` and
+/// `basename` must be NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hnsw_save(
+    index: *const HnswIndex,
+    directory: *const c_char,
+    basename: *const c_char,
+) -> i32 {
+    call("hnsw_save", || {
+        let index = unsafe { index_from(index) }?;
+        let directory = unsafe { string_from(directory, "directory") }?;
+        let basename = unsafe { string_from(basename, "basename") }?;
+        index.save(directory, basename)
+    })
+    .map_or(-1, |()| 0)
+}
+
+/// # Safety
+/// `index` must come from hnsw_new or hnsw_load and `data` must point to
+/// `len` floats.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hnsw_insert(
+    index: *const HnswIndex,
+    data: *const f32,
+    len: usize,
+    id: u64,
+) -> i32 {
+    call("hnsw_insert", || {
+        let index = unsafe { index_from(index) }?;
+        let data = unsafe { vector_from(data, len) }?;
+        index.insert(data, id)
+    })
+    .map_or(-1, |()| 0)
+}
+
+/// Writes up to `k` results, best first, and returns how many.
+///
+/// # Safety
+/// `index` must come from hnsw_new or hnsw_load, `query` must point to `len`
+/// floats, and `out_ids` and `out_distances` to room for `k` values each.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hnsw_search(
+    index: *const HnswIndex,
+    query: *const f32,
+    len: usize,
+    k: u32,
+    ef_search: u32,
+    out_ids: *mut u64,
+    out_distances: *mut f32,
+) -> i32 {
+    call("hnsw_search", || {
+        let index = unsafe { index_from(index) }?;
+        let query = unsafe { vector_from(query, len) }?;
+        if out_ids.is_null() || out_distances.is_null() {
+            return Err(invalid("output buffer is null"));
+        }
+        let results = index.search(query, k, ef_search)?;
+        for (i, result) in results.iter().take(k as usize).enumerate() {
+            unsafe {
+                *out_ids.add(i) = result.id;
+                *out_distances.add(i) = result.distance;
+            }
+        }
+        Ok(results.len().min(k as usize) as i32)
+    })
+    .unwrap_or(-1)
+}
+
+/// Number of points, or -1.
+///
+/// # Safety
+/// `index` must come from hnsw_new or hnsw_load.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hnsw_len(index: *const HnswIndex) -> i64 {
+    call("hnsw_len", || unsafe { index_from(index) }?.len()).map_or(-1, |len| len as i64)
+}
+
+/// # Safety
+/// `index` must come from hnsw_new or hnsw_load, or be null, and must not be
+/// used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn hnsw_free(index: *mut HnswIndex) {
+    if !index.is_null() {
+        drop(unsafe { Box::from_raw(index) });
+    }
+}
@@ -0,0 +1,222 @@
+use std::sync::PoisonError;
+
+use rayon::prelude::*;
+
+use crate::{DistanceType, HnswError, HnswIndex, HnswIndexConfig, HnswIndexInner, simd, threads};
+
+// Below this many clusters a linear scan over the centroids is as fast as
+// building a graph over them each iteration.
+const GRAPH_ASSIGN_MIN_K: usize = 64;
+const GRAPH_EF: usize = 32;
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ClusterAssignment {
+    pub id: u64,
+    pub cluster: u32,
+    // From the point to its cluster's centroid.
+    pub distance: f32,
+}
+
+// Small fixed-seed generator so the same index always clusters the same way.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in [0, 1).
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+fn normalize(vector: &mut [f32]) {
+    let norm = simd::dot(vector, vector).sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|x| *x /= norm);
+    }
+}
+
+// k-means++ seeding: each next centroid is drawn with probability
+// proportional to its squared distance from the nearest one so far.
+fn seed(points: &[(u64, Vec<f32>)], k: usize, distance: DistanceType) -> Vec<Vec<f32>> {
+    let mut rng = SplitMix64(points.len() as u64);
+    let first = (rng.next() % points.len() as u64) as usize;
+    let mut centroids = vec![points[first].1.clone()];
+    let mut nearest: Vec<f64> = points
+        .par_iter()
+        .map(|(_, v)| simd::eval(distance, v, &centroids[0]).max(0.0) as f64)
+        .collect();
+    while centroids.len() < k {
+        let total: f64 = nearest.iter().map(|d| d * d).sum();
+        let next = if total > 0.0 {
+            let mut target = rng.unit() * total;
+            nearest
+                .iter()
+                .position(|d| {
+                    target -= d * d;
+                    target <= 0.0
+                })
+                .unwrap_or(points.len() - 1)
+        } else {
+            (rng.next() % points.len() as u64) as usize
+        };
+        let centroid = points[next].1.clone();
+        nearest
+            .par_iter_mut()
+            .zip(points.par_iter())
+            .for_each(|(d, (_, v))| *d = d.min(simd::eval(distance, v, &centroid).max(0.0) as f64));
+        centroids.push(centroid);
+    }
+    centroids
+}
+
+fn assign_linear(
+    points: &[(u64, Vec<f32>)],
+    centroids: &[Vec<f32>],
+    distance: DistanceType,
+) -> Vec<(u32, f32)> {
+    points
+        .par_iter()
+        .map(|(_, v)| {
+            centroids
+                .iter()
+                .enumerate()
+                .map(|(c, centroid)| (c as u32, simd::eval(distance, v, centroid)))
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .unwrap_or((0, f32::INFINITY))
+        })
+        .collect()
+}
+
+// Approximate: a point may land in the second-nearest cluster now and then,
+// which k-means tolerates.
+fn assign_graph(
+    points: &[(u64, Vec<f32>)],
+    centroids: &[Vec<f32>],
+    distance: DistanceType,
+) -> Vec<(u32, f32)> {
+    let graph = HnswIndexInner::new(HnswIndexConfig {
+        max_nb_connection: 16,
+        max_elements: centroids.len() as u64,
+        max_layer: 16,
+        ef_construction: 100,
+        dimension: centroids[0].len() as u32,
+        distance,
+        normalize_vectors: false,
+        level_scale: None,
+    });
+    let pairs: Vec<(&Vec<f32>, usize)> = centroids.iter().zip(0..).collect();
+    match &graph {
+        HnswIndexInner::L2(inner) => inner.hnsw.parallel_insert(&pairs),
+        HnswIndexInner::Cosine(inner) => inner.hnsw.parallel_insert(&pairs),
+        HnswIndexInner::Dot(inner) => inner.hnsw.parallel_insert(&pairs),
+        HnswIndexInner::L1(inner) => inner.hnsw.parallel_insert(&pairs),
+    }
+    points
+        .par_iter()
+        .map(|(_, v)| {
+            let hits = match &graph {
+                HnswIndexInner::L2(inner) => inner.hnsw.search(v, 1, GRAPH_EF),
+                HnswIndexInner::Cosine(inner) => inner.hnsw.search(v, 1, GRAPH_EF),
+                HnswIndexInner::Dot(inner) => inner.hnsw.search(v, 1, GRAPH_EF),
+                HnswIndexInner::L1(inner) => inner.hnsw.search(v, 1, GRAPH_EF),
+            };
+            hits.first()
+                .map_or((0, f32::INFINITY), |hit| (hit.d_id as u32, hit.distance))
+        })
+        .collect()
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Clusters every visible point with k-means under the index's metric;
+    // for cosine and dot the centroids are kept unit length. Runs until no
+    // point changes cluster or `max_iters`. With `use_graph`, large k assigns
+    // points through a small HNSW over the centroids instead of comparing
+    // against each one. Results are in id order.
+    #[uniffi::method]
+    pub fn kmeans(
+        &self,
+        k: u32,
+        max_iters: u32,
+        use_graph: bool,
+    ) -> Result<Vec<ClusterAssignment>, HnswError> {
+        if k == 0 {
+            return Err(HnswError::InvalidArgument("k must be positive".to_string()));
+        }
+        let guard = self.lock_inner()?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut points = guard.points();
+        points.retain(|(id, _)| meta.visible(*id));
+        drop((meta, guard));
+        if points.is_empty() {
+            return Ok(Vec::new());
+        }
+        points.sort_unstable_by_key(|(id, _)| *id);
+
+        let distance = self.distance;
+        let spherical = matches!(distance, DistanceType::Cosine | DistanceType::Dot);
+        let k = (k as usize).min(points.len());
+        let dimension = points[0].1.len();
+        threads::install(|| {
+            let mut centroids = seed(&points, k, distance);
+            let mut assigned: Vec<(u32, f32)> = Vec::new();
+            for _ in 0..max_iters.max(1) {
+                let next = if use_graph && k >= GRAPH_ASSIGN_MIN_K {
+                    assign_graph(&points, &centroids, distance)
+                } else {
+                    assign_linear(&points, &centroids, distance)
+                };
+                let changed = assigned.len() != next.len()
+                    || assigned.iter().zip(&next).any(|(a, b)| a.0 != b.0);
+                assigned = next;
+                if !changed {
+                    break;
+                }
+
+                let mut sums = vec![vec![0f32; dimension]; k];
+                let mut counts = vec![0usize; k];
+                for ((_, v), (c, _)) in points.iter().zip(&assigned) {
+                    let c = *c as usize;
+                    counts[c] += 1;
+                    sums[c].iter_mut().zip(v).for_each(|(s, x)| *s += x);
+                }
+                for (c, (mut sum, count)) in sums.into_iter().zip(counts).enumerate() {
+                    if count == 0 {
+                        // Restart an empty cluster at the worst-served point.
+                        let (far, _) = assigned
+                            .iter()
+                            .enumerate()
+                            .max_by(|a, b| a.1.1.total_cmp(&b.1.1))
+                            .unwrap_or((0, &(0, 0.0)));
+                        centroids[c] = points[far].1.clone();
+                        assigned[far].1 = 0.0;
+                        continue;
+                    }
+                    sum.iter_mut().for_each(|s| *s /= count as f32);
+                    if spherical {
+                        normalize(&mut sum);
+                    }
+                    centroids[c] = sum;
+                }
+            }
+            // Distances to the final centroids.
+            let assigned = assign_linear(&points, &centroids, distance);
+            Ok(points
+                .iter()
+                .zip(assigned)
+                .map(|((id, _), (cluster, distance))| ClusterAssignment {
+                    id: *id,
+                    cluster,
+                    distance,
+                })
+                .collect())
+        })
+    }
+}
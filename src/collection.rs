@@ -0,0 +1,237 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{HnswError, HnswIndex, HnswIndexConfig};
+
+const MANIFEST_FILE: &str = "manifest.json";
+const MANIFEST_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Manifest {
+    version: u32,
+    indices: BTreeMap<String, HnswIndexConfig>,
+}
+
+impl Default for Manifest {
+    fn default() -> Self {
+        Manifest {
+            version: MANIFEST_VERSION,
+            indices: BTreeMap::new(),
+        }
+    }
+}
+
+struct CollectionState {
+    manifest: Manifest,
+    loaded: HashMap<String, Arc<HnswIndex>>,
+}
+
+fn validate_name(name: &str) -> Result<(), HnswError> {
+    let valid = !name.is_empty()
+        && name != "."
+        && name != ".."
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(HnswError::InvalidName(name.to_string()))
+    }
+}
+
+fn read_manifest(directory: &Path) -> Result<Manifest, HnswError> {
+    let path = directory.join(MANIFEST_FILE);
+    if !path.exists() {
+        return Ok(Manifest::default());
+    }
+    let bytes = fs::read(&path)?;
+    let manifest: Manifest =
+        serde_json::from_slice(&bytes).map_err(|e| HnswError::ManifestError(e.to_string()))?;
+    if manifest.version > MANIFEST_VERSION {
+        return Err(HnswError::ManifestError(format!(
+            "Unsupported manifest version {}",
+            manifest.version
+        )));
+    }
+    Ok(manifest)
+}
+
+fn write_manifest(directory: &Path, manifest: &Manifest) -> Result<(), HnswError> {
+    let bytes =
+        serde_json::to_vec_pretty(manifest).map_err(|e| HnswError::ManifestError(e.to_string()))?;
+    let tmp_path = directory.join(format!("{MANIFEST_FILE}.tmp"));
+    fs::write(&tmp_path, bytes)?;
+    fs::rename(&tmp_path, directory.join(MANIFEST_FILE))?;
+    Ok(())
+}
+
+fn index_files(directory: &Path, name: &str) -> [PathBuf; 5] {
+    [
+        directory.join(format!("{name}.hnsw.graph")),
+        directory.join(format!("{name}.hnsw.data")),
+        directory.join(format!("{name}.hnsw.meta")),
+        directory.join(format!("{name}.hnsw.reducer")),
+        directory.join(format!("{name}.hnsw.lock")),
+    ]
+}
+
+#[derive(uniffi::Object)]
+pub struct HnswCollection {
+    directory: PathBuf,
+    state: Mutex<CollectionState>,
+}
+
+impl HnswCollection {
+    fn directory_string(&self) -> String {
+        self.directory.to_string_lossy().into_owned()
+    }
+
+    fn load_index(&self, name: &str, config: HnswIndexConfig) -> Result<HnswIndex, HnswError> {
+        let [graph_path, ..] = index_files(&self.directory, name);
+        if !graph_path.exists() {
+            return Ok(HnswIndex::new(config));
+        }
+        HnswIndex::load(self.directory_string(), name.to_string(), config)
+    }
+}
+
+#[uniffi::export]
+impl HnswCollection {
+    #[uniffi::constructor]
+    pub fn open(directory: String) -> Result<Self, HnswError> {
+        let directory = PathBuf::from(directory);
+        fs::create_dir_all(&directory)?;
+        let manifest = read_manifest(&directory)?;
+        Ok(Self {
+            directory,
+            state: Mutex::new(CollectionState {
+                manifest,
+                loaded: HashMap::new(),
+            }),
+        })
+    }
+
+    #[uniffi::method]
+    pub fn create(
+        &self,
+        name: String,
+        config: HnswIndexConfig,
+    ) -> Result<Arc<HnswIndex>, HnswError> {
+        validate_name(&name)?;
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.manifest.indices.contains_key(&name) {
+            return Err(HnswError::IndexAlreadyExists(name));
+        }
+        state.manifest.indices.insert(name.clone(), config);
+        if let Err(e) = write_manifest(&self.directory, &state.manifest) {
+            state.manifest.indices.remove(&name);
+            return Err(e);
+        }
+        let index = Arc::new(HnswIndex::new(config));
+        state.loaded.insert(name, Arc::clone(&index));
+        Ok(index)
+    }
+
+    #[uniffi::method]
+    pub fn get(&self, name: String) -> Result<Arc<HnswIndex>, HnswError> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(index) = state.loaded.get(&name) {
+            return Ok(Arc::clone(index));
+        }
+        let config = *state
+            .manifest
+            .indices
+            .get(&name)
+            .ok_or_else(|| HnswError::IndexNotFound(name.clone()))?;
+        let index = Arc::new(self.load_index(&name, config)?);
+        state.loaded.insert(name, Arc::clone(&index));
+        Ok(index)
+    }
+
+    #[uniffi::method]
+    pub fn drop_index(&self, name: String) -> Result<(), HnswError> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let config = state
+            .manifest
+            .indices
+            .remove(&name)
+            .ok_or_else(|| HnswError::IndexNotFound(name.clone()))?;
+        if let Err(e) = write_manifest(&self.directory, &state.manifest) {
+            state.manifest.indices.insert(name, config);
+            return Err(e);
+        }
+        state.loaded.remove(&name);
+        for path in index_files(&self.directory, &name) {
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn list(&self) -> Result<Vec<String>, HnswError> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(state.manifest.indices.keys().cloned().collect())
+    }
+
+    #[uniffi::method]
+    pub fn contains(&self, name: String) -> Result<bool, HnswError> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(state.manifest.indices.contains_key(&name))
+    }
+
+    #[uniffi::method]
+    pub fn get_config(&self, name: String) -> Result<HnswIndexConfig, HnswError> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state
+            .manifest
+            .indices
+            .get(&name)
+            .copied()
+            .ok_or(HnswError::IndexNotFound(name))
+    }
+
+    #[uniffi::method]
+    pub fn load_all(&self) -> Result<(), HnswError> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let pending: Vec<(String, HnswIndexConfig)> = state
+            .manifest
+            .indices
+            .iter()
+            .filter(|(name, _)| !state.loaded.contains_key(*name))
+            .map(|(name, config)| (name.clone(), *config))
+            .collect();
+        for (name, config) in pending {
+            let index = Arc::new(self.load_index(&name, config)?);
+            state.loaded.insert(name, index);
+        }
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn save_all(&self) -> Result<(), HnswError> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        for (name, index) in &state.loaded {
+            index.save(self.directory_string(), name.clone())?;
+        }
+        write_manifest(&self.directory, &state.manifest)
+    }
+
+    #[uniffi::method]
+    pub fn save(&self, name: String) -> Result<(), HnswError> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if !state.manifest.indices.contains_key(&name) {
+            return Err(HnswError::IndexNotFound(name));
+        }
+        match state.loaded.get(&name) {
+            Some(index) => index.save(self.directory_string(), name),
+            None => Ok(()),
+        }
+    }
+}
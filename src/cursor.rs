@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::{HnswError, HnswIndex, SearchResult};
+
+#[uniffi::export]
+impl HnswIndex {
+    // Results `offset..offset + k` of a search for `offset + k`. Each call
+    // searches again; use a SearchCursor to page through one query.
+    #[uniffi::method]
+    pub fn search_page(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        offset: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let fetch = k.saturating_add(offset);
+        let results = self.search(query, fetch, self.resolve_ef(ef_search).max(fetch))?;
+        Ok(results.into_iter().skip(offset as usize).collect())
+    }
+}
+
+struct CursorState {
+    // Everything fetched so far, best first; `position` of them handed out.
+    results: Vec<SearchResult>,
+    seen: HashSet<u64>,
+    position: usize,
+    exhausted: bool,
+}
+
+// Pages through the neighbours of one query for "load more" lists. Results
+// are fetched ahead, doubling each time the cache runs out, so most pages
+// cost no search at all. Pages never repeat a point, even if the index
+// changes between them.
+#[derive(uniffi::Object)]
+pub struct SearchCursor {
+    index: Arc<HnswIndex>,
+    query: Vec<f32>,
+    ef_search: u32,
+    state: Mutex<CursorState>,
+}
+
+#[uniffi::export]
+impl SearchCursor {
+    #[uniffi::constructor]
+    pub fn new(index: Arc<HnswIndex>, query: Vec<f32>, ef_search: u32) -> Self {
+        SearchCursor {
+            index,
+            query,
+            ef_search,
+            state: Mutex::new(CursorState {
+                results: Vec::new(),
+                seen: HashSet::new(),
+                position: 0,
+                exhausted: false,
+            }),
+        }
+    }
+
+    // The next `k` results; fewer, then none, once the index runs out.
+    #[uniffi::method]
+    pub fn next_page(&self, k: u32) -> Result<Vec<SearchResult>, HnswError> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let wanted = state.position + k as usize;
+        if state.results.len() < wanted && !state.exhausted {
+            let fetch = wanted.max(state.results.len() * 2).min(u32::MAX as usize) as u32;
+            let fetched = self.index.search(
+                self.query.clone(),
+                fetch,
+                self.index.resolve_ef(self.ef_search).max(fetch),
+            )?;
+            state.exhausted = fetched.len() < fetch as usize;
+            for result in fetched {
+                if state.seen.insert(result.id) {
+                    state.results.push(result);
+                }
+            }
+        }
+        let end = wanted.min(state.results.len());
+        let page = state.results[state.position..end].to_vec();
+        state.position = end;
+        Ok(page)
+    }
+
+    // Results handed out so far.
+    #[uniffi::method]
+    pub fn position(&self) -> u64 {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .position as u64
+    }
+
+    #[uniffi::method]
+    pub fn is_exhausted(&self) -> bool {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.exhausted && state.position == state.results.len()
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use std::sync::PoisonError;
+
+use hnsw_rs::prelude::*;
+
+use crate::observer::Event;
+use crate::{HnswError, HnswIndex, HnswIndexInner, guarded, threads};
+
+// Chunks fetched per requested document, so documents with several strong
+// chunks don't crowd the rest out of the candidate set.
+const CHUNK_OVERSAMPLE: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum DocumentAggregation {
+    // Distance of the document's closest chunk.
+    Max,
+    // Mean distance over the document's chunks that were retrieved.
+    Mean,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct DocumentSearchResult {
+    pub document_id: u64,
+    pub distance: f32,
+    pub chunks: u32,
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Each chunk becomes its own point with an internal id, allocated the same
+    // way as keyed points. Calling again with the same document id adds chunks
+    // to it. Returns the point ids of the new chunks.
+    #[uniffi::method]
+    pub fn insert_document(
+        &self,
+        document_id: u64,
+        chunks: Vec<Vec<f32>>,
+    ) -> Result<Vec<u64>, HnswError> {
+        self.check_writable()?;
+        let chunks: Vec<Vec<f32>> = chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, vec)| self.prepare_stored(vec, i))
+            .collect::<Result<_, _>>()?;
+        let mut guard = self.lock_inner()?;
+        let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let ids: Vec<u64> = chunks.iter().map(|_| meta.allocate_id(&known)).collect();
+        self.ensure_capacity_locked(&mut guard, &known, &mut meta, &ids)?;
+        let pairs: Vec<(&Vec<f32>, usize)> = chunks
+            .iter()
+            .zip(ids.iter().map(|&id| id as usize))
+            .collect();
+        guarded("insert_document", || {
+            threads::install(|| match &*guard {
+                HnswIndexInner::L2(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::Cosine(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::Dot(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::L1(inner) => inner.hnsw.parallel_insert(&pairs),
+            })
+        })?;
+        known.extend(&ids);
+        self.sketch_inserted(&pairs);
+        meta.touch(&ids);
+        self.notify(|| Event::Insert(ids.clone()));
+        for &id in &ids {
+            meta.documents.insert(id, document_id);
+        }
+        Ok(ids)
+    }
+
+    #[uniffi::method]
+    pub fn get_document_id(&self, id: u64) -> Result<Option<u64>, HnswError> {
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(meta.documents.get(&id).copied())
+    }
+
+    #[uniffi::method]
+    pub fn get_document_chunks(&self, document_id: u64) -> Result<Vec<u64>, HnswError> {
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut ids: Vec<u64> = meta
+            .documents
+            .iter()
+            .filter(|&(_, &doc)| doc == document_id)
+            .map(|(&id, _)| id)
+            .collect();
+        ids.sort_unstable();
+        Ok(ids)
+    }
+
+    // Only chunk points are searched; points inserted without a document are
+    // skipped. Best document first.
+    #[uniffi::method]
+    pub fn search_documents(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+        aggregation: DocumentAggregation,
+    ) -> Result<Vec<DocumentSearchResult>, HnswError> {
+        let query = self.prepare_query(query, 0)?;
+        let guard = self.lock_inner()?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let filter =
+            |id: &DataId| meta.documents.contains_key(&(*id as u64)) && meta.visible(*id as u64);
+        let filter: Option<&dyn FilterT> = Some(&filter);
+        let fetch = k as usize * CHUNK_OVERSAMPLE;
+        let ef = (self.resolve_ef(ef_search) as usize).max(fetch);
+        let hits = guarded("search_documents", || match &*guard {
+            HnswIndexInner::L2(inner) => inner.hnsw.search_filter(&query, fetch, ef, filter),
+            HnswIndexInner::Cosine(inner) => inner.hnsw.search_filter(&query, fetch, ef, filter),
+            HnswIndexInner::Dot(inner) => inner.hnsw.search_filter(&query, fetch, ef, filter),
+            HnswIndexInner::L1(inner) => inner.hnsw.search_filter(&query, fetch, ef, filter),
+        })?;
+        drop(guard);
+
+        let mut grouped: HashMap<u64, (f32, f32, u32)> = HashMap::new();
+        for hit in hits {
+            let Some(&document_id) = meta.documents.get(&(hit.d_id as u64)) else {
+                continue;
+            };
+            let entry = grouped
+                .entry(document_id)
+                .or_insert((f32::INFINITY, 0.0, 0));
+            entry.0 = entry.0.min(hit.distance);
+            entry.1 += hit.distance;
+            entry.2 += 1;
+        }
+        let mut results: Vec<DocumentSearchResult> = grouped
+            .into_iter()
+            .map(|(document_id, (best, sum, chunks))| DocumentSearchResult {
+                document_id,
+                distance: match aggregation {
+                    DocumentAggregation::Max => best,
+                    DocumentAggregation::Mean => sum / chunks as f32,
+                },
+                chunks,
+            })
+            .collect();
+        results.sort_by(|a, b| {
+            a.distance
+                .total_cmp(&b.distance)
+                .then(a.document_id.cmp(&b.document_id))
+        });
+        results.truncate(k as usize);
+        Ok(results)
+    }
+}
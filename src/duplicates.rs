@@ -0,0 +1,96 @@
+use std::collections::HashSet;
+use std::sync::PoisonError;
+
+use hnsw_rs::prelude::*;
+use rayon::prelude::*;
+
+use crate::{HnswError, HnswIndex, HnswIndexInner, threads};
+
+// Neighbours looked at per point. Near-duplicates of one item beyond this
+// many are still found, through each other.
+const NEIGHBOURS: usize = 16;
+const EF: usize = 64;
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct DuplicatePair {
+    // The smaller id first.
+    pub first: u64,
+    pub second: u64,
+    pub distance: f32,
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Pairs of visible points at most `threshold` apart, closest first. Each
+    // point's graph neighbours are checked rather than every pair, so this is
+    // near-linear but can miss a pair the graph search misses. Points are
+    // searched `batch_size` at a time, releasing the graph in between so
+    // inserts and searches can interleave with a long scan; points added
+    // meanwhile are not scanned.
+    #[uniffi::method]
+    pub fn find_duplicates(
+        &self,
+        threshold: f32,
+        batch_size: u32,
+    ) -> Result<Vec<DuplicatePair>, HnswError> {
+        let guard = self.lock_inner()?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut points = guard.points();
+        points.retain(|(id, _)| meta.visible(*id));
+        drop((meta, guard));
+        points.sort_unstable_by_key(|(id, _)| *id);
+
+        let mut seen = HashSet::new();
+        let mut pairs = Vec::new();
+        for batch in points.chunks(batch_size.max(1) as usize) {
+            let guard = self.lock_inner()?;
+            let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+            let filter = |id: &DataId| meta.visible(*id as u64);
+            let found: Vec<(u64, u64, f32)> = threads::install(|| {
+                batch
+                    .par_iter()
+                    .flat_map_iter(|(id, v)| {
+                        let filter: Option<&dyn FilterT> = Some(&filter);
+                        let hits = match &*guard {
+                            HnswIndexInner::L2(inner) => {
+                                inner.hnsw.search_filter(v, NEIGHBOURS + 1, EF, filter)
+                            }
+                            HnswIndexInner::Cosine(inner) => {
+                                inner.hnsw.search_filter(v, NEIGHBOURS + 1, EF, filter)
+                            }
+                            HnswIndexInner::Dot(inner) => {
+                                inner.hnsw.search_filter(v, NEIGHBOURS + 1, EF, filter)
+                            }
+                            HnswIndexInner::L1(inner) => {
+                                inner.hnsw.search_filter(v, NEIGHBOURS + 1, EF, filter)
+                            }
+                        };
+                        hits.into_iter()
+                            .map(move |hit| (*id, hit.d_id as u64, hit.distance))
+                    })
+                    .collect()
+            });
+            drop((meta, guard));
+            for (id, other, distance) in found {
+                if other == id || distance > threshold {
+                    continue;
+                }
+                let (first, second) = (id.min(other), id.max(other));
+                if seen.insert((first, second)) {
+                    pairs.push(DuplicatePair {
+                        first,
+                        second,
+                        distance,
+                    });
+                }
+            }
+        }
+        pairs.sort_by(|a, b| {
+            a.distance
+                .total_cmp(&b.distance)
+                .then(a.first.cmp(&b.first))
+                .then(a.second.cmp(&b.second))
+        });
+        Ok(pairs)
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::HashSet;
+use std::sync::PoisonError;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+use hnsw_rs::hnsw::Hnsw;
+use hnsw_rs::prelude::*;
+use rayon::prelude::*;
+
+use crate::{DistanceType, HnswError, HnswIndex, HnswIndexInner, SearchResult, graph_points, simd};
+
+const EF_SWEEP_START: usize = 16;
+const EF_SWEEP_MAX: usize = 4096;
+// Below this many points a linear scan is both exact and faster than
+// descending the graph.
+const EXACT_SEARCH_THRESHOLD: usize = 4096;
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct RecallReport {
+    pub query_count: u32,
+    pub k: u32,
+    pub ef_search: u32,
+    pub recall_at_k: f32,
+    pub mean_query_latency_us: f64,
+    pub mean_exact_latency_us: f64,
+    pub mean_distance_error: f32,
+}
+
+pub(crate) fn exact_search<D>(
+    hnsw: &Hnsw<'static, f32, D>,
+    distance: DistanceType,
+    query: &[f32],
+    k: usize,
+    visible: impl Fn(u64) -> bool + Sync,
+) -> Vec<SearchResult>
+where
+    D: Distance<f32> + Send + Sync,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+    let points: Vec<_> = graph_points(hnsw).collect();
+    let mut scored: Vec<SearchResult> = points
+        .par_iter()
+        .filter(|point| visible(point.get_origin_id() as u64))
+        .map(|point| {
+            SearchResult::new(
+                point.get_origin_id() as u64,
+                simd::eval(distance, query, point.get_v()),
+            )
+        })
+        .collect();
+    let by_distance = |a: &SearchResult, b: &SearchResult| a.distance.total_cmp(&b.distance);
+    if scored.len() > k {
+        scored.select_nth_unstable_by(k - 1, by_distance);
+        scored.truncate(k);
+    }
+    scored.sort_by(by_distance);
+    scored
+}
+
+pub(crate) fn recall(approx: &[SearchResult], exact: &[SearchResult]) -> f32 {
+    if exact.is_empty() {
+        return 1.0;
+    }
+    let truth: HashSet<u64> = exact.iter().map(|r| r.id).collect();
+    let hits = approx.iter().filter(|r| truth.contains(&r.id)).count();
+    hits as f32 / truth.len() as f32
+}
+
+fn mean_recall<D>(
+    hnsw: &Hnsw<'static, f32, D>,
+    queries: &[Vec<f32>],
+    truth: &[Vec<SearchResult>],
+    k: usize,
+    ef: usize,
+) -> f32
+where
+    D: Distance<f32> + Send + Sync,
+{
+    let total: f32 = queries
+        .iter()
+        .zip(truth)
+        .map(|(query, exact)| {
+            let approx: Vec<SearchResult> = hnsw
+                .search(query, k, ef)
+                .into_iter()
+                .map(SearchResult::from)
+                .collect();
+            recall(&approx, exact)
+        })
+        .sum();
+    total / queries.len() as f32
+}
+
+fn tune_ef<D>(
+    hnsw: &Hnsw<'static, f32, D>,
+    distance: DistanceType,
+    queries: &[Vec<f32>],
+    k: usize,
+    target_recall: f32,
+) -> u32
+where
+    D: Distance<f32> + Send + Sync,
+{
+    let truth: Vec<Vec<SearchResult>> = queries
+        .iter()
+        .map(|query| exact_search(hnsw, distance, query, k, |_| true))
+        .collect();
+    let max_ef = EF_SWEEP_MAX.max(k);
+
+    // Double ef until the target is met, then bisect between the last
+    // failing and the first passing value for the smallest ef.
+    let mut low = k.saturating_sub(1);
+    let mut high = EF_SWEEP_START.max(k);
+    while mean_recall(hnsw, queries, &truth, k, high) < target_recall {
+        if high >= max_ef {
+            return max_ef as u32;
+        }
+        low = high;
+        high = (high * 2).min(max_ef);
+    }
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if mean_recall(hnsw, queries, &truth, k, mid) >= target_recall {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    high as u32
+}
+
+fn evaluate<D>(
+    hnsw: &Hnsw<'static, f32, D>,
+    distance: DistanceType,
+    queries: &[Vec<f32>],
+    k: usize,
+    ef: usize,
+) -> RecallReport
+where
+    D: Distance<f32> + Send + Sync,
+{
+    let mut recall_sum = 0.0f32;
+    let mut approx_us = 0.0f64;
+    let mut exact_us = 0.0f64;
+    let mut error_sum = 0.0f32;
+    let mut error_count = 0usize;
+    for query in queries {
+        let start = Instant::now();
+        let approx: Vec<SearchResult> = hnsw
+            .search(query, k, ef)
+            .into_iter()
+            .map(SearchResult::from)
+            .collect();
+        approx_us += start.elapsed().as_secs_f64() * 1e6;
+
+        let start = Instant::now();
+        let exact = exact_search(hnsw, distance, query, k, |_| true);
+        exact_us += start.elapsed().as_secs_f64() * 1e6;
+
+        recall_sum += recall(&approx, &exact);
+        for (a, e) in approx.iter().zip(&exact) {
+            error_sum += (a.distance - e.distance).abs();
+            error_count += 1;
+        }
+    }
+    let n = queries.len();
+    RecallReport {
+        query_count: n as u32,
+        k: k as u32,
+        ef_search: ef as u32,
+        recall_at_k: recall_sum / n as f32,
+        mean_query_latency_us: approx_us / n as f64,
+        mean_exact_latency_us: exact_us / n as f64,
+        mean_distance_error: if error_count == 0 {
+            0.0
+        } else {
+            error_sum / error_count as f32
+        },
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // With `apply`, the tuned value becomes the default for searches called
+    // with an ef_search of 0.
+    #[uniffi::method]
+    pub fn tune_ef_search(
+        &self,
+        sample_queries: Vec<Vec<f32>>,
+        k: u32,
+        target_recall: f32,
+        apply: bool,
+    ) -> Result<u32, HnswError> {
+        if sample_queries.is_empty() {
+            return Err(HnswError::InvalidArgument(
+                "At least one sample query is required".to_string(),
+            ));
+        }
+        if k == 0 {
+            return Err(HnswError::InvalidArgument(
+                "k must be greater than zero".to_string(),
+            ));
+        }
+        if !(target_recall > 0.0 && target_recall <= 1.0) {
+            return Err(HnswError::InvalidArgument(format!(
+                "Target recall must be in (0, 1], got {target_recall}"
+            )));
+        }
+        let sample_queries: Vec<Vec<f32>> = sample_queries
+            .into_iter()
+            .enumerate()
+            .map(|(i, query)| self.prepare_query(query, i))
+            .collect::<Result<_, _>>()?;
+        let guard = self.lock_purged()?;
+        let (queries, k) = (&sample_queries, k as usize);
+        let ef = match &*guard {
+            HnswIndexInner::L2(inner) => {
+                tune_ef(&inner.hnsw, DistanceType::L2, queries, k, target_recall)
+            }
+            HnswIndexInner::Cosine(inner) => {
+                tune_ef(&inner.hnsw, DistanceType::Cosine, queries, k, target_recall)
+            }
+            HnswIndexInner::Dot(inner) => {
+                tune_ef(&inner.hnsw, DistanceType::Dot, queries, k, target_recall)
+            }
+            HnswIndexInner::L1(inner) => {
+                tune_ef(&inner.hnsw, DistanceType::L1, queries, k, target_recall)
+            }
+        };
+        if apply {
+            self.ef_search.store(ef, Ordering::Relaxed);
+        }
+        Ok(ef)
+    }
+
+    #[uniffi::method]
+    pub fn evaluate_recall(
+        &self,
+        queries: Vec<Vec<f32>>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<RecallReport, HnswError> {
+        if queries.is_empty() {
+            return Err(HnswError::InvalidArgument(
+                "At least one query is required".to_string(),
+            ));
+        }
+        let queries: Vec<Vec<f32>> = queries
+            .into_iter()
+            .enumerate()
+            .map(|(i, query)| self.prepare_query(query, i))
+            .collect::<Result<_, _>>()?;
+        let guard = self.lock_purged()?;
+        let (k, ef) = (k as usize, self.resolve_ef(ef_search) as usize);
+        Ok(match &*guard {
+            HnswIndexInner::L2(inner) => evaluate(&inner.hnsw, DistanceType::L2, &queries, k, ef),
+            HnswIndexInner::Cosine(inner) => {
+                evaluate(&inner.hnsw, DistanceType::Cosine, &queries, k, ef)
+            }
+            HnswIndexInner::Dot(inner) => evaluate(&inner.hnsw, DistanceType::Dot, &queries, k, ef),
+            HnswIndexInner::L1(inner) => evaluate(&inner.hnsw, DistanceType::L1, &queries, k, ef),
+        })
+    }
+
+    #[uniffi::method]
+    pub fn search_exact(&self, query: Vec<f32>, k: u32) -> Result<Vec<SearchResult>, HnswError> {
+        let query = self.prepare_query(query, 0)?;
+        let guard = self.lock_inner()?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let visible = |id: u64| meta.visible(id);
+        let k = k as usize;
+        Ok(match &*guard {
+            HnswIndexInner::L2(inner) => {
+                exact_search(&inner.hnsw, DistanceType::L2, &query, k, visible)
+            }
+            HnswIndexInner::Cosine(inner) => {
+                exact_search(&inner.hnsw, DistanceType::Cosine, &query, k, visible)
+            }
+            HnswIndexInner::Dot(inner) => {
+                exact_search(&inner.hnsw, DistanceType::Dot, &query, k, visible)
+            }
+            HnswIndexInner::L1(inner) => {
+                exact_search(&inner.hnsw, DistanceType::L1, &query, k, visible)
+            }
+        })
+    }
+
+    #[uniffi::method]
+    pub fn search_auto(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        if self.len()? as usize <= EXACT_SEARCH_THRESHOLD {
+            self.search_exact(query, k)
+        } else {
+            self.search(query, k, ef_search)
+        }
+    }
+
+    #[uniffi::method]
+    pub fn get_default_ef_search(&self) -> u32 {
+        self.ef_search.load(Ordering::Relaxed)
+    }
+
+    // Used by searches called with an ef_search of 0; 0 here clears it.
+    #[uniffi::method]
+    pub fn set_default_ef_search(&self, ef_search: u32) {
+        self.ef_search.store(ef_search, Ordering::Relaxed);
+    }
+}
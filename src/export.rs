@@ -0,0 +1,97 @@
+use std::sync::{Arc, MutexGuard, PoisonError};
+
+use crate::points::PointMap;
+use crate::{HnswError, HnswIndex, HnswIndexInner, PointMeta, arrow, jsonl, npy};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum ExportFormat {
+    // An .npz archive with `ids` (uint64) and `vectors` (float32) arrays, as
+    // import_npz reads them. Payloads and keys are left out.
+    Npz,
+    // One object per line with "id", "vector", and "key" and "payload" when
+    // set. Payloads that are JSON are embedded as is, other UTF-8 as a string
+    // and anything else as an array of bytes.
+    Jsonl,
+    // Columns `id`, `vector` and a nullable binary `payload`.
+    Arrow,
+}
+
+pub(crate) struct ExportRow {
+    pub(crate) id: u64,
+    pub(crate) key: Option<String>,
+    pub(crate) vector: Vec<f32>,
+    pub(crate) payload: Option<Vec<u8>>,
+}
+
+// The live points in id order, copied out a chunk at a time as a writer asks
+// for them, so an export holds one chunk of vectors rather than all of them.
+// The index stays locked until this is dropped.
+pub(crate) struct ExportRows<'a> {
+    ids: Vec<u64>,
+    points: Arc<PointMap>,
+    meta: MutexGuard<'a, PointMeta>,
+    _guard: MutexGuard<'a, HnswIndexInner>,
+}
+
+impl ExportRows<'_> {
+    pub(crate) fn ids(&self) -> &[u64] {
+        &self.ids
+    }
+
+    pub(crate) fn chunks(&self, rows: usize) -> impl Iterator<Item = Vec<ExportRow>> + '_ {
+        self.ids.chunks(rows).map(|ids| {
+            ids.iter()
+                .filter_map(|&id| {
+                    Some(ExportRow {
+                        id,
+                        key: self.meta.keys.get(&id).cloned(),
+                        vector: self.points.vector(id)?,
+                        payload: self.meta.payloads.get(&id).cloned(),
+                    })
+                })
+                .collect()
+        })
+    }
+}
+
+impl HnswIndex {
+    // Removed points still in the graph are skipped rather than purged.
+    pub(crate) fn export_rows(&self) -> Result<ExportRows<'_>, HnswError> {
+        let guard = self.lock_inner()?;
+        let mut ids: Vec<u64> = self
+            .ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .copied()
+            .collect();
+        ids.sort_unstable();
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let points = self.point_map(&guard);
+        Ok(ExportRows {
+            ids,
+            points,
+            meta,
+            _guard: guard,
+        })
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Every point, hidden ones included, in id order, for re-embedding the
+    // content behind an index after switching models. Vectors are written as
+    // stored, i.e. after normalization and dimension reduction. Returns the
+    // number of points written. Rows are streamed out in chunks, and writes
+    // and searches wait until the file is written.
+    #[uniffi::method]
+    pub fn export_all(&self, path: String, format: ExportFormat) -> Result<u64, HnswError> {
+        let rows = self.export_rows()?;
+        match format {
+            ExportFormat::Npz => npy::write_npz(&path, self.dimension, &rows)?,
+            ExportFormat::Jsonl => jsonl::write_jsonl(&path, &rows)?,
+            ExportFormat::Arrow => arrow::write_arrow(&path, self.dimension, &rows, true)?,
+        }
+        Ok(rows.ids().len() as u64)
+    }
+}
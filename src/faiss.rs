@@ -0,0 +1,163 @@
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use crate::{DistanceType, HnswError, HnswIndex, ProgressListener};
+
+const BATCH_ROWS: usize = 1024;
+
+struct FlatIndex {
+    dimension: usize,
+    count: usize,
+    distance: DistanceType,
+    vectors_at: u64,
+    ids: Option<Vec<u64>>,
+}
+
+fn invalid(message: impl Into<String>) -> HnswError {
+    HnswError::InvalidArgument(message.into())
+}
+
+fn read_bytes<const N: usize>(reader: &mut impl Read) -> Result<[u8; N], HnswError> {
+    let mut buf = [0u8; N];
+    reader.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+fn read_i64(reader: &mut impl Read) -> Result<i64, HnswError> {
+    Ok(i64::from_le_bytes(read_bytes(reader)?))
+}
+
+fn read_len(reader: &mut impl Read) -> Result<usize, HnswError> {
+    usize::try_from(u64::from_le_bytes(read_bytes(reader)?))
+        .map_err(|_| invalid("FAISS vector length out of range"))
+}
+
+// FAISS metric ids: 0 inner product, 1 L2, 2 L1.
+fn metric(id: i32) -> Result<DistanceType, HnswError> {
+    match id {
+        0 => Ok(DistanceType::Dot),
+        1 => Ok(DistanceType::L2),
+        2 => Ok(DistanceType::L1),
+        other => Err(invalid(format!("unsupported FAISS metric: {other}"))),
+    }
+}
+
+// Common header written by faiss::write_index_header.
+fn read_header(reader: &mut impl Read) -> Result<(usize, usize, DistanceType), HnswError> {
+    let dimension = i32::from_le_bytes(read_bytes(reader)?);
+    let count = read_i64(reader)?;
+    // Two reserved words and the is_trained flag.
+    read_bytes::<17>(reader)?;
+    let metric_id = i32::from_le_bytes(read_bytes(reader)?);
+    if metric_id > 1 {
+        read_bytes::<4>(reader)?;
+    }
+    let dimension = usize::try_from(dimension).map_err(|_| invalid("negative FAISS dimension"))?;
+    let count = usize::try_from(count).map_err(|_| invalid("negative FAISS vector count"))?;
+    Ok((dimension, count, metric(metric_id)?))
+}
+
+// Vectors are left on disk: only their offset is recorded, so an IDMap
+// trailer can be read before anything is inserted.
+fn read_index<R: Read + Seek>(reader: &mut R) -> Result<FlatIndex, HnswError> {
+    let fourcc: [u8; 4] = read_bytes(reader)?;
+    match &fourcc {
+        b"IxF2" | b"IxFI" => {
+            let (dimension, count, distance) = read_header(reader)?;
+            let floats = read_len(reader)?;
+            if floats != dimension * count {
+                return Err(invalid(format!(
+                    "FAISS flat index holds {floats} floats, expected {}",
+                    dimension * count
+                )));
+            }
+            let vectors_at = reader.stream_position()?;
+            reader.seek(SeekFrom::Current(floats as i64 * 4))?;
+            Ok(FlatIndex {
+                dimension,
+                count,
+                distance,
+                vectors_at,
+                ids: None,
+            })
+        }
+        b"IxMp" | b"IxM2" => {
+            read_header(reader)?;
+            let mut flat = read_index(reader)?;
+            let len = read_len(reader)?;
+            if len != flat.count {
+                return Err(invalid(format!(
+                    "FAISS id map has {len} ids for {} vectors",
+                    flat.count
+                )));
+            }
+            let ids = (0..len)
+                .map(|_| {
+                    let id = read_i64(reader)?;
+                    u64::try_from(id).map_err(|_| invalid(format!("negative id: {id}")))
+                })
+                .collect::<Result<Vec<u64>, HnswError>>()?;
+            flat.ids = Some(ids);
+            Ok(flat)
+        }
+        other => Err(invalid(format!(
+            "unsupported FAISS index type: {}",
+            String::from_utf8_lossy(other)
+        ))),
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Reads IndexFlatL2 / IndexFlatIP, optionally wrapped in IndexIDMap or
+    // IndexIDMap2. Without an id map, row i gets id i. Inner-product files can
+    // be imported into Dot or Cosine indices. Returns the number of vectors.
+    #[uniffi::method]
+    pub fn import_faiss_flat(
+        &self,
+        path: String,
+        listener: Box<dyn ProgressListener>,
+    ) -> Result<u64, HnswError> {
+        self.check_writable()?;
+        let mut reader = BufReader::new(File::open(path)?);
+        let flat = read_index(&mut reader)?;
+        self.check_dimension(flat.dimension)?;
+        let compatible = flat.distance == self.distance
+            || (flat.distance == DistanceType::Dot && self.distance == DistanceType::Cosine);
+        if !compatible {
+            return Err(HnswError::DistanceMismatch {
+                expected: self.distance,
+                got: flat.distance,
+            });
+        }
+
+        reader.seek(SeekFrom::Start(flat.vectors_at))?;
+        let total = flat.count as u64;
+        listener.on_progress(0, total);
+        let mut buf = Vec::new();
+        let mut done = 0;
+        while done < flat.count {
+            let end = flat.count.min(done + BATCH_ROWS);
+            buf.resize((end - done) * flat.dimension * 4, 0);
+            reader.read_exact(&mut buf)?;
+            let data = buf
+                .chunks_exact(flat.dimension * 4)
+                .map(|row| {
+                    row.as_chunks::<4>()
+                        .0
+                        .iter()
+                        .map(|&b| f32::from_le_bytes(b))
+                        .collect()
+                })
+                .collect();
+            let ids = match &flat.ids {
+                Some(ids) => ids[done..end].to_vec(),
+                None => (done as u64..end as u64).collect(),
+            };
+            self.insert_batch(data, ids)?;
+            done = end;
+            listener.on_progress(done as u64, total);
+        }
+        Ok(total)
+    }
+}
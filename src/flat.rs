@@ -0,0 +1,124 @@
+use std::sync::PoisonError;
+
+use hnsw_rs::hnsw::Neighbour;
+use hnsw_rs::prelude::*;
+use rayon::prelude::*;
+
+use crate::{HnswError, HnswIndex, HnswIndexInner, guarded, threads};
+
+// Results of every query laid end to end: query i owns `counts[i]` entries,
+// starting after those of the queries before it.
+#[derive(Debug, Clone, Default, uniffi::Record)]
+pub struct FlatSearchResults {
+    pub ids: Vec<u64>,
+    pub distances: Vec<f32>,
+    pub counts: Vec<u32>,
+}
+
+impl FlatSearchResults {
+    fn push(&mut self, neighbours: Vec<Neighbour>) {
+        self.counts.push(neighbours.len() as u32);
+        for n in neighbours {
+            self.ids.push(n.d_id as u64);
+            self.distances.push(n.distance);
+        }
+    }
+}
+
+impl HnswIndex {
+    // Splits a row-major buffer into vectors of the input dimension.
+    fn split_rows(&self, data: Vec<f32>, rows: usize) -> Result<Vec<Vec<f32>>, HnswError> {
+        let stride = self.input_dimension() as usize;
+        if stride == 0 || data.len() != rows * stride {
+            return Err(HnswError::InvalidArgument(format!(
+                "flat buffer holds {} floats, expected {rows} rows of {stride}",
+                data.len()
+            )));
+        }
+        Ok(data.chunks_exact(stride).map(<[f32]>::to_vec).collect())
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // One contiguous buffer lowers across the FFI far more cheaply than a
+    // nested array; row i is data[i * dimension..(i + 1) * dimension].
+    #[uniffi::method]
+    pub fn insert_batch_flat(&self, data: Vec<f32>, ids: Vec<u64>) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let data = self.split_rows(data, ids.len())?;
+        self.insert_batch(data, ids)
+    }
+
+    #[uniffi::method]
+    pub fn search_flat(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<FlatSearchResults, HnswError> {
+        let mut flat = FlatSearchResults::default();
+        let results = self.search(query, k, ef_search)?;
+        flat.counts.push(results.len() as u32);
+        for r in results {
+            flat.ids.push(r.id);
+            flat.distances.push(r.distance);
+        }
+        Ok(flat)
+    }
+
+    // `queries` holds `num_queries` rows back to back; they are searched in
+    // parallel on the shared pool.
+    #[uniffi::method]
+    pub fn search_batch_flat(
+        &self,
+        queries: Vec<f32>,
+        num_queries: u32,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<FlatSearchResults, HnswError> {
+        let queries: Vec<Vec<f32>> = self
+            .split_rows(queries, num_queries as usize)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, query)| self.prepare_query(query, i))
+            .collect::<Result<_, _>>()?;
+        let guard = self.lock_inner()?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let (k, ef) = (k as usize, self.resolve_ef(ef_search) as usize);
+        // parallel_search takes no filter, so hidden and removed points need one
+        // filtered search per query.
+        let results = guarded("search_batch_flat", || {
+            if meta.all_visible() {
+                threads::install(|| match &*guard {
+                    HnswIndexInner::L2(inner) => inner.hnsw.parallel_search(&queries, k, ef),
+                    HnswIndexInner::Cosine(inner) => inner.hnsw.parallel_search(&queries, k, ef),
+                    HnswIndexInner::Dot(inner) => inner.hnsw.parallel_search(&queries, k, ef),
+                    HnswIndexInner::L1(inner) => inner.hnsw.parallel_search(&queries, k, ef),
+                })
+            } else {
+                let filter = |id: &DataId| meta.visible(*id as u64);
+                let search = |query: &Vec<f32>| {
+                    let filter: Option<&dyn FilterT> = Some(&filter);
+                    match &*guard {
+                        HnswIndexInner::L2(inner) => inner.hnsw.search_filter(query, k, ef, filter),
+                        HnswIndexInner::Cosine(inner) => {
+                            inner.hnsw.search_filter(query, k, ef, filter)
+                        }
+                        HnswIndexInner::Dot(inner) => {
+                            inner.hnsw.search_filter(query, k, ef, filter)
+                        }
+                        HnswIndexInner::L1(inner) => inner.hnsw.search_filter(query, k, ef, filter),
+                    }
+                };
+                threads::install(|| queries.par_iter().map(search).collect())
+            }
+        })?;
+        drop((meta, guard));
+        let mut flat = FlatSearchResults::default();
+        for neighbours in results {
+            flat.push(neighbours);
+        }
+        Ok(flat)
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::attrs::Attrs;
+use crate::lock::DumpLock;
+use crate::{BuildOptions, DumpParts, HnswError, PointMeta};
+
+// Version of everything this crate writes next to the hnsw_rs dump. hnsw_rs
+// versions its own .graph/.data files and reads older ones itself.
+//   1: unversioned metadata sidecar, or none at all
+//   2: metadata sidecar prefixed with META_MAGIC and the version
+//   3: sync versions, weights, build options, level scale, privacy epsilon
+//      and dump parts in the metadata
+//   4: removed points the dumped graph still holds
+//
+// bincode writes no field names or lengths, so a field added to PointMeta
+// needs a new version here and the previous layout kept below.
+pub(crate) const FORMAT_VERSION: u32 = 4;
+
+const META_MAGIC: &[u8; 8] = b"HNSWMETA";
+
+pub(crate) fn encode_meta(body: Vec<u8>) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(META_MAGIC.len() + 4 + body.len());
+    bytes.extend_from_slice(META_MAGIC);
+    bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    bytes.extend(body);
+    bytes
+}
+
+// Splits a metadata sidecar into its format version and bincode body.
+pub(crate) fn decode_meta(bytes: &[u8]) -> Result<(u32, &[u8]), HnswError> {
+    let Some(rest) = bytes.strip_prefix(META_MAGIC) else {
+        return Ok((1, bytes));
+    };
+    if rest.len() < 4 {
+        return Err(HnswError::Corrupted(
+            "metadata header is truncated".to_string(),
+        ));
+    }
+    let (version, body) = rest.split_at(4);
+    let version = u32::from_le_bytes(version.try_into().unwrap());
+    if version > FORMAT_VERSION {
+        return Err(HnswError::UnsupportedVersion {
+            found: version,
+            supported: FORMAT_VERSION,
+        });
+    }
+    Ok((version, body))
+}
+
+// The metadata layout of versions 1 and 2.
+#[derive(Deserialize)]
+struct PointMetaV2 {
+    namespaces: HashMap<u64, u32>,
+    keys: HashMap<u64, String>,
+    next_key_id: u64,
+    payloads: HashMap<u64, Vec<u8>>,
+    documents: HashMap<u64, u64>,
+    attrs: HashMap<u64, Attrs>,
+    timestamps: HashMap<u64, i64>,
+    hidden: HashSet<u64>,
+}
+
+impl From<PointMetaV2> for PointMeta {
+    fn from(old: PointMetaV2) -> Self {
+        PointMeta {
+            namespaces: old.namespaces,
+            keys: old.keys,
+            next_key_id: old.next_key_id,
+            payloads: old.payloads,
+            documents: old.documents,
+            attrs: old.attrs,
+            timestamps: old.timestamps,
+            hidden: old.hidden,
+            ..PointMeta::default()
+        }
+    }
+}
+
+// The metadata layout of version 3. Those saves purged first, so the graph
+// they wrote has no removed points in it.
+#[derive(Deserialize)]
+struct PointMetaV3 {
+    namespaces: HashMap<u64, u32>,
+    keys: HashMap<u64, String>,
+    next_key_id: u64,
+    payloads: HashMap<u64, Vec<u8>>,
+    documents: HashMap<u64, u64>,
+    attrs: HashMap<u64, Attrs>,
+    timestamps: HashMap<u64, i64>,
+    hidden: HashSet<u64>,
+    clock: u64,
+    versions: HashMap<u64, u64>,
+    removed: HashMap<u64, u64>,
+    weights: Option<Vec<f32>>,
+    build: BuildOptions,
+    level_scale: Option<f64>,
+    privacy_epsilon: Option<f64>,
+    parts: Option<DumpParts>,
+}
+
+impl From<PointMetaV3> for PointMeta {
+    fn from(old: PointMetaV3) -> Self {
+        PointMeta {
+            namespaces: old.namespaces,
+            keys: old.keys,
+            next_key_id: old.next_key_id,
+            payloads: old.payloads,
+            documents: old.documents,
+            attrs: old.attrs,
+            timestamps: old.timestamps,
+            hidden: old.hidden,
+            clock: old.clock,
+            versions: old.versions,
+            removed: old.removed,
+            weights: old.weights,
+            build: old.build,
+            level_scale: old.level_scale,
+            privacy_epsilon: old.privacy_epsilon,
+            parts: old.parts,
+            ..PointMeta::default()
+        }
+    }
+}
+
+// Reads a metadata body in the layout of its version.
+pub(crate) fn deserialize_meta(version: u32, body: &[u8]) -> Result<PointMeta, HnswError> {
+    match version {
+        1 | 2 => bincode::deserialize::<PointMetaV2>(body).map(PointMeta::from),
+        3 => bincode::deserialize::<PointMetaV3>(body).map(PointMeta::from),
+        _ => bincode::deserialize(body),
+    }
+    .map_err(|e| HnswError::Corrupted(e.to_string()))
+}
+
+fn meta_version(directory: &str, basename: &str) -> Result<u32, HnswError> {
+    let graph = Path::new(directory).join(format!("{basename}.hnsw.graph"));
+    if !graph.exists() {
+        return Err(HnswError::IndexNotFound(graph.display().to_string()));
+    }
+    let path = PointMeta::path(directory, basename);
+    if !path.exists() {
+        return Ok(1);
+    }
+    Ok(decode_meta(&fs::read(path)?)?.0)
+}
+
+// Format version of a saved dump. Fails with UnsupportedVersion for dumps
+// written by a newer release of this library.
+#[uniffi::export]
+pub fn dump_format_version(directory: String, basename: String) -> Result<u32, HnswError> {
+    let _lock = DumpLock::shared(&directory, &basename)?;
+    meta_version(&directory, &basename)
+}
+
+// Older dumps load as they are and are written in the current format on the
+// next save; this upgrades one in place without loading the graph. Returns
+// false when it was already current.
+#[uniffi::export]
+pub fn migrate_dump(directory: String, basename: String) -> Result<bool, HnswError> {
+    let _lock = DumpLock::exclusive(&directory, &basename)?;
+    if meta_version(&directory, &basename)? == FORMAT_VERSION {
+        return Ok(false);
+    }
+    PointMeta::load(&directory, &basename)?.save(&directory, &basename)?;
+    Ok(true)
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use crate::{HnswError, SearchResult};
+
+// Standard RRF damping constant; keeps a single first place from dominating.
+pub(crate) const RRF_K: f32 = 60.0;
+
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum FusionStrategy {
+    // sum of weight / (60 + rank) over the lists an id appears in.
+    ReciprocalRank { weights: Vec<f32> },
+    // Distances are min-max normalized per list into a similarity in [0, 1]
+    // (1 for the list's best hit), then summed with the given weights.
+    WeightedScore { weights: Vec<f32> },
+}
+
+// An empty weight list means every list counts equally.
+fn list_weights(weights: &[f32], lists: usize) -> Result<Vec<f32>, HnswError> {
+    if weights.is_empty() {
+        return Ok(vec![1.0; lists]);
+    }
+    if weights.len() != lists {
+        return Err(HnswError::InvalidArgument(format!(
+            "{} weights given for {lists} result lists",
+            weights.len()
+        )));
+    }
+    if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+        return Err(HnswError::InvalidArgument(
+            "weights must be finite and non-negative".to_string(),
+        ));
+    }
+    Ok(weights.to_vec())
+}
+
+fn normalized(results: &[SearchResult]) -> Vec<f32> {
+    let (min, max) = results
+        .iter()
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), r| {
+            (lo.min(r.distance), hi.max(r.distance))
+        });
+    results
+        .iter()
+        .map(|r| {
+            if max > min {
+                (max - r.distance) / (max - min)
+            } else {
+                1.0
+            }
+        })
+        .collect()
+}
+
+// Merges result lists from several indices (or several queries) into one
+// ranking. Each fused result keeps its smallest distance across lists and
+// carries the fused score in `score`, best first.
+#[uniffi::export]
+pub fn fuse_results(
+    results: Vec<Vec<SearchResult>>,
+    k: u32,
+    strategy: FusionStrategy,
+) -> Result<Vec<SearchResult>, HnswError> {
+    let mut fused: HashMap<u64, (f32, f32)> = HashMap::new();
+    let mut add = |hit: &SearchResult, score: f32| {
+        let entry = fused.entry(hit.id).or_insert((0.0, f32::INFINITY));
+        entry.0 += score;
+        entry.1 = entry.1.min(hit.distance);
+    };
+    match &strategy {
+        FusionStrategy::ReciprocalRank { weights } => {
+            let weights = list_weights(weights, results.len())?;
+            for (list, weight) in results.iter().zip(weights) {
+                for (rank, hit) in list.iter().enumerate() {
+                    add(hit, weight / (RRF_K + rank as f32 + 1.0));
+                }
+            }
+        }
+        FusionStrategy::WeightedScore { weights } => {
+            let weights = list_weights(weights, results.len())?;
+            for (list, weight) in results.iter().zip(weights) {
+                for (hit, similarity) in list.iter().zip(normalized(list)) {
+                    add(hit, weight * similarity);
+                }
+            }
+        }
+    }
+
+    let mut merged: Vec<SearchResult> = fused
+        .into_iter()
+        .map(|(id, (score, distance))| SearchResult {
+            score: Some(score),
+            ..SearchResult::new(id, distance)
+        })
+        .collect();
+    merged.sort_by(|a, b| {
+        b.score
+            .unwrap_or_default()
+            .total_cmp(&a.score.unwrap_or_default())
+            .then(a.distance.total_cmp(&b.distance))
+            .then(a.id.cmp(&b.id))
+    });
+    merged.truncate(k as usize);
+    Ok(merged)
+}
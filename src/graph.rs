@@ -0,0 +1,222 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::sync::{Arc, PoisonError};
+
+use hnsw_rs::hnsw::{Hnsw, Point};
+use hnsw_rs::prelude::*;
+use serde::Serialize;
+
+use crate::{HnswError, HnswIndex, HnswIndexInner, graph_points};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum GraphFormat {
+    GraphMl,
+    Dot,
+    Json,
+}
+
+#[derive(Serialize)]
+struct GraphNode {
+    id: u64,
+    level: u8,
+}
+
+#[derive(Serialize)]
+struct GraphEdge {
+    source: u64,
+    target: u64,
+    layer: u8,
+    distance: f32,
+}
+
+#[derive(Serialize)]
+struct GraphDump {
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+}
+
+// Removed points awaiting a purge, and links to them, are left out.
+fn dump_graph<D>(hnsw: &Hnsw<'static, f32, D>, tombstones: &HashSet<u64>) -> GraphDump
+where
+    D: Distance<f32> + Send + Sync,
+{
+    let mut dump = GraphDump {
+        nodes: Vec::new(),
+        edges: Vec::new(),
+    };
+    for point in graph_points(hnsw) {
+        let source = point.get_origin_id() as u64;
+        if tombstones.contains(&source) {
+            continue;
+        }
+        let level = point.get_point_id().0;
+        dump.nodes.push(GraphNode { id: source, level });
+        for (layer, links) in point.get_neighborhood_id().iter().enumerate() {
+            if layer > level as usize {
+                break;
+            }
+            dump.edges.extend(
+                links
+                    .iter()
+                    .filter(|n| !tombstones.contains(&(n.d_id as u64)))
+                    .map(|n| GraphEdge {
+                        source,
+                        target: n.d_id as u64,
+                        layer: layer as u8,
+                        distance: n.distance,
+                    }),
+            );
+        }
+    }
+    dump
+}
+
+fn write_dot(dump: &GraphDump, out: &mut impl Write) -> std::io::Result<()> {
+    writeln!(out, "digraph hnsw {{")?;
+    for node in &dump.nodes {
+        writeln!(out, "  n{} [level={}];", node.id, node.level)?;
+    }
+    for edge in &dump.edges {
+        writeln!(
+            out,
+            "  n{} -> n{} [layer={}, distance={}];",
+            edge.source, edge.target, edge.layer, edge.distance
+        )?;
+    }
+    writeln!(out, "}}")
+}
+
+fn write_graphml(dump: &GraphDump, out: &mut impl Write) -> std::io::Result<()> {
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    )?;
+    writeln!(
+        out,
+        r#"  <key id="level" for="node" attr.name="level" attr.type="int"/>"#
+    )?;
+    writeln!(
+        out,
+        r#"  <key id="layer" for="edge" attr.name="layer" attr.type="int"/>"#
+    )?;
+    writeln!(
+        out,
+        r#"  <key id="distance" for="edge" attr.name="distance" attr.type="float"/>"#
+    )?;
+    writeln!(out, r#"  <graph id="hnsw" edgedefault="directed">"#)?;
+    for node in &dump.nodes {
+        writeln!(
+            out,
+            r#"    <node id="n{}"><data key="level">{}</data></node>"#,
+            node.id, node.level
+        )?;
+    }
+    for edge in &dump.edges {
+        writeln!(
+            out,
+            r#"    <edge source="n{}" target="n{}"><data key="layer">{}</data><data key="distance">{}</data></edge>"#,
+            edge.source, edge.target, edge.layer, edge.distance
+        )?;
+    }
+    writeln!(out, "  </graph>")?;
+    writeln!(out, "</graphml>")
+}
+
+// hnsw_rs starts every search from the first point to reach the highest
+// layer, which is the first point that layer yields.
+pub(crate) fn entry_point<D>(hnsw: &Hnsw<'static, f32, D>) -> Option<Arc<Point<'static, f32>>>
+where
+    D: Distance<f32> + Send + Sync,
+{
+    let indexation = hnsw.get_point_indexation();
+    let top = indexation.get_max_level_observed() as usize;
+    indexation.get_layer_iterator(top).next()
+}
+
+pub(crate) fn entry_point_id<D>(hnsw: &Hnsw<'static, f32, D>) -> Option<u64>
+where
+    D: Distance<f32> + Send + Sync,
+{
+    entry_point(hnsw).map(|point| point.get_origin_id() as u64)
+}
+
+fn max_layer<D>(hnsw: &Hnsw<'static, f32, D>) -> u32
+where
+    D: Distance<f32> + Send + Sync,
+{
+    hnsw.get_point_indexation().get_max_level_observed() as u32
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    #[uniffi::method]
+    // Removed points are unknown here and left out of the links.
+    #[uniffi::method]
+    pub fn graph_neighbors(&self, id: u64, layer: u32) -> Result<Vec<u64>, HnswError> {
+        let guard = self.lock_inner()?;
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let point = known
+            .contains(&id)
+            .then(|| self.point_map(&guard).point(id))
+            .flatten()
+            .ok_or_else(|| HnswError::InvalidArgument(format!("Unknown id: {id}")))?;
+        let layers = point.get_neighborhood_id();
+        Ok(layers.get(layer as usize).map_or_else(Vec::new, |links| {
+            links
+                .iter()
+                .map(|n| n.d_id as u64)
+                .filter(|id| known.contains(id))
+                .collect()
+        }))
+    }
+
+    // Can be a removed point until the next purge: hnsw_rs keeps starting
+    // searches from it.
+    #[uniffi::method]
+    pub fn get_entry_point(&self) -> Result<Option<u64>, HnswError> {
+        let guard = self.lock_inner()?;
+        Ok(match &*guard {
+            HnswIndexInner::L2(inner) => entry_point_id(&inner.hnsw),
+            HnswIndexInner::Cosine(inner) => entry_point_id(&inner.hnsw),
+            HnswIndexInner::Dot(inner) => entry_point_id(&inner.hnsw),
+            HnswIndexInner::L1(inner) => entry_point_id(&inner.hnsw),
+        })
+    }
+
+    #[uniffi::method]
+    pub fn export_graph(&self, path: String, format: GraphFormat) -> Result<(), HnswError> {
+        let dump = {
+            let guard = self.lock_inner()?;
+            let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+            let tombstones = &meta.tombstones;
+            match &*guard {
+                HnswIndexInner::L2(inner) => dump_graph(&inner.hnsw, tombstones),
+                HnswIndexInner::Cosine(inner) => dump_graph(&inner.hnsw, tombstones),
+                HnswIndexInner::Dot(inner) => dump_graph(&inner.hnsw, tombstones),
+                HnswIndexInner::L1(inner) => dump_graph(&inner.hnsw, tombstones),
+            }
+        };
+        let mut out = BufWriter::new(File::create(path)?);
+        match format {
+            GraphFormat::GraphMl => write_graphml(&dump, &mut out)?,
+            GraphFormat::Dot => write_dot(&dump, &mut out)?,
+            GraphFormat::Json => serde_json::to_writer(&mut out, &dump)
+                .map_err(|e| HnswError::DumpError(e.to_string()))?,
+        }
+        out.flush()?;
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn get_max_layer(&self) -> Result<u32, HnswError> {
+        let guard = self.lock_inner()?;
+        Ok(match &*guard {
+            HnswIndexInner::L2(inner) => max_layer(&inner.hnsw),
+            HnswIndexInner::Cosine(inner) => max_layer(&inner.hnsw),
+            HnswIndexInner::Dot(inner) => max_layer(&inner.hnsw),
+            HnswIndexInner::L1(inner) => max_layer(&inner.hnsw),
+        })
+    }
+}
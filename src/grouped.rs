@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+use std::sync::PoisonError;
+
+use hnsw_rs::prelude::*;
+
+use crate::attrs::AttrValue;
+use crate::{HnswError, HnswIndex, HnswIndexInner, SearchResult, guarded};
+
+// Largest candidate set search_grouped grows to before settling for what it
+// has found.
+const MAX_GROUP_FETCH: usize = 8192;
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct GroupSearchResult {
+    pub group: AttrValue,
+    // Best first.
+    pub hits: Vec<SearchResult>,
+}
+
+// AttrValue isn't Hash because of floats, so groups are keyed by bits.
+#[derive(PartialEq, Eq, Hash)]
+enum GroupKey {
+    Int(i64),
+    Float(u64),
+    Text(String),
+    Bool(bool),
+}
+
+impl From<&AttrValue> for GroupKey {
+    fn from(value: &AttrValue) -> Self {
+        match value {
+            AttrValue::Int { value } => GroupKey::Int(*value),
+            AttrValue::Float { value } => GroupKey::Float(value.to_bits()),
+            AttrValue::Text { value } => GroupKey::Text(value.clone()),
+            AttrValue::Bool { value } => GroupKey::Bool(*value),
+        }
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // The `groups_k` groups with the closest hits, grouping by the value of
+    // attribute `group_by_attr`, each with up to `per_group_k` of its best
+    // hits. Points without the attribute are skipped. The candidate set grows
+    // until the answer is settled, so one large group can't starve the rest.
+    #[uniffi::method]
+    pub fn search_grouped(
+        &self,
+        query: Vec<f32>,
+        group_by_attr: String,
+        groups_k: u32,
+        per_group_k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<GroupSearchResult>, HnswError> {
+        if groups_k == 0 || per_group_k == 0 {
+            return Ok(Vec::new());
+        }
+        let (groups_k, per_group_k) = (groups_k as usize, per_group_k as usize);
+        let query = self.prepare_query(query, 0)?;
+        let guard = self.lock_inner()?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let group_of = |id: u64| meta.attrs.get(&id).and_then(|a| a.get(&group_by_attr));
+        let filter = |id: &DataId| meta.visible(*id as u64) && group_of(*id as u64).is_some();
+        let filter: Option<&dyn FilterT> = Some(&filter);
+
+        let mut fetch = (groups_k * per_group_k).clamp(1, MAX_GROUP_FETCH);
+        loop {
+            let ef = (self.resolve_ef(ef_search) as usize).max(fetch);
+            let hits = guarded("search_grouped", || match &*guard {
+                HnswIndexInner::L2(inner) => inner.hnsw.search_filter(&query, fetch, ef, filter),
+                HnswIndexInner::Cosine(inner) => {
+                    inner.hnsw.search_filter(&query, fetch, ef, filter)
+                }
+                HnswIndexInner::Dot(inner) => inner.hnsw.search_filter(&query, fetch, ef, filter),
+                HnswIndexInner::L1(inner) => inner.hnsw.search_filter(&query, fetch, ef, filter),
+            })?;
+            let exhausted = hits.len() < fetch;
+
+            // Hits come best first, so groups are in order of their best hit.
+            let mut slots: HashMap<GroupKey, usize> = HashMap::new();
+            let mut groups: Vec<GroupSearchResult> = Vec::new();
+            for hit in hits {
+                let Some(value) = group_of(hit.d_id as u64) else {
+                    continue;
+                };
+                let slot = *slots.entry(GroupKey::from(value)).or_insert_with(|| {
+                    groups.push(GroupSearchResult {
+                        group: value.clone(),
+                        hits: Vec::new(),
+                    });
+                    groups.len() - 1
+                });
+                if groups[slot].hits.len() < per_group_k {
+                    groups[slot].hits.push(SearchResult::from(hit));
+                }
+            }
+            groups.truncate(groups_k);
+            // Later hits are farther than everything here, so once the first
+            // groups are full they can't change.
+            let settled = groups.len() == groups_k
+                && groups.iter().all(|group| group.hits.len() == per_group_k);
+            if settled || exhausted || fetch == MAX_GROUP_FETCH {
+                return Ok(groups);
+            }
+            fetch = (fetch * 2).min(MAX_GROUP_FETCH);
+        }
+    }
+}
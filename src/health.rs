@@ -0,0 +1,84 @@
+use std::sync::{Mutex, PoisonError};
+
+use crate::{HnswError, HnswIndex, HnswIndexInner};
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct HealthReport {
+    // False when the graph and the id set disagree, e.g. after a panic part
+    // way through an insert; reload the index from disk rather than saving it.
+    pub usable: bool,
+    // Locks a panicking call left poisoned. Calls recover them regardless;
+    // this only records that a panic happened.
+    pub poisoned_locks: Vec<String>,
+    pub graph_points: u64,
+    pub known_ids: u64,
+    // A load_lazy index that hasn't been touched yet is not checked.
+    pub loaded: bool,
+}
+
+fn poisoned<T>(name: &str, lock: &Mutex<T>, found: &mut Vec<String>) {
+    if lock.is_poisoned() {
+        found.push(name.to_string());
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Cheap enough to call after catching an error. Poison is cleared once the
+    // index checks out, so a second call reports clean.
+    #[uniffi::method]
+    pub fn health_check(&self) -> Result<HealthReport, HnswError> {
+        let mut poisoned_locks = Vec::new();
+        poisoned("source", &self.source, &mut poisoned_locks);
+        poisoned("inner", &self.inner, &mut poisoned_locks);
+        poisoned("lazy", &self.lazy, &mut poisoned_locks);
+        poisoned("ids", &self.ids, &mut poisoned_locks);
+        poisoned("meta", &self.meta, &mut poisoned_locks);
+        poisoned("sketches", &self.sketches, &mut poisoned_locks);
+
+        let guard = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        let loaded = self
+            .lazy
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_none();
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let tombstones = self
+            .meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .tombstones
+            .len();
+        // Tombstoned points are still in the graph until the next purge.
+        let graph_points = if loaded {
+            (match &*guard {
+                HnswIndexInner::L2(inner) => inner.hnsw.get_nb_point(),
+                HnswIndexInner::Cosine(inner) => inner.hnsw.get_nb_point(),
+                HnswIndexInner::Dot(inner) => inner.hnsw.get_nb_point(),
+                HnswIndexInner::L1(inner) => inner.hnsw.get_nb_point(),
+            }
+            .saturating_sub(tombstones)) as u64
+        } else {
+            known.len() as u64
+        };
+        let known_ids = known.len() as u64;
+        let usable = graph_points == known_ids;
+        drop((known, guard));
+
+        if usable {
+            self.source.clear_poison();
+            self.inner.clear_poison();
+            self.lazy.clear_poison();
+            self.ids.clear_poison();
+            self.meta.clear_poison();
+            self.sketches.clear_poison();
+        }
+        Ok(HealthReport {
+            usable,
+            poisoned_locks,
+            graph_points,
+            known_ids,
+            loaded,
+        })
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::PoisonError;
+
+use crate::{HnswError, HnswIndex};
+
+#[uniffi::export]
+impl HnswIndex {
+    // Hidden points stay in the graph, so searches still route through them,
+    // but no search returns them. Unlike compact this needs no rebuild and is
+    // undone by passing false.
+    #[uniffi::method]
+    pub fn set_hidden(&self, id: u64, hidden: bool) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let _guard = self.lock_inner()?;
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        if !known.contains(&id) {
+            return Err(HnswError::InvalidArgument(format!("Unknown id: {id}")));
+        }
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        if hidden {
+            meta.hidden.insert(id);
+        } else {
+            meta.hidden.remove(&id);
+        }
+        meta.touch(&[id]);
+        Ok(())
+    }
+
+    // Points a search can return, unlike len() which counts every point in
+    // the graph.
+    #[uniffi::method]
+    pub fn count(&self) -> Result<u64, HnswError> {
+        let _guard = self.lock_inner()?;
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let hidden = meta.hidden.iter().filter(|id| known.contains(id)).count();
+        Ok((known.len() - hidden) as u64)
+    }
+
+    // True when `id` is in the index and not hidden.
+    #[uniffi::method]
+    pub fn contains(&self, id: u64) -> Result<bool, HnswError> {
+        let _guard = self.lock_inner()?;
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(known.contains(&id) && meta.visible(id))
+    }
+
+    #[uniffi::method]
+    pub fn is_hidden(&self, id: u64) -> Result<bool, HnswError> {
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(meta.hidden.contains(&id))
+    }
+
+    #[uniffi::method]
+    pub fn hidden_ids(&self) -> Result<Vec<u64>, HnswError> {
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut ids: Vec<u64> = meta.hidden.iter().copied().collect();
+        ids.sort_unstable();
+        Ok(ids)
+    }
+}
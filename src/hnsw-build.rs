@@ -0,0 +1,196 @@
+// Builds an index dump from npy, npz or jsonl vectors with the same code the
+// apps load it with, so a dump made on CI is exactly what the app expects.
+//
+//   cargo run --features cli --bin hnsw-build -- \
+//       --input vectors.npy --dimension 384 --out dist --basename index
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use hnsw::{DistanceType, HnswError, HnswIndex, HnswIndexConfig};
+
+const USAGE: &str = "\
+usage: hnsw-build --input FILE --dimension N --out DIR [options]
+
+input is read by extension: .npy, .npz or .jsonl
+
+options:
+  --basename NAME          dump basename (default: index)
+  --bundle PATH            also write a single-file bundle
+  --distance METRIC        l2, cosine, l1 or dot (default: cosine)
+  --normalize              normalize vectors on insert
+  --max-elements N         initial capacity; grows as needed (default: 10000)
+  --max-connections N      (default: 16)
+  --max-layer N            (default: 16)
+  --ef-construction N      (default: 200)
+  --id-offset N            added to row ids for npy/npz (default: 0)
+  --vectors-name NAME      npz array with the vectors (default: vectors)
+  --ids-name NAME          npz array with the ids (default: row numbers)
+  --vector-field NAME      jsonl field with the vector (default: vector)
+  --id-field NAME          jsonl field with the id (default: id)
+  --payload-field NAME     jsonl field to keep as payload; repeatable";
+
+struct Args {
+    values: HashMap<String, String>,
+    payload_fields: Vec<String>,
+    normalize: bool,
+}
+
+impl Args {
+    fn parse() -> Result<Self, String> {
+        let mut values = HashMap::new();
+        let mut payload_fields = Vec::new();
+        let mut normalize = false;
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                return Err(format!("unexpected argument '{arg}'"));
+            };
+            if name == "normalize" {
+                normalize = true;
+                continue;
+            }
+            if name == "help" {
+                return Err(String::new());
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| format!("--{name} needs a value"))?;
+            if name == "payload-field" {
+                payload_fields.push(value);
+            } else {
+                values.insert(name.to_string(), value);
+            }
+        }
+        Ok(Args {
+            values,
+            payload_fields,
+            normalize,
+        })
+    }
+
+    fn required(&self, name: &str) -> Result<&str, String> {
+        self.values
+            .get(name)
+            .map(String::as_str)
+            .ok_or_else(|| format!("--{name} is required"))
+    }
+
+    fn string(&self, name: &str, default: &str) -> String {
+        self.values
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| default.to_string())
+    }
+
+    fn number<T: std::str::FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        match self.values.get(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| format!("--{name} must be a number, got '{value}'")),
+            None => Ok(default),
+        }
+    }
+}
+
+fn distance(name: &str) -> Result<DistanceType, String> {
+    match name.to_ascii_lowercase().as_str() {
+        "l2" => Ok(DistanceType::L2),
+        "cosine" => Ok(DistanceType::Cosine),
+        "l1" => Ok(DistanceType::L1),
+        "dot" => Ok(DistanceType::Dot),
+        _ => Err(format!("unknown distance '{name}'")),
+    }
+}
+
+fn build(args: &Args) -> Result<(), String> {
+    let input = args.required("input")?;
+    let out = args.required("out")?;
+    let config = HnswIndexConfig {
+        max_nb_connection: args.number("max-connections", 16)?,
+        max_elements: args.number("max-elements", 10_000)?,
+        max_layer: args.number("max-layer", 16)?,
+        ef_construction: args.number("ef-construction", 200)?,
+        dimension: args
+            .required("dimension")?
+            .parse()
+            .map_err(|_| "--dimension must be a number")?,
+        distance: distance(&args.string("distance", "cosine"))?,
+        normalize_vectors: args.normalize,
+        level_scale: None,
+    };
+    let index = HnswIndex::new(config);
+    index.set_auto_grow(true);
+
+    let start = Instant::now();
+    let extension = Path::new(input)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let fail = |e: HnswError| format!("{input}: {e}");
+    let id_offset = args.number("id-offset", 0)?;
+    let imported = match extension.as_str() {
+        "npy" => index
+            .import_npy(input.to_string(), id_offset)
+            .map_err(fail)?,
+        "npz" => index
+            .import_npz(
+                input.to_string(),
+                args.string("vectors-name", "vectors"),
+                args.values.get("ids-name").cloned(),
+                id_offset,
+            )
+            .map_err(fail)?,
+        "jsonl" => {
+            let report = index
+                .import_jsonl(
+                    input.to_string(),
+                    args.string("vector-field", "vector"),
+                    args.string("id-field", "id"),
+                    args.payload_fields.clone(),
+                )
+                .map_err(fail)?;
+            for error in &report.errors {
+                eprintln!("{input}:{}: {}", error.line, error.message);
+            }
+            if !report.errors.is_empty() {
+                return Err(format!("{} bad lines", report.errors.len()));
+            }
+            report.imported
+        }
+        _ => return Err(format!("{input}: expected a .npy, .npz or .jsonl file")),
+    };
+    eprintln!("inserted {imported} vectors in {:.1?}", start.elapsed());
+
+    std::fs::create_dir_all(out).map_err(|e| format!("{out}: {e}"))?;
+    let basename = args.string("basename", "index");
+    index
+        .save(out.to_string(), basename.clone())
+        .map_err(|e| format!("saving {out}/{basename}: {e}"))?;
+    eprintln!("wrote {out}/{basename}.hnsw.*");
+    if let Some(bundle) = args.values.get("bundle") {
+        index
+            .save_bundle(bundle.clone())
+            .map_err(|e| format!("{bundle}: {e}"))?;
+        eprintln!("wrote {bundle}");
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let result = Args::parse().and_then(|args| build(&args));
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) if message.is_empty() => {
+            println!("{USAGE}");
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("hnsw-build: {message}\n\n{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
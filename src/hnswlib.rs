@@ -0,0 +1,135 @@
+use std::fs::File;
+use std::io::{BufReader, Read};
+
+use crate::{DistanceType, HnswError, HnswIndex, HnswIndexConfig};
+
+const BATCH_ROWS: usize = 1024;
+
+// hnswlib keeps its delete flag in the third byte of each level-0 link list
+// header.
+const DELETE_MARK: u8 = 0x01;
+
+// Fields of hnswlib's HierarchicalNSW::saveIndex header, in file order.
+// size_t fields are written as 8 bytes on every platform hnswlib targets.
+struct Header {
+    offset_level0: usize,
+    max_elements: usize,
+    element_count: usize,
+    size_per_element: usize,
+    label_offset: usize,
+    offset_data: usize,
+    m: usize,
+    ef_construction: usize,
+}
+
+fn read_u64(reader: &mut impl Read) -> Result<u64, HnswError> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_size(reader: &mut impl Read) -> Result<usize, HnswError> {
+    usize::try_from(read_u64(reader)?)
+        .map_err(|_| HnswError::InvalidArgument("hnswlib header value out of range".into()))
+}
+
+impl Header {
+    fn read(reader: &mut impl Read) -> Result<Self, HnswError> {
+        let offset_level0 = read_size(reader)?;
+        let max_elements = read_size(reader)?;
+        let element_count = read_size(reader)?;
+        let size_per_element = read_size(reader)?;
+        let label_offset = read_size(reader)?;
+        let offset_data = read_size(reader)?;
+        // maxlevel_ (i32) and enterpoint_node_ (u32) aren't needed for a rebuild.
+        let mut skip = [0u8; 8];
+        reader.read_exact(&mut skip)?;
+        let _max_m = read_size(reader)?;
+        let _max_m0 = read_size(reader)?;
+        let m = read_size(reader)?;
+        let _mult = read_u64(reader)?;
+        let ef_construction = read_size(reader)?;
+        if offset_data < offset_level0 + 4
+            || label_offset < offset_data
+            || size_per_element < label_offset + 8
+        {
+            return Err(HnswError::InvalidArgument(
+                "not an hnswlib index: inconsistent element layout".into(),
+            ));
+        }
+        Ok(Self {
+            offset_level0,
+            max_elements,
+            element_count,
+            size_per_element,
+            label_offset,
+            offset_data,
+            m,
+            ef_construction,
+        })
+    }
+
+    fn dimension(&self) -> usize {
+        (self.label_offset - self.offset_data) / 4
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // hnswlib's graph layout can't be mapped onto hnsw_rs, so the vectors and
+    // labels are read from the level-0 block and the graph is rebuilt with the
+    // same M and ef_construction. Elements marked deleted are skipped. Labels
+    // become ids.
+    #[uniffi::constructor]
+    pub fn from_hnswlib(
+        path: String,
+        dimension: u32,
+        distance_type: DistanceType,
+    ) -> Result<Self, HnswError> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let header = Header::read(&mut reader)?;
+        if header.dimension() != dimension as usize {
+            return Err(HnswError::DimensionMismatch {
+                expected: dimension,
+                got: header.dimension() as u32,
+            });
+        }
+        let index = HnswIndex::new(HnswIndexConfig {
+            max_nb_connection: header.m as u32,
+            max_elements: header.max_elements.max(header.element_count) as u64,
+            max_layer: 16,
+            ef_construction: header.ef_construction as u32,
+            dimension,
+            distance: distance_type,
+            normalize_vectors: false,
+            level_scale: None,
+        });
+
+        let mut element = vec![0u8; header.size_per_element];
+        let mut data = Vec::with_capacity(BATCH_ROWS);
+        let mut ids = Vec::with_capacity(BATCH_ROWS);
+        for _ in 0..header.element_count {
+            reader.read_exact(&mut element)?;
+            if element[header.offset_level0 + 2] & DELETE_MARK != 0 {
+                continue;
+            }
+            let vector = element[header.offset_data..header.label_offset]
+                .as_chunks::<4>()
+                .0
+                .iter()
+                .map(|&b| f32::from_le_bytes(b))
+                .collect();
+            let mut label = [0u8; 8];
+            label.copy_from_slice(&element[header.label_offset..header.label_offset + 8]);
+            data.push(vector);
+            ids.push(u64::from_le_bytes(label));
+            if data.len() == BATCH_ROWS {
+                index.insert_batch(std::mem::take(&mut data), std::mem::take(&mut ids))?;
+            }
+        }
+        if !data.is_empty() {
+            index.insert_batch(data, ids)?;
+        }
+        Ok(index)
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::fusion::RRF_K;
+use crate::{HnswError, HnswIndex, SearchResult};
+
+const BM25_K1: f32 = 1.2;
+const BM25_B: f32 = 0.75;
+// Each ranking contributes this many candidates per requested result.
+const CANDIDATE_OVERSAMPLE: usize = 4;
+
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+}
+
+#[derive(Default)]
+struct KeywordIndex {
+    // term -> (id -> term frequency)
+    postings: HashMap<String, HashMap<u64, u32>>,
+    lengths: HashMap<u64, u32>,
+    total_length: u64,
+}
+
+impl KeywordIndex {
+    fn insert(&mut self, id: u64, text: &str) {
+        self.remove(id);
+        let mut length = 0;
+        for term in tokenize(text) {
+            *self
+                .postings
+                .entry(term)
+                .or_default()
+                .entry(id)
+                .or_default() += 1;
+            length += 1;
+        }
+        self.lengths.insert(id, length);
+        self.total_length += length as u64;
+    }
+
+    fn remove(&mut self, id: u64) {
+        let Some(length) = self.lengths.remove(&id) else {
+            return;
+        };
+        self.total_length -= length as u64;
+        self.postings.retain(|_, docs| {
+            docs.remove(&id);
+            !docs.is_empty()
+        });
+    }
+
+    // Best first.
+    fn search(&self, terms: &[String], limit: usize) -> Vec<(u64, f32)> {
+        let n = self.lengths.len() as f32;
+        if n == 0.0 {
+            return Vec::new();
+        }
+        let average = self.total_length as f32 / n;
+        let mut scores: HashMap<u64, f32> = HashMap::new();
+        for term in terms.iter().flat_map(|t| tokenize(t)) {
+            let Some(docs) = self.postings.get(&term) else {
+                continue;
+            };
+            let df = docs.len() as f32;
+            let idf = (1.0 + (n - df + 0.5) / (df + 0.5)).ln();
+            for (&id, &tf) in docs {
+                let tf = tf as f32;
+                let length = self.lengths[&id] as f32;
+                let norm = BM25_K1 * (1.0 - BM25_B + BM25_B * length / average.max(1.0));
+                *scores.entry(id).or_default() += idf * tf * (BM25_K1 + 1.0) / (tf + norm);
+            }
+        }
+        let mut ranked: Vec<(u64, f32)> = scores.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked.truncate(limit);
+        ranked
+    }
+}
+
+// Keyword side of hybrid search: a BM25 index over payloads that are valid
+// UTF-8. It is built when the searcher is created; call `reindex` after
+// changing a payload, or `refresh` after bulk changes.
+#[derive(uniffi::Object)]
+pub struct HybridSearcher {
+    index: Arc<HnswIndex>,
+    keywords: Mutex<KeywordIndex>,
+}
+
+impl HybridSearcher {
+    fn build(index: &HnswIndex) -> Result<KeywordIndex, HnswError> {
+        let meta = index.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut keywords = KeywordIndex::default();
+        for (&id, payload) in &meta.payloads {
+            if let Ok(text) = std::str::from_utf8(payload) {
+                keywords.insert(id, text);
+            }
+        }
+        Ok(keywords)
+    }
+}
+
+#[uniffi::export]
+impl HybridSearcher {
+    #[uniffi::constructor]
+    pub fn new(index: Arc<HnswIndex>) -> Result<Self, HnswError> {
+        let keywords = Self::build(&index)?;
+        Ok(HybridSearcher {
+            index,
+            keywords: Mutex::new(keywords),
+        })
+    }
+
+    #[uniffi::method]
+    pub fn refresh(&self) -> Result<(), HnswError> {
+        let rebuilt = Self::build(&self.index)?;
+        *self.keywords.lock().unwrap_or_else(PoisonError::into_inner) = rebuilt;
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn reindex(&self, id: u64) -> Result<(), HnswError> {
+        let payload = self.index.get_payload(id)?;
+        let mut keywords = self.keywords.lock().unwrap_or_else(PoisonError::into_inner);
+        match payload.as_deref().map(std::str::from_utf8) {
+            Some(Ok(text)) => keywords.insert(id, text),
+            _ => keywords.remove(id),
+        }
+        Ok(())
+    }
+
+    // Reciprocal rank fusion of the dense and keyword rankings. `alpha` in
+    // [0, 1] weights the dense side; 1 is pure vector search. `score` holds
+    // the fused score. `distance` is the vector distance, or infinity for hits
+    // only the keyword side found.
+    #[uniffi::method]
+    pub fn hybrid_search(
+        &self,
+        query_vector: Vec<f32>,
+        query_terms: Vec<String>,
+        k: u32,
+        alpha: f32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        if !(0.0..=1.0).contains(&alpha) {
+            return Err(HnswError::InvalidArgument(
+                "alpha must be between 0 and 1".to_string(),
+            ));
+        }
+        let depth = k as usize * CANDIDATE_OVERSAMPLE;
+        let dense = self
+            .index
+            .search(query_vector, depth as u32, (depth * 2) as u32)?;
+        let sparse = self
+            .keywords
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .search(&query_terms, depth);
+
+        let mut fused: HashMap<u64, (f32, f32)> = HashMap::new();
+        for (rank, hit) in dense.iter().enumerate() {
+            let entry = fused.entry(hit.id).or_insert((0.0, f32::INFINITY));
+            entry.0 += alpha / (RRF_K + rank as f32 + 1.0);
+            entry.1 = hit.distance;
+        }
+        let guard = self.index.lock_inner()?;
+        let known = self
+            .index
+            .ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let meta = self
+            .index
+            .meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        for (rank, &(id, _)) in sparse.iter().enumerate() {
+            // Payloads can outlive points removed by a rebuild, and the
+            // keyword side knows nothing of hidden points.
+            if !known.contains(&id) || !meta.visible(id) {
+                continue;
+            }
+            let entry = fused.entry(id).or_insert((0.0, f32::INFINITY));
+            entry.0 += (1.0 - alpha) / (RRF_K + rank as f32 + 1.0);
+        }
+        drop((meta, known, guard));
+
+        let mut results: Vec<SearchResult> = fused
+            .into_iter()
+            .map(|(id, (score, distance))| SearchResult {
+                score: Some(score),
+                ..SearchResult::new(id, distance)
+            })
+            .collect();
+        results.sort_by(|a, b| {
+            b.score
+                .unwrap_or_default()
+                .total_cmp(&a.score.unwrap_or_default())
+                .then(a.distance.total_cmp(&b.distance))
+        });
+        results.truncate(k as usize);
+        Ok(results)
+    }
+}
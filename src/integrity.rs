@@ -0,0 +1,168 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, PoisonError};
+
+use hnsw_rs::hnsw::{Hnsw, Point, PointId};
+use hnsw_rs::prelude::*;
+
+use crate::graph::entry_point;
+use crate::{HnswError, HnswIndex, HnswIndexInner, graph_points};
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct IntegrityReport {
+    // True when nothing below was found.
+    pub ok: bool,
+    pub deep: bool,
+    // Live points only; removed ones awaiting a purge are left out of this
+    // and everything below, though searches still pass through them.
+    pub graph_points: u64,
+    // Ids held by more than one point in the graph.
+    pub duplicate_ids: Vec<u64>,
+    // Ids the index lists but the graph lacks, and the reverse.
+    pub missing_ids: Vec<u64>,
+    pub unlisted_ids: Vec<u64>,
+    // Points whose vector has the wrong length or, with deep, a NaN or
+    // infinite component.
+    pub bad_vectors: Vec<u64>,
+    // Deep only: links to points that don't exist or whose id doesn't match,
+    // and points no search can reach from the entry point.
+    pub dangling_links: u64,
+    pub unreachable_ids: Vec<u64>,
+}
+
+#[derive(Default)]
+struct Scan {
+    graph_points: u64,
+    ids: HashSet<u64>,
+    duplicate_ids: Vec<u64>,
+    bad_vectors: Vec<u64>,
+    dangling_links: u64,
+    unreachable_ids: Vec<u64>,
+}
+
+fn scan<D>(
+    hnsw: &Hnsw<'static, f32, D>,
+    dimension: usize,
+    deep: bool,
+    tombstones: &HashSet<u64>,
+) -> Scan
+where
+    D: Distance<f32> + Send + Sync,
+{
+    let mut scan = Scan::default();
+    let mut points: HashMap<PointId, Arc<Point<'static, f32>>> = HashMap::new();
+    for point in graph_points(hnsw) {
+        let id = point.get_origin_id() as u64;
+        // Still linked, so kept for the walk, but not reported on.
+        if tombstones.contains(&id) {
+            if deep {
+                points.insert(point.get_point_id(), point);
+            }
+            continue;
+        }
+        scan.graph_points += 1;
+        if !scan.ids.insert(id) {
+            scan.duplicate_ids.push(id);
+        }
+        let v = point.get_v();
+        if v.len() != dimension || (deep && v.iter().any(|x| !x.is_finite())) {
+            scan.bad_vectors.push(id);
+        }
+        if deep {
+            points.insert(point.get_point_id(), Arc::clone(&point));
+        }
+    }
+    if !deep {
+        return scan;
+    }
+
+    let mut reached: HashSet<PointId> = HashSet::new();
+    let mut queue: VecDeque<PointId> = entry_point(hnsw)
+        .map(|entry| entry.get_point_id())
+        .into_iter()
+        .collect();
+    reached.extend(queue.iter().copied());
+    for point in points.values() {
+        let level = point.get_point_id().0 as usize;
+        for links in point.get_neighborhood_id().iter().take(level + 1) {
+            for n in links {
+                if points.get(&n.p_id).map(|p| p.get_origin_id()) != Some(n.d_id) {
+                    scan.dangling_links += 1;
+                }
+            }
+        }
+    }
+    while let Some(p_id) = queue.pop_front() {
+        let Some(point) = points.get(&p_id) else {
+            continue;
+        };
+        for links in point.get_neighborhood_id() {
+            for n in links {
+                if points.contains_key(&n.p_id) && reached.insert(n.p_id) {
+                    queue.push_back(n.p_id);
+                }
+            }
+        }
+    }
+    scan.unreachable_ids = points
+        .iter()
+        .filter(|(p_id, _)| !reached.contains(p_id))
+        .map(|(_, point)| point.get_origin_id() as u64)
+        .filter(|id| !tombstones.contains(id))
+        .collect();
+    scan
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // For a "repair library" flow: checks the graph against the id set and
+    // the config. `deep` also follows every link, reads every vector value
+    // and walks the graph from its entry point, so it costs about as much as
+    // a rebuild and holds the graph lock throughout. Fix a bad report by
+    // reloading from a good dump or rebuilding from the source data. It
+    // reports on the graph as it is and never purges or rebuilds it.
+    #[uniffi::method]
+    pub fn verify_integrity(&self, deep: bool) -> Result<IntegrityReport, HnswError> {
+        let guard = self.lock_inner()?;
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let tombstones = self
+            .meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .tombstones
+            .clone();
+        let dimension = self.config.dimension as usize;
+        let mut scan = match &*guard {
+            HnswIndexInner::L2(inner) => scan(&inner.hnsw, dimension, deep, &tombstones),
+            HnswIndexInner::Cosine(inner) => scan(&inner.hnsw, dimension, deep, &tombstones),
+            HnswIndexInner::Dot(inner) => scan(&inner.hnsw, dimension, deep, &tombstones),
+            HnswIndexInner::L1(inner) => scan(&inner.hnsw, dimension, deep, &tombstones),
+        };
+        let mut missing_ids: Vec<u64> = known.difference(&scan.ids).copied().collect();
+        let mut unlisted_ids: Vec<u64> = scan.ids.difference(&known).copied().collect();
+        drop((known, guard));
+
+        missing_ids.sort_unstable();
+        unlisted_ids.sort_unstable();
+        scan.duplicate_ids.sort_unstable();
+        scan.duplicate_ids.dedup();
+        scan.bad_vectors.sort_unstable();
+        scan.unreachable_ids.sort_unstable();
+        let ok = missing_ids.is_empty()
+            && unlisted_ids.is_empty()
+            && scan.duplicate_ids.is_empty()
+            && scan.bad_vectors.is_empty()
+            && scan.dangling_links == 0
+            && scan.unreachable_ids.is_empty();
+        Ok(IntegrityReport {
+            ok,
+            deep,
+            graph_points: scan.graph_points,
+            duplicate_ids: scan.duplicate_ids,
+            missing_ids,
+            unlisted_ids,
+            bad_vectors: scan.bad_vectors,
+            dangling_links: scan.dangling_links,
+            unreachable_ids: scan.unreachable_ids,
+        })
+    }
+}
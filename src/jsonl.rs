@@ -0,0 +1,238 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::export::ExportRows;
+use crate::{HnswError, HnswIndex, PointMeta};
+
+const BATCH_ROWS: usize = 1024;
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct JsonlLineError {
+    pub line: u64,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, uniffi::Record)]
+pub struct JsonlImportReport {
+    pub imported: u64,
+    pub errors: Vec<JsonlLineError>,
+}
+
+struct Row {
+    line: u64,
+    vector: Vec<f32>,
+    payload: Option<Vec<u8>>,
+}
+
+#[derive(Default)]
+struct Pending {
+    numbered: Vec<(u64, Row)>,
+    keyed: Vec<(String, Row)>,
+}
+
+impl Pending {
+    fn len(&self) -> usize {
+        self.numbered.len() + self.keyed.len()
+    }
+}
+
+#[derive(Serialize)]
+struct ExportLine<'a> {
+    id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key: Option<&'a str>,
+    vector: &'a [f32],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payload: Option<Value>,
+}
+
+fn payload_value(payload: &[u8]) -> Value {
+    if let Ok(value) = serde_json::from_slice(payload) {
+        return value;
+    }
+    match std::str::from_utf8(payload) {
+        Ok(text) => Value::String(text.to_string()),
+        Err(_) => Value::Array(
+            payload
+                .iter()
+                .map(|&b| Value::Number(u64::from(b).into()))
+                .collect(),
+        ),
+    }
+}
+
+pub(crate) fn write_jsonl(path: &str, rows: &ExportRows) -> Result<(), HnswError> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for row in rows.chunks(BATCH_ROWS).flatten() {
+        let line = ExportLine {
+            id: row.id,
+            key: row.key.as_deref(),
+            vector: &row.vector,
+            payload: row.payload.as_deref().map(payload_value),
+        };
+        serde_json::to_writer(&mut writer, &line).map_err(|e| HnswError::IoError(e.to_string()))?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+// Field names starting with '/' are JSON pointers into nested objects,
+// e.g. "/data/0/embedding".
+fn field<'a>(value: &'a Value, name: &str) -> Option<&'a Value> {
+    if name.starts_with('/') {
+        value.pointer(name)
+    } else {
+        value.get(name)
+    }
+}
+
+fn parse_vector(value: &Value, name: &str) -> Result<Vec<f32>, String> {
+    field(value, name)
+        .and_then(Value::as_array)
+        .ok_or_else(|| format!("missing array field '{name}'"))?
+        .iter()
+        .map(|x| {
+            x.as_f64()
+                .map(|x| x as f32)
+                .ok_or_else(|| format!("non-numeric value in '{name}'"))
+        })
+        .collect()
+}
+
+fn parse_payload(value: &Value, fields: &[String]) -> Result<Option<Vec<u8>>, String> {
+    if fields.is_empty() {
+        return Ok(None);
+    }
+    let captured: Map<String, Value> = fields
+        .iter()
+        .filter_map(|name| field(value, name).map(|v| (name.clone(), v.clone())))
+        .collect();
+    serde_json::to_vec(&Value::Object(captured))
+        .map(Some)
+        .map_err(|e| e.to_string())
+}
+
+// Called under the insert's meta lock, so the payloads land with the points.
+fn attach_payloads<T>(meta: &mut PointMeta, rows: &[(T, Row)], ids: &[u64]) {
+    for ((_, row), &id) in rows.iter().zip(ids) {
+        if let Some(payload) = &row.payload {
+            meta.payloads.insert(id, payload.clone());
+        }
+    }
+}
+
+impl HnswIndex {
+    fn insert_numbered_rows(&self, rows: &[(u64, Row)]) -> Result<Vec<u64>, HnswError> {
+        let ids: Vec<u64> = rows.iter().map(|(id, _)| *id).collect();
+        let data = rows.iter().map(|(_, row)| row.vector.clone()).collect();
+        self.insert_batch_with(data, ids.clone(), |meta| attach_payloads(meta, rows, &ids))?;
+        Ok(ids)
+    }
+
+    fn insert_keyed_rows(&self, rows: &[(String, Row)]) -> Result<Vec<u64>, HnswError> {
+        let keys = rows.iter().map(|(key, _)| key.clone()).collect();
+        let data = rows.iter().map(|(_, row)| row.vector.clone()).collect();
+        self.insert_batch_keyed_with(data, keys, |meta, ids| attach_payloads(meta, rows, ids))
+    }
+
+    // Batches are all-or-nothing, so when one fails its rows are retried one
+    // at a time to pin the error to a line.
+    fn store_rows<T>(
+        &self,
+        rows: Vec<(T, Row)>,
+        insert: impl Fn(&[(T, Row)]) -> Result<Vec<u64>, HnswError>,
+        report: &mut JsonlImportReport,
+    ) -> Result<(), HnswError> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+        if let Ok(ids) = insert(&rows) {
+            report.imported += ids.len() as u64;
+            return Ok(());
+        }
+        for row in rows {
+            let single = std::slice::from_ref(&row);
+            match insert(single) {
+                Ok(_) => report.imported += 1,
+                Err(e) => report.errors.push(JsonlLineError {
+                    line: row.1.line,
+                    message: e.to_string(),
+                }),
+            }
+        }
+        Ok(())
+    }
+
+    fn flush_rows(
+        &self,
+        pending: &mut Pending,
+        report: &mut JsonlImportReport,
+    ) -> Result<(), HnswError> {
+        let Pending { numbered, keyed } = std::mem::take(pending);
+        self.store_rows(numbered, |rows| self.insert_numbered_rows(rows), report)?;
+        self.store_rows(keyed, |rows| self.insert_keyed_rows(rows), report)
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Integer ids are used as-is; string ids go through the key mapping.
+    // `payload_fields` present on a line are stored as a JSON object payload.
+    // Bad lines are reported and skipped rather than failing the import.
+    #[uniffi::method]
+    pub fn import_jsonl(
+        &self,
+        path: String,
+        vector_field: String,
+        id_field: String,
+        payload_fields: Vec<String>,
+    ) -> Result<JsonlImportReport, HnswError> {
+        self.check_writable()?;
+        let reader = BufReader::new(File::open(path)?);
+        let mut report = JsonlImportReport::default();
+        let mut pending = Pending::default();
+        for (i, bytes) in reader.split(b'\n').enumerate() {
+            let bytes = bytes?;
+            if bytes.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
+            let line = i as u64 + 1;
+            let parsed = serde_json::from_slice::<Value>(&bytes)
+                .map_err(|e| e.to_string())
+                .and_then(|value| {
+                    let row = Row {
+                        line,
+                        vector: parse_vector(&value, &vector_field)?,
+                        payload: parse_payload(&value, &payload_fields)?,
+                    };
+                    match field(&value, &id_field) {
+                        Some(Value::String(key)) => {
+                            pending.keyed.push((key.clone(), row));
+                            Ok(())
+                        }
+                        Some(id) => {
+                            let id = id
+                                .as_u64()
+                                .ok_or_else(|| format!("'{id_field}' is not a valid id"))?;
+                            pending.numbered.push((id, row));
+                            Ok(())
+                        }
+                        None => Err(format!("missing field '{id_field}'")),
+                    }
+                });
+            if let Err(message) = parsed {
+                report.errors.push(JsonlLineError { line, message });
+            }
+            if pending.len() >= BATCH_ROWS {
+                self.flush_rows(&mut pending, &mut report)?;
+            }
+        }
+        self.flush_rows(&mut pending, &mut report)?;
+        report.errors.sort_by_key(|e| e.line);
+        Ok(report)
+    }
+}
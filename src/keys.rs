@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+use std::sync::PoisonError;
+
+use crate::observer::Event;
+use crate::{HnswError, HnswIndex, HnswIndexInner, PointMeta, guarded, threads};
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct KeyedSearchResult {
+    pub key: String,
+    pub distance: f32,
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    #[uniffi::method]
+    pub fn insert_keyed(&self, data: Vec<f32>, key: String) -> Result<u64, HnswError> {
+        let ids = self.insert_batch_keyed(vec![data], vec![key])?;
+        Ok(ids[0])
+    }
+
+    #[uniffi::method]
+    pub fn insert_batch_keyed(
+        &self,
+        data: Vec<Vec<f32>>,
+        keys: Vec<String>,
+    ) -> Result<Vec<u64>, HnswError> {
+        self.insert_batch_keyed_with(data, keys, |_, _| {})
+    }
+
+    // Points inserted without a key are left out of the results.
+    #[uniffi::method]
+    pub fn search_keyed(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<KeyedSearchResult>, HnswError> {
+        let results = self.search(query, k, ef_search)?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(results
+            .into_iter()
+            .filter_map(|r| {
+                meta.keys.get(&r.id).map(|key| KeyedSearchResult {
+                    key: key.clone(),
+                    distance: r.distance,
+                })
+            })
+            .collect())
+    }
+
+    #[uniffi::method]
+    pub fn get_key(&self, id: u64) -> Result<Option<String>, HnswError> {
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(meta.keys.get(&id).cloned())
+    }
+
+    #[uniffi::method]
+    pub fn get_id_for_key(&self, key: String) -> Result<Option<u64>, HnswError> {
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(meta.key_ids.get(&key).copied())
+    }
+}
+
+impl HnswIndex {
+    // `insert_batch_keyed`, also recording what `file` sets on the points,
+    // given the ids they were allocated, in the same step.
+    pub(crate) fn insert_batch_keyed_with(
+        &self,
+        data: Vec<Vec<f32>>,
+        keys: Vec<String>,
+        file: impl FnOnce(&mut PointMeta, &[u64]),
+    ) -> Result<Vec<u64>, HnswError> {
+        self.check_writable()?;
+        if data.len() != keys.len() {
+            return Err(HnswError::LengthMismatch {
+                vectors: data.len() as u64,
+                ids: keys.len() as u64,
+            });
+        }
+        let data: Vec<Vec<f32>> = data
+            .into_iter()
+            .enumerate()
+            .map(|(i, vec)| self.prepare_stored(vec, i))
+            .collect::<Result<_, _>>()?;
+        let mut guard = self.lock_inner()?;
+        let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut seen = HashSet::with_capacity(keys.len());
+        for key in &keys {
+            if meta.key_ids.contains_key(key) || !seen.insert(key) {
+                return Err(HnswError::DuplicateKey(key.clone()));
+            }
+        }
+        let ids: Vec<u64> = keys.iter().map(|_| meta.allocate_id(&known)).collect();
+        self.ensure_capacity_locked(&mut guard, &known, &mut meta, &ids)?;
+        let pairs: Vec<(&Vec<f32>, usize)> =
+            data.iter().zip(ids.iter().map(|&id| id as usize)).collect();
+        guarded("insert_batch_keyed", || {
+            threads::install(|| match &*guard {
+                HnswIndexInner::L2(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::Cosine(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::Dot(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::L1(inner) => inner.hnsw.parallel_insert(&pairs),
+            })
+        })?;
+        known.extend(&ids);
+        self.sketch_inserted(&pairs);
+        for (&id, key) in ids.iter().zip(keys) {
+            meta.key_ids.insert(key.clone(), id);
+            meta.keys.insert(id, key);
+        }
+        file(&mut meta, &ids);
+        meta.touch(&ids);
+        self.notify(|| Event::Insert(ids.clone()));
+        Ok(ids)
+    }
+}
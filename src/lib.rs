@@ -1,19 +1,151 @@
-use std::collections::HashSet;
+// Built with --no-default-features the uniffi annotations expand to nothing
+// and this is a plain Rust library.
+#[cfg(not(feature = "ffi"))]
+extern crate uniffi_noop as uniffi;
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::Read;
 use std::mem::ManuallyDrop;
-use std::path::Path;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
 use std::ptr::NonNull;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant, SystemTime};
 
 use hnsw_rs::api::AnnT;
 use hnsw_rs::hnsw::{Hnsw, Neighbour as HnswNeighbour};
-use hnsw_rs::hnswio::HnswIo;
+use hnsw_rs::hnswio::{HnswIo, ReloadOptions};
 use hnsw_rs::prelude::*;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use attrs::{Attrs, attrs_heap_bytes};
+use binary::BinarySketches;
+use cache::QueryCache;
+use lock::DumpLock;
+use observer::{Event, Notifier};
+use points::PointMap;
+use transform::QueryTransform;
+
+mod aggregate;
+mod arrow;
+mod attrs;
+mod background;
+mod binary;
+mod bounded;
+mod bundle;
+mod busy;
+mod cache;
+#[cfg(feature = "capi")]
+mod capi;
+mod cluster;
+mod collection;
+mod cursor;
+mod documents;
+mod duplicates;
+mod eval;
+mod export;
+mod faiss;
+mod flat;
+mod format;
+mod fusion;
+mod graph;
+mod grouped;
+mod health;
+mod hidden;
+mod hnswlib;
+mod hybrid;
+mod integrity;
+mod jsonl;
+mod keys;
+mod lock;
+mod logging;
+mod maintain;
+mod memory;
+mod migrate;
+mod multi;
+mod npy;
+mod observer;
+mod payload;
+mod points;
+mod pq;
+mod pressure;
+mod privacy;
+mod quantization;
+mod radius;
+mod reduce;
+mod residency;
+#[cfg(feature = "server")]
+mod server;
+mod session;
+mod signpost;
+mod simd;
+mod stats;
+mod sync;
+mod text;
+mod threads;
+mod transform;
+mod ttl;
+mod typeahead;
+mod typed;
+mod usearch;
+mod view;
+mod weights;
+mod wipe;
+
+pub use attrs::AttrValue;
+pub use background::{load_bundle_async, load_index_async};
+pub use bounded::{BoundedSearchResults, SearchLimits};
+pub use bundle::set_temp_directory;
+pub use cluster::ClusterAssignment;
+pub use collection::HnswCollection;
+pub use cursor::SearchCursor;
+pub use documents::{DocumentAggregation, DocumentSearchResult};
+pub use duplicates::DuplicatePair;
+pub use eval::RecallReport;
+pub use export::ExportFormat;
+pub use flat::FlatSearchResults;
+pub use format::{dump_format_version, migrate_dump};
+pub use fusion::{FusionStrategy, fuse_results};
+pub use graph::GraphFormat;
+pub use grouped::GroupSearchResult;
+pub use health::HealthReport;
+pub use hybrid::HybridSearcher;
+pub use integrity::IntegrityReport;
+pub use jsonl::{JsonlImportReport, JsonlLineError};
+pub use keys::KeyedSearchResult;
+pub use logging::{LogEvent, LogLevel, LogListener, clear_log_callback, set_log_callback};
+pub use maintain::MaintenanceReport;
+pub use migrate::MigrationEmbedder;
+pub use multi::{MultiIndexSearcher, MultiSearchResult};
+pub use observer::IndexObserver;
+pub use payload::SearchOptions;
+pub use pq::HnswPqIndex;
+pub use pressure::{MemoryPressureLevel, notify_memory_pressure};
+pub use quantization::HnswSq8Index;
+pub use reduce::DimReducer;
+pub use residency::ResidencyStats;
+#[cfg(feature = "server")]
+pub use server::QueryServer;
+pub use session::InsertSession;
+pub use signpost::{SignpostListener, clear_signpost_listener, set_signpost_listener};
+pub use stats::{SearchStats, SearchWithStats};
+pub use sync::ChangeApplyReport;
+pub use text::Embedder;
+pub use threads::{ThreadQos, get_num_threads, set_num_threads, set_thread_qos};
+pub use typeahead::QuerySession;
+pub use typed::{HnswF64Index, HnswI32Index, HnswU16Index};
+pub use view::HnswIndexView;
+pub use wipe::destroy_dump;
 
 #[derive(Debug, thiserror::Error, uniffi::Error)]
 #[uniffi(flat_error)]
 pub enum HnswError {
     #[error("IO error: {0}")]
     IoError(String),
+    // No longer returned: a lock poisoned by a panic is recovered instead.
     #[error("Lock acquisition error")]
     LockError,
     #[error("Index is empty")]
@@ -29,6 +161,44 @@ pub enum HnswError {
     ReloadError(String),
     #[error("Dump error: {0}")]
     DumpError(String),
+    #[error("Index not found: {0}")]
+    IndexNotFound(String),
+    #[error("Index already exists: {0}")]
+    IndexAlreadyExists(String),
+    #[error("Invalid index name: {0}")]
+    InvalidName(String),
+    #[error("Manifest error: {0}")]
+    ManifestError(String),
+    #[error("Operation cancelled")]
+    Cancelled,
+    #[error("Thread pool error: {0}")]
+    ThreadPoolError(String),
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+    #[error("Index has not been trained")]
+    NotTrained,
+    #[error("Zero vector cannot be normalized")]
+    ZeroVector,
+    #[error("Invalid vector at index {index}: {reason}")]
+    InvalidVector { index: u64, reason: String },
+    #[error("Duplicate id: {0}")]
+    DuplicateId(u64),
+    #[error("Duplicate key: {0}")]
+    DuplicateKey(String),
+    #[error("Index is read-only")]
+    ReadOnly,
+    #[error("Length mismatch: {vectors} vectors for {ids} ids")]
+    LengthMismatch { vectors: u64, ids: u64 },
+    #[error("Capacity exceeded: index holds at most {max}, attempted {attempted}")]
+    CapacityExceeded { max: u64, attempted: u64 },
+    #[error("Corrupted file: {0}")]
+    Corrupted(String),
+    #[error("Internal error: {0}")]
+    Internal(String),
+    #[error("Unsupported format version {found}; this build reads up to {supported}")]
+    UnsupportedVersion { found: u32, supported: u32 },
+    #[error("Index is busy")]
+    Busy,
 }
 
 impl From<std::io::Error> for HnswError {
@@ -37,21 +207,62 @@ impl From<std::io::Error> for HnswError {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, uniffi::Enum)]
 pub enum DistanceType {
     L2,
     Cosine,
     L1,
+    // Over unit vectors, which inserts and queries are checked for unless
+    // normalize_vectors makes them so.
     Dot,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum ElementType {
+    F32,
+    F64,
+    U16,
+    I32,
+    U8,
+}
+
+impl ElementType {
+    fn size_bytes(self) -> u64 {
+        match self {
+            ElementType::F32 | ElementType::I32 => 4,
+            ElementType::F64 => 8,
+            ElementType::U16 => 2,
+            ElementType::U8 => 1,
+        }
+    }
+}
+
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct SearchResult {
     pub id: u64,
     pub distance: f32,
+    // Only filled in by `search_with_options`, on request.
+    #[uniffi(default = None)]
+    pub rank: Option<u32>,
+    #[uniffi(default = None)]
+    pub score: Option<f32>,
+    #[uniffi(default = None)]
+    pub payload: Option<Vec<u8>>,
+}
+
+impl SearchResult {
+    pub(crate) fn new(id: u64, distance: f32) -> Self {
+        SearchResult {
+            id,
+            distance,
+            rank: None,
+            score: None,
+            payload: None,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, uniffi::Record)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, uniffi::Record)]
 pub struct HnswIndexConfig {
     pub max_nb_connection: u32,
     pub max_elements: u64,
@@ -59,14 +270,264 @@ pub struct HnswIndexConfig {
     pub ef_construction: u32,
     pub dimension: u32,
     pub distance: DistanceType,
+    #[uniffi(default = false)]
+    #[serde(default)]
+    pub normalize_vectors: bool,
+    // Multiplies hnsw_rs's level scale, 1/ln(max_nb_connection). Lower values
+    // give fewer upper layers and less memory at some cost in recall. Clamped
+    // to 0.2..=1; None leaves it at 1. It takes effect when a graph is built,
+    // and hnsw_rs logs a line to stdout each time it is applied.
+    #[uniffi(default = None)]
+    #[serde(default)]
+    pub level_scale: Option<f64>,
+}
+
+#[uniffi::export(callback_interface)]
+pub trait ProgressListener: Send + Sync {
+    fn on_progress(&self, done: u64, total: u64);
+}
+
+#[derive(Debug, Default, uniffi::Object)]
+pub struct CancellationToken {
+    cancelled: AtomicBool,
+}
+
+impl CancellationToken {
+    fn check(&self) -> Result<(), HnswError> {
+        if self.is_cancelled() {
+            return Err(HnswError::Cancelled);
+        }
+        Ok(())
+    }
+}
+
+#[uniffi::export]
+impl CancellationToken {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[uniffi::method]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    #[uniffi::method]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    #[uniffi::method]
+    pub fn reset(&self) {
+        self.cancelled.store(false, Ordering::SeqCst);
+    }
 }
 
 impl From<HnswNeighbour> for SearchResult {
     fn from(n: HnswNeighbour) -> Self {
-        SearchResult {
-            id: n.d_id as u64,
-            distance: n.distance,
+        SearchResult::new(n.d_id as u64, n.distance)
+    }
+}
+
+// Saved with bincode, so changing the fields means a new layout version in
+// format.rs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct PointMeta {
+    namespaces: HashMap<u64, u32>,
+    keys: HashMap<u64, String>,
+    next_key_id: u64,
+    payloads: HashMap<u64, Vec<u8>>,
+    // Chunk point id -> document id.
+    documents: HashMap<u64, u64>,
+    attrs: HashMap<u64, Attrs>,
+    timestamps: HashMap<u64, i64>,
+    // Kept in the graph for connectivity but left out of results.
+    hidden: HashSet<u64>,
+    // Sync versions: `clock` counts changes, `versions` holds the change that
+    // last touched each point and `removed` the change that deleted it.
+    clock: u64,
+    versions: HashMap<u64, u64>,
+    removed: HashMap<u64, u64>,
+    // Per-dimension distance weights. Stored vectors are scaled by their
+    // square roots, which weights squared differences and products by them.
+    weights: Option<Vec<f32>>,
+    build: BuildOptions,
+    // The level scale the graph was built with, for loads whose config
+    // doesn't give one.
+    level_scale: Option<f64>,
+    // Noise added to inserted vectors; see set_privacy_epsilon.
+    privacy_epsilon: Option<f64>,
+    // What the save that wrote this sidecar wrote alongside it.
+    parts: Option<DumpParts>,
+    // Reverse of `keys`, rebuilt on load.
+    #[serde(skip)]
+    key_ids: HashMap<String, u64>,
+    // Removed ids whose points are still in the graph until the next purge.
+    // Saved with the graph that holds them and left out of the id set on
+    // load, so a save doesn't have to purge first.
+    tombstones: HashSet<u64>,
+}
+
+// hnsw_rs construction heuristics. hnsw_rs doesn't dump them, so they are
+// kept with the metadata and set again on every graph the index creates.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+struct BuildOptions {
+    extend_candidates: bool,
+    keep_pruned: bool,
+}
+
+// Sizes of the graph and data files a save wrote, and whether it wrote a
+// reducer. The metadata is renamed into place last, so files left over from
+// another save, or a save cut short, show up as a mismatch on load.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct DumpParts {
+    graph_bytes: u64,
+    data_bytes: u64,
+    reducer: bool,
+}
+
+impl PointMeta {
+    fn path(directory: &str, basename: &str) -> PathBuf {
+        Path::new(directory).join(format!("{basename}.hnsw.meta"))
+    }
+
+    fn load(directory: &str, basename: &str) -> Result<Self, HnswError> {
+        let path = Self::path(directory, basename);
+        if !path.exists() {
+            return Ok(PointMeta::default());
+        }
+        let bytes = fs::read(path)?;
+        let (version, body) = format::decode_meta(&bytes)?;
+        let mut meta = format::deserialize_meta(version, body)?;
+        meta.key_ids = meta
+            .keys
+            .iter()
+            .map(|(&id, key)| (key.clone(), id))
+            .collect();
+        Ok(meta)
+    }
+
+    fn save(&self, directory: &str, basename: &str) -> Result<(), HnswError> {
+        let body = bincode::serialize(self).map_err(|e| HnswError::DumpError(e.to_string()))?;
+        fs::write(Self::path(directory, basename), format::encode_meta(body))?;
+        Ok(())
+    }
+
+    // Dumps saved before the parts were recorded can't be checked and load
+    // as they are.
+    fn check_parts(&self, directory: &str, basename: &str) -> Result<(), HnswError> {
+        let Some(parts) = self.parts else {
+            return Ok(());
+        };
+        for (ext, expected) in [
+            ("hnsw.graph", parts.graph_bytes),
+            ("hnsw.data", parts.data_bytes),
+        ] {
+            let path = Path::new(directory).join(format!("{basename}.{ext}"));
+            if fs::metadata(&path).map(|m| m.len()).ok() != Some(expected) {
+                return Err(HnswError::Corrupted(format!(
+                    "{} is missing or from another save",
+                    path.display()
+                )));
+            }
+        }
+        let reducer = DimReducer::sidecar_path(directory, basename);
+        if reducer.exists() != parts.reducer {
+            return Err(HnswError::Corrupted(format!(
+                "{} is {} the saved metadata",
+                reducer.display(),
+                if parts.reducer {
+                    "missing from"
+                } else {
+                    "not part of"
+                }
+            )));
+        }
+        Ok(())
+    }
+
+    fn without(&self, deleted_ids: &[u64]) -> Self {
+        let deleted: HashSet<u64> = deleted_ids.iter().copied().collect();
+        let mut meta = self.clone();
+        meta.namespaces.retain(|id, _| !deleted.contains(id));
+        meta.keys.retain(|id, _| !deleted.contains(id));
+        meta.payloads.retain(|id, _| !deleted.contains(id));
+        meta.documents.retain(|id, _| !deleted.contains(id));
+        meta.attrs.retain(|id, _| !deleted.contains(id));
+        meta.timestamps.retain(|id, _| !deleted.contains(id));
+        meta.hidden.retain(|id| !deleted.contains(id));
+        meta.key_ids.retain(|_, id| !deleted.contains(id));
+        for &id in deleted_ids {
+            if meta.versions.remove(&id).is_some() {
+                meta.clock += 1;
+                meta.removed.insert(id, meta.clock);
+            }
+        }
+        meta
+    }
+
+    fn touch(&mut self, ids: &[u64]) {
+        for &id in ids {
+            self.clock += 1;
+            self.versions.insert(id, self.clock);
+            self.removed.remove(&id);
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.namespaces.shrink_to_fit();
+        self.keys.shrink_to_fit();
+        self.payloads.shrink_to_fit();
+        self.documents.shrink_to_fit();
+        self.attrs.shrink_to_fit();
+        self.timestamps.shrink_to_fit();
+        self.hidden.shrink_to_fit();
+        self.tombstones.shrink_to_fit();
+        self.versions.shrink_to_fit();
+        self.removed.shrink_to_fit();
+        self.key_ids.shrink_to_fit();
+    }
+
+    // Approximate: one slot per map entry (key, value and a control byte) plus
+    // the bytes owned by keys, which are stored twice, and payloads.
+    fn heap_bytes(&self) -> u64 {
+        let slots = self.namespaces.capacity() * 13
+            + self.keys.capacity() * 33
+            + self.payloads.capacity() * 33
+            + self.documents.capacity() * 17
+            + self.attrs.capacity() * 57
+            + self.timestamps.capacity() * 17
+            + self.hidden.capacity() * 9
+            + self.tombstones.capacity() * 9
+            + self.versions.capacity() * 17
+            + self.removed.capacity() * 17
+            + self.key_ids.capacity() * 33;
+        let owned: usize = self.keys.values().map(|k| 2 * k.len()).sum::<usize>()
+            + self.payloads.values().map(Vec::len).sum::<usize>()
+            + self.attrs.values().map(attrs_heap_bytes).sum::<usize>()
+            + self.weights.as_ref().map_or(0, |w| w.capacity() * 4);
+        (slots + owned) as u64
+    }
+
+    fn visible(&self, id: u64) -> bool {
+        !self.hidden.contains(&id) && !self.tombstones.contains(&id)
+    }
+
+    // True when searches can skip the visibility filter.
+    fn all_visible(&self) -> bool {
+        self.hidden.is_empty() && self.tombstones.is_empty()
+    }
+
+    // Internal ids for keyed points are handed out sequentially, skipping any
+    // id the caller already used directly.
+    fn allocate_id(&mut self, known: &HashSet<u64>) -> u64 {
+        while known.contains(&self.next_key_id) || self.tombstones.contains(&self.next_key_id) {
+            self.next_key_id += 1;
         }
+        let id = self.next_key_id;
+        self.next_key_id += 1;
+        id
     }
 }
 
@@ -146,61 +607,282 @@ impl Drop for HnswInnerL1 {
 unsafe impl Send for HnswInnerL1 {}
 unsafe impl Sync for HnswInnerL1 {}
 
+pub(crate) fn check_finite(v: &[f32], index: usize) -> Result<(), HnswError> {
+    match v.iter().position(|x| !x.is_finite()) {
+        Some(dim) => Err(HnswError::InvalidVector {
+            index: index as u64,
+            reason: format!(
+                "{} at dimension {dim}",
+                if v[dim].is_nan() { "NaN" } else { "Infinity" }
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
+// Checks what the metric needs beyond finite values. A zero vector has no
+// direction, so Cosine divides by zero on it and Dot ranks it level with
+// everything; either way its results would be meaningless.
+pub(crate) fn check_metric(
+    v: &[f32],
+    distance: DistanceType,
+    index: usize,
+) -> Result<(), HnswError> {
+    match distance {
+        DistanceType::Cosine | DistanceType::Dot if v.iter().all(|&x| x == 0.0) => {
+            Err(HnswError::InvalidVector {
+                index: index as u64,
+                reason: format!("zero vector has no {distance:?} distance"),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+// hnsw_rs clamps the factor to this range itself, but NaN gets past its
+// clamp, and it prints a complaint to stdout for anything outside it on top of
+// the line it prints on every call.
+fn clamp_level_scale(scale: f64) -> f64 {
+    if scale.is_finite() {
+        scale.clamp(0.2, 1.0)
+    } else {
+        1.0
+    }
+}
+
+// How far from 1 a Dot vector's norm may be. Embedding models that emit
+// unit vectors are off by rounding, far less than this.
+const UNIT_NORM_TOLERANCE: f32 = 1e-3;
+
+// hnsw_rs's dot distance asserts that no product exceeds 1, so a Dot index
+// that doesn't normalize needs unit vectors. Ones within rounding of unit
+// are normalized to keep products at the bound; anything else is refused
+// here rather than panicking mid-search.
+fn check_unit(v: &mut [f32], index: usize) -> Result<(), HnswError> {
+    let norm = simd::dot(v, v).sqrt();
+    if (norm - 1.0).abs() > UNIT_NORM_TOLERANCE {
+        return Err(HnswError::InvalidVector {
+            index: index as u64,
+            reason: format!(
+                "Dot needs unit vectors, got norm {norm}; normalize them or set normalize_vectors"
+            ),
+        });
+    }
+    normalize_vector(v)
+}
+
+pub(crate) fn normalize_vector(v: &mut [f32]) -> Result<(), HnswError> {
+    let norm = simd::dot(v, v).sqrt();
+    if norm == 0.0 {
+        return Err(HnswError::ZeroVector);
+    }
+    v.iter_mut().for_each(|x| *x /= norm);
+    Ok(())
+}
+
 fn compact_hnsw<D>(
     hnsw: &Hnsw<'static, f32, D>,
     config: HnswIndexConfig,
     deleted_ids: &[u64],
+    options: BuildOptions,
     make_dist: impl FnOnce() -> D,
 ) -> Result<Hnsw<'static, f32, D>, HnswError>
 where
     D: Distance<f32> + Send + Sync,
 {
     if hnsw.get_nb_point() == 0 {
-        return Ok(Hnsw::new(
+        let mut new_hnsw = Hnsw::new(
             config.max_nb_connection as usize,
             config.max_elements as usize,
             config.max_layer as usize,
             config.ef_construction as usize,
             make_dist(),
-        ));
+        );
+        new_hnsw.set_extend_candidates(options.extend_candidates);
+        new_hnsw.set_keeping_pruned(options.keep_pruned);
+        if let Some(scale) = config.level_scale {
+            new_hnsw.modify_level_scale(clamp_level_scale(scale));
+        }
+        return Ok(new_hnsw);
     }
 
     let mut deleted: HashSet<usize> = HashSet::with_capacity(deleted_ids.len());
     for &id in deleted_ids {
-        let id_usize = usize::try_from(id)
-            .map_err(|_| HnswError::IoError("Deleted id exceeds usize range".to_string()))?;
+        let id_usize = usize::try_from(id).map_err(|_| {
+            HnswError::InvalidArgument("Deleted id exceeds usize range".to_string())
+        })?;
         deleted.insert(id_usize);
     }
 
     let mut seen: HashSet<usize> = HashSet::new();
-    let mut kept_count: u64 = 0;
-    for point in hnsw.get_point_indexation().into_iter() {
-        let id = point.get_origin_id();
-        if deleted.contains(&id) || !seen.insert(id) {
-            continue;
-        }
-        kept_count += 1;
-    }
+    let kept: Vec<_> = graph_points(hnsw)
+        .filter(|point| {
+            let id = point.get_origin_id();
+            !deleted.contains(&id) && seen.insert(id)
+        })
+        .collect();
 
-    let max_elements = std::cmp::max(config.max_elements, kept_count);
-    let new_hnsw = Hnsw::new(
+    let max_elements = std::cmp::max(config.max_elements, kept.len() as u64);
+    let mut new_hnsw = Hnsw::new(
         config.max_nb_connection as usize,
         max_elements as usize,
         config.max_layer as usize,
         config.ef_construction as usize,
         make_dist(),
     );
+    new_hnsw.set_extend_candidates(options.extend_candidates);
+    new_hnsw.set_keeping_pruned(options.keep_pruned);
+    if let Some(scale) = config.level_scale {
+        new_hnsw.modify_level_scale(clamp_level_scale(scale));
+    }
 
-    let mut seen: HashSet<usize> = HashSet::new();
-    for point in hnsw.get_point_indexation().into_iter() {
-        let id = point.get_origin_id();
-        if deleted.contains(&id) || !seen.insert(id) {
-            continue;
+    let pairs: Vec<(&[f32], usize)> = kept
+        .iter()
+        .map(|point| (point.get_v(), point.get_origin_id()))
+        .collect();
+    guarded("compact", || {
+        threads::install(|| new_hnsw.parallel_insert_slice(&pairs))
+    })?;
+
+    Ok(new_hnsw)
+}
+
+fn insert_pairs<D>(
+    hnsw: &Hnsw<'static, f32, D>,
+    pairs: &[(&Vec<f32>, usize)],
+    listener: Option<&dyn ProgressListener>,
+    token: Option<&CancellationToken>,
+) -> Result<(), HnswError>
+where
+    D: Distance<f32> + Send + Sync,
+{
+    let total = pairs.len() as u64;
+    let step = std::cmp::max(total / 100, 1);
+    let done = AtomicU64::new(0);
+    if let Some(listener) = listener {
+        listener.on_progress(0, total);
+    }
+    pairs.par_iter().for_each(|&(data, id)| {
+        if token.is_some_and(|t| t.is_cancelled()) {
+            return;
         }
-        new_hnsw.insert((point.get_v(), id));
+        hnsw.insert((data, id));
+        let count = done.fetch_add(1, Ordering::Relaxed) + 1;
+        if let Some(listener) = listener
+            && (count.is_multiple_of(step) || count == total)
+        {
+            listener.on_progress(count, total);
+        }
+    });
+    match token {
+        Some(token) => token.check(),
+        None => Ok(()),
     }
+}
 
-    Ok(new_hnsw)
+const PREFETCH_CHUNK_BYTES: usize = 4 * 1024 * 1024;
+
+// hnsw_rs reads the dump through its own readers, so progress is reported
+// while streaming the files into the page cache ahead of the actual reload.
+fn prefetch_dump(
+    directory: &str,
+    basename: &str,
+    listener: &dyn ProgressListener,
+    token: Option<&CancellationToken>,
+) -> Result<(), HnswError> {
+    let paths: Vec<PathBuf> = ["hnsw.graph", "hnsw.data", "hnsw.meta"]
+        .iter()
+        .map(|ext| Path::new(directory).join(format!("{basename}.{ext}")))
+        .filter(|path| path.exists())
+        .collect();
+    let mut total = 0u64;
+    for path in &paths {
+        total += fs::metadata(path)?.len();
+    }
+    let mut done = 0u64;
+    let mut buf = vec![0u8; PREFETCH_CHUNK_BYTES];
+    listener.on_progress(0, total);
+    for path in &paths {
+        let mut file = fs::File::open(path)?;
+        loop {
+            if let Some(token) = token {
+                token.check()?;
+            }
+            let n = file.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            done += n as u64;
+            listener.on_progress(done.min(total), total);
+        }
+    }
+    Ok(())
+}
+
+// hnsw_rs's point iterator unwraps the entry point, so on an empty graph it
+// panics instead of yielding nothing.
+pub(crate) fn graph_points<'a, T, D>(
+    hnsw: &'a Hnsw<'static, T, D>,
+) -> impl Iterator<Item = Arc<Point<'static, T>>> + 'a
+where
+    T: Clone + Send + Sync,
+    D: Distance<T> + Send + Sync,
+{
+    (hnsw.get_nb_point() > 0)
+        .then(|| hnsw.get_point_indexation().into_iter())
+        .into_iter()
+        .flatten()
+}
+
+fn origin_ids<D>(hnsw: &Hnsw<'static, f32, D>) -> HashSet<u64>
+where
+    D: Distance<f32> + Send + Sync,
+{
+    graph_points(hnsw)
+        .map(|point| point.get_origin_id() as u64)
+        .collect()
+}
+
+fn stored_points<D>(hnsw: &Hnsw<'static, f32, D>) -> Vec<(u64, Vec<f32>)>
+where
+    D: Distance<f32> + Send + Sync,
+{
+    graph_points(hnsw)
+        .map(|point| (point.get_origin_id() as u64, point.get_v().to_vec()))
+        .collect()
+}
+
+fn stored_vectors<D>(hnsw: &Hnsw<'static, f32, D>, ids: &HashSet<u64>) -> HashMap<u64, Vec<f32>>
+where
+    D: Distance<f32> + Send + Sync,
+{
+    graph_points(hnsw)
+        .filter(|point| ids.contains(&(point.get_origin_id() as u64)))
+        .map(|point| (point.get_origin_id() as u64, point.get_v().to_vec()))
+        .collect()
+}
+
+// hnsw_rs asserts on some inputs, and a panic unwinding into Swift aborts
+// the app. Calls into it that can panic go through here instead.
+fn guarded<T>(operation: &str, f: impl FnOnce() -> T) -> Result<T, HnswError> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        HnswError::Internal(format!("{operation} panicked: {message}"))
+    })
+}
+
+fn check_new_ids(known: &HashSet<u64>, ids: &[u64]) -> Result<(), HnswError> {
+    let mut seen = HashSet::with_capacity(ids.len());
+    for &id in ids {
+        if known.contains(&id) || !seen.insert(id) {
+            return Err(HnswError::DuplicateId(id));
+        }
+    }
+    Ok(())
 }
 
 enum HnswIndexInner {
@@ -211,8 +893,12 @@ enum HnswIndexInner {
 }
 
 impl HnswIndexInner {
+    // Builds are not reproducible: hnsw_rs seeds its level generator from
+    // entropy with no way to pass a seed, and parallel inserts link points in
+    // whatever order the threads reach them. Snapshot tests should compare
+    // search results with some tolerance rather than graph files.
     fn new(config: HnswIndexConfig) -> Self {
-        match config.distance {
+        let mut inner = match config.distance {
             DistanceType::L1 => HnswIndexInner::L1(HnswInnerL1 {
                 hnsw: ManuallyDrop::new(Hnsw::new(
                     config.max_nb_connection as usize,
@@ -253,18 +939,22 @@ impl HnswIndexInner {
                 )),
                 io_ptr: None,
             }),
-        }
+        };
+        inner.set_level_scale(config.level_scale);
+        inner
     }
 
     fn load(
         directory: String,
         basename: String,
         distance: DistanceType,
+        mmap: bool,
     ) -> Result<Self, HnswError> {
         let dir_path = Path::new(&directory);
         match distance {
             DistanceType::L1 => {
-                let io = Box::new(HnswIo::new(dir_path, &basename));
+                let options = ReloadOptions::new(mmap);
+                let io = Box::new(HnswIo::new_with_options(dir_path, &basename, options));
                 let io_ptr = Box::into_raw(io);
                 let hnsw: Hnsw<'static, f32, DistL1> = unsafe {
                     (*io_ptr)
@@ -277,7 +967,8 @@ impl HnswIndexInner {
                 }))
             }
             DistanceType::L2 => {
-                let io = Box::new(HnswIo::new(dir_path, &basename));
+                let options = ReloadOptions::new(mmap);
+                let io = Box::new(HnswIo::new_with_options(dir_path, &basename, options));
                 let io_ptr = Box::into_raw(io);
                 let hnsw: Hnsw<'static, f32, DistL2> = unsafe {
                     (*io_ptr)
@@ -290,7 +981,8 @@ impl HnswIndexInner {
                 }))
             }
             DistanceType::Cosine => {
-                let io = Box::new(HnswIo::new(dir_path, &basename));
+                let options = ReloadOptions::new(mmap);
+                let io = Box::new(HnswIo::new_with_options(dir_path, &basename, options));
                 let io_ptr = Box::into_raw(io);
                 let hnsw: Hnsw<'static, f32, DistCosine> = unsafe {
                     (*io_ptr)
@@ -303,7 +995,8 @@ impl HnswIndexInner {
                 }))
             }
             DistanceType::Dot => {
-                let io = Box::new(HnswIo::new(dir_path, &basename));
+                let options = ReloadOptions::new(mmap);
+                let io = Box::new(HnswIo::new_with_options(dir_path, &basename, options));
                 let io_ptr = Box::into_raw(io);
                 let hnsw: Hnsw<'static, f32, DistDot> = unsafe {
                     (*io_ptr)
@@ -317,178 +1010,1280 @@ impl HnswIndexInner {
             }
         }
     }
+
+    fn compacted(
+        &self,
+        config: HnswIndexConfig,
+        deleted_ids: &[u64],
+        options: BuildOptions,
+    ) -> Result<HnswIndexInner, HnswError> {
+        Ok(match self {
+            HnswIndexInner::L2(existing) => {
+                let hnsw =
+                    compact_hnsw(&existing.hnsw, config, deleted_ids, options, || DistL2 {})?;
+                HnswIndexInner::L2(HnswInnerL2 {
+                    hnsw: ManuallyDrop::new(hnsw),
+                    io_ptr: None,
+                })
+            }
+            HnswIndexInner::Cosine(existing) => {
+                let hnsw = compact_hnsw(&existing.hnsw, config, deleted_ids, options, || {
+                    DistCosine {}
+                })?;
+                HnswIndexInner::Cosine(HnswInnerCosine {
+                    hnsw: ManuallyDrop::new(hnsw),
+                    io_ptr: None,
+                })
+            }
+            HnswIndexInner::Dot(existing) => {
+                let hnsw =
+                    compact_hnsw(&existing.hnsw, config, deleted_ids, options, || DistDot {})?;
+                HnswIndexInner::Dot(HnswInnerDot {
+                    hnsw: ManuallyDrop::new(hnsw),
+                    io_ptr: None,
+                })
+            }
+            HnswIndexInner::L1(existing) => {
+                let hnsw =
+                    compact_hnsw(&existing.hnsw, config, deleted_ids, options, || DistL1 {})?;
+                HnswIndexInner::L1(HnswInnerL1 {
+                    hnsw: ManuallyDrop::new(hnsw),
+                    io_ptr: None,
+                })
+            }
+        })
+    }
+
+    fn points(&self) -> Vec<(u64, Vec<f32>)> {
+        match self {
+            HnswIndexInner::L2(inner) => stored_points(&inner.hnsw),
+            HnswIndexInner::Cosine(inner) => stored_points(&inner.hnsw),
+            HnswIndexInner::Dot(inner) => stored_points(&inner.hnsw),
+            HnswIndexInner::L1(inner) => stored_points(&inner.hnsw),
+        }
+    }
+
+    // Only the vectors of `ids`, without copying the rest like points() does.
+    fn vectors(&self, ids: &HashSet<u64>) -> HashMap<u64, Vec<f32>> {
+        match self {
+            HnswIndexInner::L2(inner) => stored_vectors(&inner.hnsw, ids),
+            HnswIndexInner::Cosine(inner) => stored_vectors(&inner.hnsw, ids),
+            HnswIndexInner::Dot(inner) => stored_vectors(&inner.hnsw, ids),
+            HnswIndexInner::L1(inner) => stored_vectors(&inner.hnsw, ids),
+        }
+    }
+
+    fn set_searching_mode(&mut self, enabled: bool) {
+        match self {
+            HnswIndexInner::L2(inner) => inner.hnsw.set_searching_mode(enabled),
+            HnswIndexInner::Cosine(inner) => inner.hnsw.set_searching_mode(enabled),
+            HnswIndexInner::Dot(inner) => inner.hnsw.set_searching_mode(enabled),
+            HnswIndexInner::L1(inner) => inner.hnsw.set_searching_mode(enabled),
+        }
+    }
+
+    // Only for a graph that was just created: hnsw_rs multiplies the current
+    // scale, and a loaded graph already has the one it was dumped with.
+    fn set_level_scale(&mut self, scale: Option<f64>) {
+        let Some(scale) = scale.map(clamp_level_scale) else {
+            return;
+        };
+        match self {
+            HnswIndexInner::L2(inner) => inner.hnsw.modify_level_scale(scale),
+            HnswIndexInner::Cosine(inner) => inner.hnsw.modify_level_scale(scale),
+            HnswIndexInner::Dot(inner) => inner.hnsw.modify_level_scale(scale),
+            HnswIndexInner::L1(inner) => inner.hnsw.modify_level_scale(scale),
+        }
+    }
+
+    fn set_build_options(&mut self, options: BuildOptions) {
+        match self {
+            HnswIndexInner::L2(inner) => {
+                inner.hnsw.set_extend_candidates(options.extend_candidates);
+                inner.hnsw.set_keeping_pruned(options.keep_pruned);
+            }
+            HnswIndexInner::Cosine(inner) => {
+                inner.hnsw.set_extend_candidates(options.extend_candidates);
+                inner.hnsw.set_keeping_pruned(options.keep_pruned);
+            }
+            HnswIndexInner::Dot(inner) => {
+                inner.hnsw.set_extend_candidates(options.extend_candidates);
+                inner.hnsw.set_keeping_pruned(options.keep_pruned);
+            }
+            HnswIndexInner::L1(inner) => {
+                inner.hnsw.set_extend_candidates(options.extend_candidates);
+                inner.hnsw.set_keeping_pruned(options.keep_pruned);
+            }
+        }
+    }
+
+    fn ids(&self) -> HashSet<u64> {
+        match self {
+            HnswIndexInner::L2(inner) => origin_ids(&inner.hnsw),
+            HnswIndexInner::Cosine(inner) => origin_ids(&inner.hnsw),
+            HnswIndexInner::Dot(inner) => origin_ids(&inner.hnsw),
+            HnswIndexInner::L1(inner) => origin_ids(&inner.hnsw),
+        }
+    }
+
+    // Tombstones included.
+    fn nb_point(&self) -> usize {
+        match self {
+            HnswIndexInner::L2(inner) => inner.hnsw.get_nb_point(),
+            HnswIndexInner::Cosine(inner) => inner.hnsw.get_nb_point(),
+            HnswIndexInner::Dot(inner) => inner.hnsw.get_nb_point(),
+            HnswIndexInner::L1(inner) => inner.hnsw.get_nb_point(),
+        }
+    }
+}
+
+// Where a loaded index came from, and the dump's modification time at that
+// point, so `reload_if_changed` can spot a newer save by another process.
+struct DumpSource {
+    directory: String,
+    basename: String,
+    modified: SystemTime,
+}
+
+// file_dump may write under another name than it was given, e.g. when the
+// graph's data is mapped from the file it would overwrite, and returns the
+// name it used. This moves those files to `basename`.
+pub(crate) fn rename_dump(directory: &str, dumped: &str, basename: &str) -> Result<(), HnswError> {
+    let path = Path::new(directory);
+    for ext in ["hnsw.graph", "hnsw.data"] {
+        fs::rename(
+            path.join(format!("{dumped}.{ext}")),
+            path.join(format!("{basename}.{ext}")),
+        )?;
+    }
+    Ok(())
+}
+
+struct LazyLoad {
+    directory: String,
+    basename: String,
+    mmap: bool,
+}
+
+fn dump_modified(directory: &str, basename: &str) -> Result<SystemTime, HnswError> {
+    let mut latest = SystemTime::UNIX_EPOCH;
+    for ext in ["hnsw.graph", "hnsw.data"] {
+        let path = Path::new(directory).join(format!("{basename}.{ext}"));
+        latest = latest.max(fs::metadata(path)?.modified()?);
+    }
+    Ok(latest)
 }
 
 #[derive(uniffi::Object)]
 pub struct HnswIndex {
     inner: Mutex<HnswIndexInner>,
+    // External ids present in the graph; guarded after `inner`.
+    ids: Mutex<HashSet<u64>>,
+    meta: Mutex<PointMeta>,
+    config: HnswIndexConfig,
+    capacity: AtomicU64,
     dimension: u32,
     distance: DistanceType,
+    normalize: bool,
+    read_only: bool,
+    // Vector data is file-backed rather than heap-allocated.
+    mmapped: AtomicBool,
+    ef_search: AtomicU32,
+    // Double the capacity instead of failing with CapacityExceeded.
+    auto_grow: AtomicBool,
+    sketches: Mutex<Option<BinarySketches>>,
+    points: Mutex<Option<Arc<PointMap>>>,
+    reducer: Option<Arc<DimReducer>>,
+    source: Mutex<Option<DumpSource>>,
+    // Directory and basename of a load_resource dump, which is never recorded
+    // as the source.
+    resource: Option<(String, String)>,
+    // Set by load_lazy until the first call that needs the graph.
+    lazy: Mutex<Option<LazyLoad>>,
+    // try_search calls waiting for the graph, and how many may (0: any).
+    waiting_searches: AtomicU32,
+    max_waiting_searches: AtomicU32,
+    embedder: Mutex<Option<Arc<dyn Embedder>>>,
+    query_transform: Mutex<Option<Arc<QueryTransform>>>,
+    extend_candidates: AtomicBool,
+    keep_pruned: AtomicBool,
+    observer: Mutex<Option<Notifier>>,
+    query_cache: Mutex<Option<QueryCache>>,
 }
 
-#[uniffi::export]
 impl HnswIndex {
-    #[uniffi::constructor]
-    pub fn new(config: HnswIndexConfig) -> Self {
-        let dimension = config.dimension;
-        let distance = config.distance;
+    fn from_parts(
+        mut inner: HnswIndexInner,
+        mut meta: PointMeta,
+        mut config: HnswIndexConfig,
+        reducer: Option<Arc<DimReducer>>,
+    ) -> Self {
+        config.level_scale = config.level_scale.or(meta.level_scale);
+        meta.level_scale = config.level_scale;
+        inner.set_build_options(meta.build);
         Self {
-            inner: Mutex::new(HnswIndexInner::new(config)),
-            dimension,
-            distance,
+            extend_candidates: AtomicBool::new(meta.build.extend_candidates),
+            keep_pruned: AtomicBool::new(meta.build.keep_pruned),
+            ids: Mutex::new(&inner.ids() - &meta.tombstones),
+            inner: Mutex::new(inner),
+            meta: Mutex::new(meta),
+            config,
+            capacity: AtomicU64::new(config.max_elements),
+            dimension: config.dimension,
+            distance: config.distance,
+            normalize: config.normalize_vectors,
+            read_only: false,
+            mmapped: AtomicBool::new(false),
+            ef_search: AtomicU32::new(0),
+            auto_grow: AtomicBool::new(false),
+            sketches: Mutex::new(None),
+            points: Mutex::new(None),
+            reducer,
+            source: Mutex::new(None),
+            resource: None,
+            lazy: Mutex::new(None),
+            waiting_searches: AtomicU32::new(0),
+            max_waiting_searches: AtomicU32::new(0),
+            embedder: Mutex::new(None),
+            query_transform: Mutex::new(None),
+            observer: Mutex::new(None),
+            query_cache: Mutex::new(None),
         }
     }
 
-    #[uniffi::constructor]
-    pub fn load(
+    // Mirrors meta.build, so graphs rebuilt while meta is locked can still
+    // read the options.
+    fn build_options(&self) -> BuildOptions {
+        BuildOptions {
+            extend_candidates: self.extend_candidates.load(Ordering::Relaxed),
+            keep_pruned: self.keep_pruned.load(Ordering::Relaxed),
+        }
+    }
+
+    fn store_build_options(&self, options: BuildOptions) {
+        self.extend_candidates
+            .store(options.extend_candidates, Ordering::Relaxed);
+        self.keep_pruned
+            .store(options.keep_pruned, Ordering::Relaxed);
+    }
+
+    // Every access to the graph or the id set goes through here, so a lazily
+    // opened index is loaded by whichever call needs it first; until then the
+    // id set is empty. Lock order: inner, lazy, ids.
+    fn lock_inner(&self) -> Result<MutexGuard<'_, HnswIndexInner>, HnswError> {
+        let guard = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        self.load_pending(guard)
+    }
+
+    fn load_pending<'a>(
+        &self,
+        mut guard: MutexGuard<'a, HnswIndexInner>,
+    ) -> Result<MutexGuard<'a, HnswIndexInner>, HnswError> {
+        let mut lazy = self.lazy.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(pending) = lazy.as_ref() {
+            let _lock = DumpLock::shared(&pending.directory, &pending.basename)?;
+            *guard = HnswIndexInner::load(
+                pending.directory.clone(),
+                pending.basename.clone(),
+                self.distance,
+                pending.mmap,
+            )?;
+            let tombstones = self
+                .meta
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .tombstones
+                .clone();
+            *self.ids.lock().unwrap_or_else(PoisonError::into_inner) = &guard.ids() - &tombstones;
+            guard.set_build_options(self.build_options());
+            self.mmapped.store(pending.mmap, Ordering::Relaxed);
+            *lazy = None;
+        }
+        Ok(guard)
+    }
+
+    fn open(
         directory: String,
         basename: String,
         config: HnswIndexConfig,
+        read_only: bool,
     ) -> Result<Self, HnswError> {
-        let dimension = config.dimension;
-        let distance = config.distance;
-        let inner = HnswIndexInner::load(directory, basename, distance)?;
-        Ok(Self {
-            inner: Mutex::new(inner),
-            dimension,
-            distance,
-        })
+        let _signpost = signpost::interval("load");
+        let start = Instant::now();
+        let _lock = DumpLock::shared(&directory, &basename)?;
+        let modified = dump_modified(&directory, &basename)?;
+        let meta = PointMeta::load(&directory, &basename)?;
+        meta.check_parts(&directory, &basename)?;
+        let reducer = DimReducer::load_sidecar(&directory, &basename)?.map(Arc::new);
+        let mut inner = HnswIndexInner::load(
+            directory.clone(),
+            basename.clone(),
+            config.distance,
+            read_only,
+        )?;
+        if read_only {
+            inner.set_searching_mode(true);
+        }
+        let mut index = Self::from_parts(inner, meta, config, reducer);
+        index.read_only = read_only;
+        index.mmapped = AtomicBool::new(read_only);
+        logging::emit(LogLevel::Info, "load", Some(start.elapsed()), || {
+            vec![
+                ("directory", directory.clone()),
+                ("basename", basename.clone()),
+                ("read_only", read_only.to_string()),
+            ]
+        });
+        index.source = Mutex::new(Some(DumpSource {
+            directory,
+            basename,
+            modified,
+        }));
+        Ok(index)
     }
 
-    #[uniffi::method]
-    pub fn insert(&self, data: Vec<f32>, id: u64) -> Result<(), HnswError> {
-        if data.len() != self.dimension as usize {
-            return Err(HnswError::DimensionMismatch {
-                expected: self.dimension,
-                got: data.len() as u32,
-            });
-        }
-        let guard = self.inner.lock().map_err(|_| HnswError::LockError)?;
-        match &*guard {
-            HnswIndexInner::L2(inner) => inner.hnsw.insert((&data, id as usize)),
-            HnswIndexInner::Cosine(inner) => inner.hnsw.insert((&data, id as usize)),
-            HnswIndexInner::Dot(inner) => inner.hnsw.insert((&data, id as usize)),
-            HnswIndexInner::L1(inner) => inner.hnsw.insert((&data, id as usize)),
-        }
+    fn set_build_option(&self, set: impl FnOnce(&mut BuildOptions)) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let mut guard = self.lock_inner()?;
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        set(&mut meta.build);
+        self.store_build_options(meta.build);
+        guard.set_build_options(meta.build);
         Ok(())
     }
 
-    #[uniffi::method]
-    pub fn insert_batch(&self, data: Vec<Vec<f32>>, ids: Vec<u64>) -> Result<(), HnswError> {
-        if data.len() != ids.len() {
-            return Err(HnswError::IoError(
-                "Data and IDs must have the same length".to_string(),
-            ));
+    fn check_writable(&self) -> Result<(), HnswError> {
+        if self.read_only {
+            return Err(HnswError::ReadOnly);
         }
-        for vec in &data {
-            if vec.len() != self.dimension as usize {
-                return Err(HnswError::DimensionMismatch {
-                    expected: self.dimension,
-                    got: vec.len() as u32,
-                });
-            }
+        Ok(())
+    }
+
+    // Marks points as changed for export_changes. Called with the graph and id
+    // set still locked, so meta comes next in the lock order.
+    fn touch(&self, ids: &[u64]) {
+        self.touch_with(ids, |_| {});
+    }
+
+    // `touch`, also recording what `file` sets on the new points while meta
+    // is locked, so no search, export or cached result sees them without it.
+    fn touch_with(&self, ids: &[u64], file: impl FnOnce(&mut PointMeta)) {
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        file(&mut meta);
+        meta.touch(ids);
+    }
+
+    // `insert`, also recording a namespace, attributes and the like with
+    // `file` in the same step.
+    fn insert_with(
+        &self,
+        data: Vec<f32>,
+        id: u64,
+        file: impl FnOnce(&mut PointMeta),
+    ) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let _signpost = signpost::interval("insert");
+        let start = Instant::now();
+        let data = self.prepare_stored(data, 0)?;
+        let mut guard = self.lock_inner()?;
+        let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        if known.contains(&id) {
+            return Err(HnswError::DuplicateId(id));
         }
-        let guard = self.inner.lock().map_err(|_| HnswError::LockError)?;
+        self.ensure_capacity(&mut guard, &known, &[id])?;
+        known.insert(id);
+        let inserted = guarded("insert", || match &*guard {
+            HnswIndexInner::L2(inner) => inner.hnsw.insert((&data, id as usize)),
+            HnswIndexInner::Cosine(inner) => inner.hnsw.insert((&data, id as usize)),
+            HnswIndexInner::Dot(inner) => inner.hnsw.insert((&data, id as usize)),
+            HnswIndexInner::L1(inner) => inner.hnsw.insert((&data, id as usize)),
+        });
+        if inserted.is_err() {
+            known.remove(&id);
+        }
+        inserted?;
+        self.touch_with(&[id], file);
+        self.sketch_inserted(&[(&data, id as usize)]);
+        self.notify(|| Event::Insert(vec![id]));
+        // Release the locks first so a listener may call back into the index.
+        drop((known, guard));
+        logging::emit(LogLevel::Trace, "insert", Some(start.elapsed()), || {
+            vec![("id", id.to_string())]
+        });
+        Ok(())
+    }
+
+    fn insert_batch_with(
+        &self,
+        data: Vec<Vec<f32>>,
+        ids: Vec<u64>,
+        file: impl FnOnce(&mut PointMeta),
+    ) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let _signpost = signpost::interval("insert_batch");
+        let start = Instant::now();
+        let data = self.prepare_batch(data, &ids)?;
+        let mut guard = self.lock_inner()?;
+        let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        check_new_ids(&known, &ids)?;
+        self.ensure_capacity(&mut guard, &known, &ids)?;
         let pairs: Vec<(&Vec<f32>, usize)> =
             data.iter().zip(ids.iter().map(|&id| id as usize)).collect();
-        match &*guard {
-            HnswIndexInner::L2(inner) => inner.hnsw.parallel_insert(&pairs),
-            HnswIndexInner::Cosine(inner) => inner.hnsw.parallel_insert(&pairs),
-            HnswIndexInner::Dot(inner) => inner.hnsw.parallel_insert(&pairs),
-            HnswIndexInner::L1(inner) => inner.hnsw.parallel_insert(&pairs),
+        // A panic part way through leaves some of the batch in the graph but
+        // not in the id set; health_check reports that.
+        guarded("insert_batch", || {
+            threads::install(|| match &*guard {
+                HnswIndexInner::L2(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::Cosine(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::Dot(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::L1(inner) => inner.hnsw.parallel_insert(&pairs),
+            })
+        })?;
+        known.extend(&ids);
+        self.touch_with(&ids, file);
+        self.sketch_inserted(&pairs);
+        self.notify(|| Event::Insert(ids.clone()));
+        drop((known, guard));
+        logging::emit(
+            LogLevel::Debug,
+            "insert_batch",
+            Some(start.elapsed()),
+            || vec![("count", ids.len().to_string())],
+        );
+        Ok(())
+    }
+
+    // hnsw_rs can't delete in place, so removed ids become tombstones: they
+    // leave the id set and metadata at once, and searches filter them out,
+    // but their points stay in the graph until one rebuild purges them all.
+    // That happens once a quarter of the graph is tombstones, before an
+    // insert that would reuse one or run out of room, before anything reads
+    // every point, and from maintain. Nothing relinks the graph around a
+    // tombstone in the meantime: hnsw_rs has no API to rewire neighbour
+    // lists, so the purge's rebuild is the only repair, which repair_graph
+    // also runs on request. Hidden points stay linked on purpose.
+    fn remove_locked(
+        &self,
+        guard: &mut HnswIndexInner,
+        known: &mut HashSet<u64>,
+        meta: &mut PointMeta,
+        deleted: &[u64],
+    ) -> Result<(), HnswError> {
+        for id in deleted {
+            known.remove(id);
+        }
+        *meta = meta.without(deleted);
+        meta.tombstones.extend(deleted);
+        self.notify(|| Event::Remove(deleted.to_vec()));
+        if meta.tombstones.len() * 4 >= known.len() + meta.tombstones.len() {
+            self.purge_locked(guard, meta)?;
         }
         Ok(())
     }
 
-    #[uniffi::method]
-    pub fn search(
+    // Rebuilds the graph without its tombstones, if it has any.
+    fn purge_locked(
+        &self,
+        guard: &mut HnswIndexInner,
+        meta: &mut PointMeta,
+    ) -> Result<bool, HnswError> {
+        if meta.tombstones.is_empty() {
+            return Ok(false);
+        }
+        self.rebuild_locked(guard, meta, self.capacity.load(Ordering::Relaxed))?;
+        Ok(true)
+    }
+
+    // Brings the deferred rebuild forward. Returns whether there was
+    // anything to purge.
+    pub(crate) fn purge(&self) -> Result<bool, HnswError> {
+        let mut guard = self.lock_inner()?;
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        self.purge_locked(&mut guard, &mut meta)
+    }
+
+    // The graph, with no tombstones left in it, for reads that go through
+    // every point rather than searching.
+    pub(crate) fn lock_purged(&self) -> Result<MutexGuard<'_, HnswIndexInner>, HnswError> {
+        let mut guard = self.lock_inner()?;
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        self.purge_locked(&mut guard, &mut meta)?;
+        drop(meta);
+        Ok(guard)
+    }
+
+    fn rebuild_locked(
+        &self,
+        guard: &mut HnswIndexInner,
+        meta: &mut PointMeta,
+        max_elements: u64,
+    ) -> Result<(), HnswError> {
+        let config = HnswIndexConfig {
+            max_elements,
+            ..self.config
+        };
+        let deleted: Vec<u64> = meta.tombstones.iter().copied().collect();
+        *guard = guard.compacted(config, &deleted, self.build_options())?;
+        self.relocate_sketches(guard, &meta.tombstones);
+        self.invalidate_points();
+        meta.tombstones.clear();
+        self.mmapped.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn invalidate_sketches(&self) {
+        if let Some(sketches) = self
+            .sketches
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            sketches.invalidate();
+        }
+    }
+
+    // `search`, or with a timeout on getting the graph for try_search.
+    fn search_within(
         &self,
         query: Vec<f32>,
         k: u32,
         ef_search: u32,
+        timeout: Option<Duration>,
     ) -> Result<Vec<SearchResult>, HnswError> {
-        if query.len() != self.dimension as usize {
+        let _signpost = signpost::interval("search");
+        let start = Instant::now();
+        let query = self.prepare_query(query, 0)?;
+        let ef_search = self.resolve_ef(ef_search);
+        self.with_query_cache(&query, k, ef_search, None, || {
+            self.search_prepared(&query, k, ef_search, start, timeout)
+        })
+    }
+
+    fn search_prepared(
+        &self,
+        query: &[f32],
+        k: u32,
+        ef_search: u32,
+        start: Instant,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let guard = match timeout {
+            Some(timeout) => self.try_lock_inner(start + timeout)?,
+            None => self.lock_inner()?,
+        };
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let (k, ef) = (k as usize, ef_search as usize);
+        // The unfiltered path is kept for the common case of nothing hidden
+        // or removed.
+        let results = guarded("search", || {
+            if meta.all_visible() {
+                match &*guard {
+                    HnswIndexInner::L2(inner) => inner.hnsw.search(query, k, ef),
+                    HnswIndexInner::Cosine(inner) => inner.hnsw.search(query, k, ef),
+                    HnswIndexInner::Dot(inner) => inner.hnsw.search(query, k, ef),
+                    HnswIndexInner::L1(inner) => inner.hnsw.search(query, k, ef),
+                }
+            } else {
+                let filter = |id: &DataId| meta.visible(*id as u64);
+                let filter: Option<&dyn FilterT> = Some(&filter);
+                match &*guard {
+                    HnswIndexInner::L2(inner) => inner.hnsw.search_filter(query, k, ef, filter),
+                    HnswIndexInner::Cosine(inner) => inner.hnsw.search_filter(query, k, ef, filter),
+                    HnswIndexInner::Dot(inner) => inner.hnsw.search_filter(query, k, ef, filter),
+                    HnswIndexInner::L1(inner) => inner.hnsw.search_filter(query, k, ef, filter),
+                }
+            }
+        })?;
+        drop((meta, guard));
+        logging::emit(LogLevel::Debug, "search", Some(start.elapsed()), || {
+            vec![
+                ("k", k.to_string()),
+                ("ef_search", ef_search.to_string()),
+                ("results", results.len().to_string()),
+            ]
+        });
+        Ok(results.into_iter().map(SearchResult::from).collect())
+    }
+
+    fn grow_locked(
+        &self,
+        guard: &mut HnswIndexInner,
+        meta: &mut PointMeta,
+        new_max: u64,
+    ) -> Result<(), HnswError> {
+        if new_max <= self.capacity.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        self.rebuild_locked(guard, meta, new_max)?;
+        self.capacity.store(new_max, Ordering::Relaxed);
+        Ok(())
+    }
+
+    // hnsw_rs sizes its layers from max_elements and quietly degrades past
+    // it, so inserts check here first, with the graph and id set locked.
+    fn ensure_capacity(
+        &self,
+        guard: &mut HnswIndexInner,
+        known: &HashSet<u64>,
+        ids: &[u64],
+    ) -> Result<(), HnswError> {
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        self.ensure_capacity_locked(guard, known, &mut meta, ids)
+    }
+
+    // Tombstones count against hnsw_rs's max_elements, and a reused id must
+    // not meet its old point, so either means purging first.
+    fn ensure_capacity_locked(
+        &self,
+        guard: &mut HnswIndexInner,
+        known: &HashSet<u64>,
+        meta: &mut PointMeta,
+        ids: &[u64],
+    ) -> Result<(), HnswError> {
+        let max = self.capacity.load(Ordering::Relaxed);
+        let adding = ids.len();
+        if (known.len() + meta.tombstones.len() + adding) as u64 > max
+            || ids.iter().any(|id| meta.tombstones.contains(id))
+        {
+            self.purge_locked(guard, meta)?;
+        }
+        let attempted = (known.len() + adding) as u64;
+        if attempted <= max {
+            let threshold = max - max / 10;
+            if (known.len() as u64) < threshold
+                && attempted >= threshold
+                && !self.auto_grow.load(Ordering::Relaxed)
+            {
+                self.notify(|| Event::CapacityWarning(attempted, max));
+            }
+            return Ok(());
+        }
+        if !self.auto_grow.load(Ordering::Relaxed) {
+            return Err(HnswError::CapacityExceeded { max, attempted });
+        }
+        self.grow_locked(guard, meta, attempted.max(max.saturating_mul(2)))
+    }
+
+    // Dimension of caller-supplied vectors; differs from the stored dimension
+    // when a reducer is attached.
+    fn input_dimension(&self) -> u32 {
+        match &self.reducer {
+            Some(reducer) => reducer.input_dimension(),
+            None => self.dimension,
+        }
+    }
+
+    fn check_dimension(&self, len: usize) -> Result<(), HnswError> {
+        let expected = self.input_dimension();
+        if len != expected as usize {
             return Err(HnswError::DimensionMismatch {
-                expected: self.dimension,
-                got: query.len() as u32,
+                expected,
+                got: len as u32,
             });
         }
-        let guard = self.inner.lock().map_err(|_| HnswError::LockError)?;
-        let results = match &*guard {
-            HnswIndexInner::L2(inner) => inner.hnsw.search(&query, k as usize, ef_search as usize),
-            HnswIndexInner::Cosine(inner) => {
-                inner.hnsw.search(&query, k as usize, ef_search as usize)
-            }
-            HnswIndexInner::Dot(inner) => inner.hnsw.search(&query, k as usize, ef_search as usize),
-            HnswIndexInner::L1(inner) => inner.hnsw.search(&query, k as usize, ef_search as usize),
+        Ok(())
+    }
+
+    // An ef_search of 0 means the default from set_default_ef_search or
+    // tune_ef_search with apply, if one was set.
+    pub(crate) fn resolve_ef(&self, ef_search: u32) -> u32 {
+        match ef_search {
+            0 => self.ef_search.load(Ordering::Relaxed),
+            ef => ef,
+        }
+    }
+
+    // Validates a caller-supplied vector and maps it into the space the graph
+    // is built in.
+    fn prepare(&self, vector: Vec<f32>, index: usize) -> Result<Vec<f32>, HnswError> {
+        self.check_dimension(vector.len())?;
+        check_finite(&vector, index)?;
+        let mut vector = match &self.reducer {
+            Some(reducer) => reducer.project(&vector),
+            None => vector,
         };
-        Ok(results.into_iter().map(SearchResult::from).collect())
+        if let Some(weights) = &self
+            .meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .weights
+        {
+            weights::apply(&mut vector, weights);
+        }
+        check_metric(&vector, self.config.distance, index)?;
+        if self.normalize {
+            normalize_vector(&mut vector)?;
+        } else if self.config.distance == DistanceType::Dot {
+            check_unit(&mut vector, index)?;
+        }
+        Ok(vector)
+    }
+
+    // prepare for vectors about to be inserted, which also get privacy noise.
+    fn prepare_stored(&self, vector: Vec<f32>, index: usize) -> Result<Vec<f32>, HnswError> {
+        let mut vector = self.prepare(vector, index)?;
+        self.perturb(&mut vector);
+        Ok(vector)
+    }
+
+    fn prepare_batch(&self, data: Vec<Vec<f32>>, ids: &[u64]) -> Result<Vec<Vec<f32>>, HnswError> {
+        if data.len() != ids.len() {
+            return Err(HnswError::LengthMismatch {
+                vectors: data.len() as u64,
+                ids: ids.len() as u64,
+            });
+        }
+        data.into_iter()
+            .enumerate()
+            .map(|(i, vec)| self.prepare_stored(vec, i))
+            .collect()
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    #[uniffi::constructor]
+    pub fn new(config: HnswIndexConfig) -> Self {
+        Self::from_parts(
+            HnswIndexInner::new(config),
+            PointMeta::default(),
+            config,
+            None,
+        )
+    }
+
+    #[uniffi::constructor]
+    pub fn new_with_reducer(
+        config: HnswIndexConfig,
+        reducer: Arc<DimReducer>,
+    ) -> Result<Self, HnswError> {
+        if reducer.output_dimension() != config.dimension {
+            return Err(HnswError::DimensionMismatch {
+                expected: config.dimension,
+                got: reducer.output_dimension(),
+            });
+        }
+        Ok(Self::from_parts(
+            HnswIndexInner::new(config),
+            PointMeta::default(),
+            config,
+            Some(reducer),
+        ))
+    }
+
+    #[uniffi::constructor]
+    pub fn load(
+        directory: String,
+        basename: String,
+        config: HnswIndexConfig,
+    ) -> Result<Self, HnswError> {
+        Self::open(directory, basename, config, false)
+    }
+
+    // Search-only view: the data file is memory-mapped rather than copied, so
+    // several processes can share the same files, and every mutating call
+    // fails with ReadOnly.
+    #[uniffi::constructor]
+    pub fn load_read_only(
+        directory: String,
+        basename: String,
+        config: HnswIndexConfig,
+    ) -> Result<Self, HnswError> {
+        Self::open(directory, basename, config, true)
+    }
+
+    // For files shipped inside the app bundle. Nothing is written next to
+    // them, not even the lock file, since bundles are read-only on iOS and
+    // code-signed on macOS. With `mmap` the data file is mapped rather than
+    // copied into memory. The index is read-only either way.
+    #[uniffi::constructor]
+    pub fn load_resource(
+        directory: String,
+        basename: String,
+        config: HnswIndexConfig,
+        mmap: bool,
+    ) -> Result<Self, HnswError> {
+        let _signpost = signpost::interval("load");
+        let start = Instant::now();
+        let meta = PointMeta::load(&directory, &basename)?;
+        meta.check_parts(&directory, &basename)?;
+        let reducer = DimReducer::load_sidecar(&directory, &basename)?.map(Arc::new);
+        let mut inner =
+            HnswIndexInner::load(directory.clone(), basename.clone(), config.distance, mmap)?;
+        inner.set_searching_mode(true);
+        let mut index = Self::from_parts(inner, meta, config, reducer);
+        index.read_only = true;
+        index.mmapped = AtomicBool::new(mmap);
+        index.resource = Some((directory.clone(), basename.clone()));
+        logging::emit(LogLevel::Info, "load", Some(start.elapsed()), || {
+            vec![
+                ("directory", directory.clone()),
+                ("basename", basename.clone()),
+                ("resource", "true".to_string()),
+            ]
+        });
+        Ok(index)
+    }
+
+    // Only the metadata sidecars are read up front; the graph and data files
+    // are loaded, or mapped when `mmap` is set, by the first call that needs
+    // them.
+    #[uniffi::constructor]
+    pub fn load_lazy(
+        directory: String,
+        basename: String,
+        config: HnswIndexConfig,
+        mmap: bool,
+    ) -> Result<Self, HnswError> {
+        let _lock = DumpLock::shared(&directory, &basename)?;
+        let modified = dump_modified(&directory, &basename)?;
+        let meta = PointMeta::load(&directory, &basename)?;
+        meta.check_parts(&directory, &basename)?;
+        let reducer = DimReducer::load_sidecar(&directory, &basename)?.map(Arc::new);
+        let placeholder = HnswIndexInner::new(HnswIndexConfig {
+            max_elements: 0,
+            ..config
+        });
+        let index = Self::from_parts(placeholder, meta, config, reducer);
+        *index.lazy.lock().unwrap_or_else(PoisonError::into_inner) = Some(LazyLoad {
+            directory: directory.clone(),
+            basename: basename.clone(),
+            mmap,
+        });
+        *index.source.lock().unwrap_or_else(PoisonError::into_inner) = Some(DumpSource {
+            directory,
+            basename,
+            modified,
+        });
+        Ok(index)
     }
 
     #[uniffi::method]
-    pub fn len(&self) -> Result<u64, HnswError> {
-        let guard = self.inner.lock().map_err(|_| HnswError::LockError)?;
-        Ok(match &*guard {
-            HnswIndexInner::L2(inner) => inner.hnsw.get_nb_point() as u64,
-            HnswIndexInner::Cosine(inner) => inner.hnsw.get_nb_point() as u64,
-            HnswIndexInner::Dot(inner) => inner.hnsw.get_nb_point() as u64,
-            HnswIndexInner::L1(inner) => inner.hnsw.get_nb_point() as u64,
+    pub fn is_loaded(&self) -> Result<bool, HnswError> {
+        Ok(self
+            .lazy
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_none())
+    }
+
+    // Swaps in the dump on disk if it has been saved since this index was
+    // loaded, e.g. by another process. Unsaved local changes are discarded.
+    #[uniffi::method]
+    pub fn reload_if_changed(&self) -> Result<bool, HnswError> {
+        let mut source = self.source.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(source) = source.as_mut() else {
+            return Err(HnswError::InvalidArgument(
+                "Index was not loaded from disk".to_string(),
+            ));
+        };
+        let (directory, basename) = (&source.directory, &source.basename);
+        let _lock = DumpLock::shared(directory, basename)?;
+        let modified = dump_modified(directory, basename)?;
+        if modified == source.modified {
+            return Ok(false);
+        }
+        let meta = PointMeta::load(directory, basename)?;
+        meta.check_parts(directory, basename)?;
+        let mut inner = HnswIndexInner::load(
+            directory.clone(),
+            basename.clone(),
+            self.distance,
+            self.read_only,
+        )?;
+        if self.read_only {
+            inner.set_searching_mode(true);
+        }
+        let ids = &inner.ids() - &meta.tombstones;
+
+        // A still-pending lazy load is superseded rather than materialized.
+        let mut guard = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        *self.lazy.lock().unwrap_or_else(PoisonError::into_inner) = None;
+        let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut current = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        *guard = inner;
+        self.clear_query_cache();
+        self.store_build_options(meta.build);
+        guard.set_build_options(meta.build);
+        *known = ids;
+        *current = meta;
+        self.mmapped.store(self.read_only, Ordering::Relaxed);
+        self.invalidate_sketches();
+        self.invalidate_points();
+        source.modified = modified;
+        Ok(true)
+    }
+
+    #[uniffi::method]
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    #[uniffi::constructor]
+    pub fn load_cancellable(
+        directory: String,
+        basename: String,
+        config: HnswIndexConfig,
+        token: Arc<CancellationToken>,
+    ) -> Result<Self, HnswError> {
+        token.check()?;
+        let index = Self::load(directory, basename, config)?;
+        token.check()?;
+        Ok(index)
+    }
+
+    #[uniffi::constructor]
+    pub fn load_with_progress(
+        directory: String,
+        basename: String,
+        config: HnswIndexConfig,
+        listener: Box<dyn ProgressListener>,
+        token: Option<Arc<CancellationToken>>,
+    ) -> Result<Self, HnswError> {
+        let token = token.as_deref();
+        prefetch_dump(&directory, &basename, &*listener, token)?;
+        let index = Self::load(directory, basename, config)?;
+        if let Some(token) = token {
+            token.check()?;
+        }
+        Ok(index)
+    }
+
+    #[uniffi::method]
+    pub fn insert(&self, data: Vec<f32>, id: u64) -> Result<(), HnswError> {
+        self.insert_with(data, id, |_| {})
+    }
+
+    #[uniffi::method]
+    pub fn insert_batch(&self, data: Vec<Vec<f32>>, ids: Vec<u64>) -> Result<(), HnswError> {
+        self.insert_batch_with(data, ids, |_| {})
+    }
+
+    #[uniffi::method]
+    pub fn insert_batch_serial(&self, data: Vec<Vec<f32>>, ids: Vec<u64>) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let data = self.prepare_batch(data, &ids)?;
+        let mut guard = self.lock_inner()?;
+        let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        check_new_ids(&known, &ids)?;
+        self.ensure_capacity(&mut guard, &known, &ids)?;
+        // Inserts are one at a time, so a panic leaves only the points before
+        // it in the graph, and the id set is kept in step with them.
+        let mut done = 0;
+        let inserted = guarded("insert_batch_serial", || {
+            for (vec, &id) in data.iter().zip(ids.iter()) {
+                match &*guard {
+                    HnswIndexInner::L2(inner) => inner.hnsw.insert((vec, id as usize)),
+                    HnswIndexInner::Cosine(inner) => inner.hnsw.insert((vec, id as usize)),
+                    HnswIndexInner::Dot(inner) => inner.hnsw.insert((vec, id as usize)),
+                    HnswIndexInner::L1(inner) => inner.hnsw.insert((vec, id as usize)),
+                }
+                done += 1;
+            }
+        });
+        known.extend(&ids[..done]);
+        self.touch(&ids[..done]);
+        let pairs: Vec<(&Vec<f32>, usize)> =
+            data.iter().zip(ids.iter().map(|&id| id as usize)).collect();
+        self.sketch_inserted(&pairs[..done]);
+        self.notify(|| Event::Insert(ids[..done].to_vec()));
+        inserted
+    }
+
+    #[uniffi::method]
+    pub fn insert_batch_with_progress(
+        &self,
+        data: Vec<Vec<f32>>,
+        ids: Vec<u64>,
+        listener: Box<dyn ProgressListener>,
+    ) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let data = self.prepare_batch(data, &ids)?;
+        let mut guard = self.lock_inner()?;
+        let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        check_new_ids(&known, &ids)?;
+        self.ensure_capacity(&mut guard, &known, &ids)?;
+        let pairs: Vec<(&Vec<f32>, usize)> =
+            data.iter().zip(ids.iter().map(|&id| id as usize)).collect();
+        let listener: &dyn ProgressListener = &*listener;
+        guarded("insert_batch_with_progress", || {
+            threads::install(|| match &*guard {
+                HnswIndexInner::L2(inner) => {
+                    insert_pairs(&inner.hnsw, &pairs, Some(listener), None)
+                }
+                HnswIndexInner::Cosine(inner) => {
+                    insert_pairs(&inner.hnsw, &pairs, Some(listener), None)
+                }
+                HnswIndexInner::Dot(inner) => {
+                    insert_pairs(&inner.hnsw, &pairs, Some(listener), None)
+                }
+                HnswIndexInner::L1(inner) => {
+                    insert_pairs(&inner.hnsw, &pairs, Some(listener), None)
+                }
+            })
+        })
+        .and_then(|result| result)?;
+        known.extend(&ids);
+        self.touch(&ids);
+        self.sketch_inserted(&pairs);
+        self.notify(|| Event::Insert(ids.clone()));
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn insert_batch_cancellable(
+        &self,
+        data: Vec<Vec<f32>>,
+        ids: Vec<u64>,
+        token: Arc<CancellationToken>,
+    ) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let data = self.prepare_batch(data, &ids)?;
+        token.check()?;
+        let mut guard = self.lock_inner()?;
+        let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        check_new_ids(&known, &ids)?;
+        self.ensure_capacity(&mut guard, &known, &ids)?;
+        let pairs: Vec<(&Vec<f32>, usize)> =
+            data.iter().zip(ids.iter().map(|&id| id as usize)).collect();
+        let token: &CancellationToken = &token;
+        let result = guarded("insert_batch_cancellable", || {
+            threads::install(|| match &*guard {
+                HnswIndexInner::L2(inner) => insert_pairs(&inner.hnsw, &pairs, None, Some(token)),
+                HnswIndexInner::Cosine(inner) => {
+                    insert_pairs(&inner.hnsw, &pairs, None, Some(token))
+                }
+                HnswIndexInner::Dot(inner) => insert_pairs(&inner.hnsw, &pairs, None, Some(token)),
+                HnswIndexInner::L1(inner) => insert_pairs(&inner.hnsw, &pairs, None, Some(token)),
+            })
         })
+        .and_then(|result| result);
+        // A cancelled or failed batch is partially inserted; rescan to learn
+        // which ids made it.
+        // The point count is then off too, so the sketches are rebuilt from the
+        // graph on the next search_bq.
+        match result {
+            Ok(()) => {
+                known.extend(&ids);
+                self.sketch_inserted(&pairs);
+            }
+            Err(_) => {
+                let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+                *known = &guard.ids() - &meta.tombstones;
+            }
+        }
+        let inserted: Vec<u64> = ids
+            .iter()
+            .copied()
+            .filter(|id| known.contains(id))
+            .collect();
+        self.touch(&inserted);
+        self.notify(|| Event::Insert(inserted.clone()));
+        result
     }
 
     #[uniffi::method]
-    pub fn is_empty(&self) -> Result<bool, HnswError> {
-        let guard = self.inner.lock().map_err(|_| HnswError::LockError)?;
-        Ok(match &*guard {
-            HnswIndexInner::L2(inner) => inner.hnsw.get_nb_point() == 0,
-            HnswIndexInner::Cosine(inner) => inner.hnsw.get_nb_point() == 0,
-            HnswIndexInner::Dot(inner) => inner.hnsw.get_nb_point() == 0,
-            HnswIndexInner::L1(inner) => inner.hnsw.get_nb_point() == 0,
+    pub fn insert_batch_with_qos(
+        &self,
+        data: Vec<Vec<f32>>,
+        ids: Vec<u64>,
+        qos: ThreadQos,
+    ) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let data = self.prepare_batch(data, &ids)?;
+        let mut guard = self.lock_inner()?;
+        let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        check_new_ids(&known, &ids)?;
+        self.ensure_capacity(&mut guard, &known, &ids)?;
+        let pairs: Vec<(&Vec<f32>, usize)> =
+            data.iter().zip(ids.iter().map(|&id| id as usize)).collect();
+        guarded("insert_batch_with_qos", || {
+            threads::install_with_qos(qos, || match &*guard {
+                HnswIndexInner::L2(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::Cosine(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::Dot(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::L1(inner) => inner.hnsw.parallel_insert(&pairs),
+            })
         })
+        .and_then(|result| result)?;
+        known.extend(&ids);
+        self.touch(&ids);
+        self.sketch_inserted(&pairs);
+        self.notify(|| Event::Insert(ids.clone()));
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn search(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        self.search_within(query, k, ef_search, None)
+    }
+
+    #[uniffi::method]
+    pub fn len(&self) -> Result<u64, HnswError> {
+        let guard = self.lock_inner()?;
+        let points = match &*guard {
+            HnswIndexInner::L2(inner) => inner.hnsw.get_nb_point(),
+            HnswIndexInner::Cosine(inner) => inner.hnsw.get_nb_point(),
+            HnswIndexInner::Dot(inner) => inner.hnsw.get_nb_point(),
+            HnswIndexInner::L1(inner) => inner.hnsw.get_nb_point(),
+        };
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok((points - meta.tombstones.len()) as u64)
+    }
+
+    #[uniffi::method]
+    pub fn is_empty(&self) -> Result<bool, HnswError> {
+        Ok(self.len()? == 0)
     }
 
     #[uniffi::method]
     pub fn get_dimension(&self) -> u32 {
-        self.dimension
+        self.input_dimension()
+    }
+
+    #[uniffi::method]
+    pub fn get_distance(&self) -> DistanceType {
+        self.distance
+    }
+
+    #[uniffi::method]
+    pub fn get_reducer(&self) -> Option<Arc<DimReducer>> {
+        self.reducer.clone()
     }
 
     #[uniffi::method]
     pub fn save(&self, directory: String, basename: String) -> Result<(), HnswError> {
-        let guard = self.inner.lock().map_err(|_| HnswError::LockError)?;
+        self.check_writable()?;
+        let _signpost = signpost::interval("save");
+        let start = Instant::now();
+        let _lock = DumpLock::exclusive(&directory, &basename)?;
+        let guard = self.lock_inner()?;
         let path = Path::new(&directory);
-        match &*guard {
-            HnswIndexInner::L2(inner) => inner
-                .hnsw
-                .file_dump(path, &basename)
-                .map_err(|e| HnswError::DumpError(e.to_string()))?,
-            HnswIndexInner::Cosine(inner) => inner
-                .hnsw
-                .file_dump(path, &basename)
-                .map_err(|e| HnswError::DumpError(e.to_string()))?,
-            HnswIndexInner::Dot(inner) => inner
-                .hnsw
-                .file_dump(path, &basename)
-                .map_err(|e| HnswError::DumpError(e.to_string()))?,
-            HnswIndexInner::L1(inner) => inner
-                .hnsw
-                .file_dump(path, &basename)
-                .map_err(|e| HnswError::DumpError(e.to_string()))?,
-        };
+        // Dump under a staging name and rename into place, so processes that
+        // have the previous files mapped keep reading the old inodes.
+        let staging = format!("{basename}.staging");
+        let dumped = guarded("save", || match &*guard {
+            HnswIndexInner::L2(inner) => inner.hnsw.file_dump(path, &staging),
+            HnswIndexInner::Cosine(inner) => inner.hnsw.file_dump(path, &staging),
+            HnswIndexInner::Dot(inner) => inner.hnsw.file_dump(path, &staging),
+            HnswIndexInner::L1(inner) => inner.hnsw.file_dump(path, &staging),
+        })?
+        .map_err(|e| HnswError::DumpError(e.to_string()))?;
+        let staged_len =
+            |ext: &str| fs::metadata(path.join(format!("{dumped}.{ext}"))).map(|m| m.len());
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        meta.parts = Some(DumpParts {
+            graph_bytes: staged_len("hnsw.graph")?,
+            data_bytes: staged_len("hnsw.data")?,
+            reducer: self.reducer.is_some(),
+        });
+        meta.save(&directory, &staging)?;
+        DimReducer::save_sidecar(self.reducer.as_deref(), &directory, &staging)?;
+        drop(guard);
+
+        // Everything is written; move it into place with the metadata last.
+        rename_dump(&directory, &dumped, &basename)?;
+        let reducer = DimReducer::sidecar_path(&directory, &basename);
+        if self.reducer.is_some() {
+            fs::rename(DimReducer::sidecar_path(&directory, &staging), reducer)?;
+        } else if reducer.exists() {
+            fs::remove_file(reducer)?;
+        }
+        fs::rename(
+            PointMeta::path(&directory, &staging),
+            PointMeta::path(&directory, &basename),
+        )?;
+        drop(meta);
+        self.notify(|| Event::Save(directory.clone(), basename.clone()));
+        logging::emit(LogLevel::Info, "save", Some(start.elapsed()), || {
+            vec![
+                ("directory", directory.clone()),
+                ("basename", basename.clone()),
+            ]
+        });
         Ok(())
     }
 
+    #[uniffi::method]
+    pub fn save_cancellable(
+        &self,
+        directory: String,
+        basename: String,
+        token: Arc<CancellationToken>,
+    ) -> Result<(), HnswError> {
+        // file_dump writes both files in one call, so the only safe point to
+        // abort is before anything has been written.
+        token.check()?;
+        self.save(directory, basename)
+    }
+
+    #[uniffi::method]
+    pub fn save_with_qos(
+        &self,
+        directory: String,
+        basename: String,
+        qos: ThreadQos,
+    ) -> Result<(), HnswError> {
+        threads::install_with_qos(qos, || self.save(directory, basename))?
+    }
+
     #[uniffi::method]
     pub fn set_searching_mode(&self, enabled: bool) -> Result<(), HnswError> {
-        let mut guard = self.inner.lock().map_err(|_| HnswError::LockError)?;
-        match &mut *guard {
-            HnswIndexInner::L2(inner) => inner.hnsw.set_searching_mode(enabled),
-            HnswIndexInner::Cosine(inner) => inner.hnsw.set_searching_mode(enabled),
-            HnswIndexInner::Dot(inner) => inner.hnsw.set_searching_mode(enabled),
-            HnswIndexInner::L1(inner) => inner.hnsw.set_searching_mode(enabled),
-        }
+        let mut guard = self.lock_inner()?;
+        guard.set_searching_mode(enabled);
         Ok(())
     }
 
+    // Also considers the neighbours of candidates when choosing a new point's
+    // links. Slower inserts, better recall on clustered data. Saved with the
+    // index and applies to points inserted from now on.
+    #[uniffi::method]
+    pub fn set_extend_candidates(&self, enabled: bool) -> Result<(), HnswError> {
+        self.set_build_option(|options| options.extend_candidates = enabled)
+    }
+
+    // Tops up a new point's links with candidates the pruning heuristic
+    // dropped, so it keeps its full complement of links. Saved with the index
+    // and applies to points inserted from now on.
+    #[uniffi::method]
+    pub fn set_keep_pruned(&self, enabled: bool) -> Result<(), HnswError> {
+        self.set_build_option(|options| options.keep_pruned = enabled)
+    }
+
+    #[uniffi::method]
+    pub fn get_extend_candidates(&self) -> bool {
+        self.extend_candidates.load(Ordering::Relaxed)
+    }
+
+    #[uniffi::method]
+    pub fn get_keep_pruned(&self) -> bool {
+        self.keep_pruned.load(Ordering::Relaxed)
+    }
+
+    // Removes `ids` in place and returns how many were in the index. Unlike
+    // compact this keeps the same index object. The ids are gone from
+    // results at once; the graph is rebuilt later, once for many removals.
+    #[uniffi::method]
+    pub fn remove_batch(&self, ids: Vec<u64>) -> Result<u64, HnswError> {
+        self.check_writable()?;
+        let mut guard = self.lock_inner()?;
+        let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut deleted: Vec<u64> = ids.into_iter().filter(|id| known.contains(id)).collect();
+        deleted.sort_unstable();
+        deleted.dedup();
+        if deleted.is_empty() {
+            return Ok(0);
+        }
+        self.remove_locked(&mut guard, &mut known, &mut meta, &deleted)?;
+        Ok(deleted.len() as u64)
+    }
+
     #[uniffi::method]
     pub fn compact(
         &self,
@@ -507,43 +2302,216 @@ impl HnswIndex {
                 got: config.distance,
             });
         }
-        let guard = self.inner.lock().map_err(|_| HnswError::LockError)?;
-        let inner = match &*guard {
-            HnswIndexInner::L2(existing) => {
-                let hnsw = compact_hnsw(&existing.hnsw, config, &deleted_ids, || DistL2 {})?;
-                HnswIndexInner::L2(HnswInnerL2 {
-                    hnsw: ManuallyDrop::new(hnsw),
-                    io_ptr: None,
-                })
-            }
-            HnswIndexInner::Cosine(existing) => {
-                let hnsw = compact_hnsw(&existing.hnsw, config, &deleted_ids, || DistCosine {})?;
-                HnswIndexInner::Cosine(HnswInnerCosine {
-                    hnsw: ManuallyDrop::new(hnsw),
-                    io_ptr: None,
-                })
-            }
-            HnswIndexInner::Dot(existing) => {
-                let hnsw = compact_hnsw(&existing.hnsw, config, &deleted_ids, || DistDot {})?;
-                HnswIndexInner::Dot(HnswInnerDot {
-                    hnsw: ManuallyDrop::new(hnsw),
-                    io_ptr: None,
-                })
-            }
-            HnswIndexInner::L1(existing) => {
-                let hnsw = compact_hnsw(&existing.hnsw, config, &deleted_ids, || DistL1 {})?;
-                HnswIndexInner::L1(HnswInnerL1 {
-                    hnsw: ManuallyDrop::new(hnsw),
-                    io_ptr: None,
-                })
+        let guard = self.lock_purged()?;
+        let inner = guard.compacted(config, &deleted_ids, self.build_options())?;
+        let meta = self
+            .meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .without(&deleted_ids);
+        // compacted grows the graph to fit what it kept; the capacity has to
+        // agree with it.
+        let config = HnswIndexConfig {
+            max_elements: config.max_elements.max(inner.nb_point() as u64),
+            ..config
+        };
+        Ok(Self::from_parts(inner, meta, config, self.reducer.clone()))
+    }
+
+    // Vectors are copied out up front so the source index stays searchable
+    // while the new graph is built.
+    #[uniffi::method]
+    pub fn rebuild(
+        &self,
+        new_config: HnswIndexConfig,
+        listener: Box<dyn ProgressListener>,
+        token: Option<Arc<CancellationToken>>,
+    ) -> Result<Self, HnswError> {
+        if new_config.dimension != self.dimension {
+            return Err(HnswError::DimensionMismatch {
+                expected: self.dimension,
+                got: new_config.dimension,
+            });
+        }
+        let mut points = self.lock_purged()?.points();
+        if new_config.normalize_vectors {
+            for (i, (_, vec)) in points.iter_mut().enumerate() {
+                normalize_vector(vec).map_err(|_| HnswError::InvalidVector {
+                    index: i as u64,
+                    reason: "zero vector cannot be normalized".to_string(),
+                })?;
             }
+        }
+        let meta = self
+            .meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let config = HnswIndexConfig {
+            max_elements: new_config.max_elements.max(points.len() as u64),
+            ..new_config
         };
-        Ok(Self {
-            inner: Mutex::new(inner),
-            dimension: config.dimension,
-            distance: config.distance,
+        let mut inner = HnswIndexInner::new(config);
+        inner.set_build_options(self.build_options());
+        let pairs: Vec<(&Vec<f32>, usize)> =
+            points.iter().map(|(id, vec)| (vec, *id as usize)).collect();
+        let (listener, token) = (Some(&*listener), token.as_deref());
+        guarded("rebuild", || {
+            threads::install(|| match &inner {
+                HnswIndexInner::L2(inner) => insert_pairs(&inner.hnsw, &pairs, listener, token),
+                HnswIndexInner::Cosine(inner) => insert_pairs(&inner.hnsw, &pairs, listener, token),
+                HnswIndexInner::Dot(inner) => insert_pairs(&inner.hnsw, &pairs, listener, token),
+                HnswIndexInner::L1(inner) => insert_pairs(&inner.hnsw, &pairs, listener, token),
+            })
         })
+        .and_then(|result| result)?;
+        let index = Self::from_parts(inner, meta, config, self.reducer.clone());
+        index
+            .ef_search
+            .store(self.ef_search.load(Ordering::Relaxed), Ordering::Relaxed);
+        Ok(index)
     }
+
+    #[uniffi::method]
+    pub fn get_capacity(&self) -> u64 {
+        self.capacity.load(Ordering::Relaxed)
+    }
+
+    #[uniffi::method]
+    pub fn reserve(&self, additional: u64) -> Result<(), HnswError> {
+        let len = self.len()?;
+        self.grow_to(len.saturating_add(additional))
+    }
+
+    // hnsw_rs fixes its sizing when the graph is created, so growing means
+    // rebuilding the graph from the stored vectors at the new capacity.
+    #[uniffi::method]
+    pub fn grow_to(&self, new_max: u64) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let mut guard = self.lock_inner()?;
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        self.grow_locked(&mut guard, &mut meta, new_max)
+    }
+
+    #[uniffi::method]
+    pub fn remaining_capacity(&self) -> Result<u64, HnswError> {
+        let _guard = self.lock_inner()?;
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(self
+            .capacity
+            .load(Ordering::Relaxed)
+            .saturating_sub(known.len() as u64))
+    }
+
+    #[uniffi::method]
+    pub fn set_auto_grow(&self, enabled: bool) {
+        self.auto_grow.store(enabled, Ordering::Relaxed);
+    }
+
+    #[uniffi::method]
+    pub fn insert_with_namespace(
+        &self,
+        data: Vec<f32>,
+        id: u64,
+        namespace: u32,
+    ) -> Result<(), HnswError> {
+        self.insert_with(data, id, |meta| {
+            meta.namespaces.insert(id, namespace);
+        })
+    }
+
+    #[uniffi::method]
+    pub fn insert_batch_with_namespace(
+        &self,
+        data: Vec<Vec<f32>>,
+        ids: Vec<u64>,
+        namespace: u32,
+    ) -> Result<(), HnswError> {
+        let filed = ids.clone();
+        self.insert_batch_with(data, ids, |meta| {
+            for id in filed {
+                meta.namespaces.insert(id, namespace);
+            }
+        })
+    }
+
+    #[uniffi::method]
+    pub fn get_namespace(&self, id: u64) -> Result<Option<u32>, HnswError> {
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(meta.namespaces.get(&id).copied())
+    }
+
+    #[uniffi::method]
+    pub fn search_in_namespace(
+        &self,
+        query: Vec<f32>,
+        namespace: u32,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let query = self.prepare_query(query, 0)?;
+        let ef_search = self.resolve_ef(ef_search);
+        self.with_query_cache(&query, k, ef_search, Some(namespace), || {
+            let guard = self.lock_inner()?;
+            let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+            let filter = |id: &DataId| {
+                meta.namespaces.get(&(*id as u64)) == Some(&namespace) && meta.visible(*id as u64)
+            };
+            let filter: Option<&dyn FilterT> = Some(&filter);
+            let (k, ef_search) = (k as usize, ef_search as usize);
+            let results = guarded("search_in_namespace", || match &*guard {
+                HnswIndexInner::L2(inner) => inner.hnsw.search_filter(&query, k, ef_search, filter),
+                HnswIndexInner::Cosine(inner) => {
+                    inner.hnsw.search_filter(&query, k, ef_search, filter)
+                }
+                HnswIndexInner::Dot(inner) => {
+                    inner.hnsw.search_filter(&query, k, ef_search, filter)
+                }
+                HnswIndexInner::L1(inner) => inner.hnsw.search_filter(&query, k, ef_search, filter),
+            })?;
+            Ok(results.into_iter().map(SearchResult::from).collect())
+        })
+    }
+}
+
+// Same definitions the index scores with, but computed in f32 by simd.rs
+// where anndists accumulates cosine in f64, so app-side re-ranking agrees
+// with search distances to within rounding, not bit for bit.
+#[uniffi::export]
+pub fn distance(a: Vec<f32>, b: Vec<f32>, distance_type: DistanceType) -> Result<f32, HnswError> {
+    if a.len() != b.len() {
+        return Err(HnswError::DimensionMismatch {
+            expected: a.len() as u32,
+            got: b.len() as u32,
+        });
+    }
+    Ok(simd::eval(distance_type, &a, &b))
+}
+
+#[uniffi::export]
+pub fn cosine_similarity(a: Vec<f32>, b: Vec<f32>) -> Result<f32, HnswError> {
+    Ok(1.0 - distance(a, b, DistanceType::Cosine)?)
+}
+
+// Rough per-point bookkeeping of hnsw_rs: the Point struct with its Arc/RwLock
+// wrappers, and one Arc<PointWithOrder> allocation per neighbour link.
+const POINT_OVERHEAD_BYTES: u64 = 128;
+const NEIGHBOUR_LINK_BYTES: u64 = 40;
+
+#[uniffi::export]
+pub fn estimate_memory_bytes(
+    dimension: u32,
+    count: u64,
+    max_nb_connection: u32,
+    element_type: ElementType,
+) -> u64 {
+    let m = max_nb_connection.max(2) as u64;
+    // Layer 0 holds up to 2 * M links; upper layers add M links each with
+    // geometrically decreasing probability, roughly M / (M - 1) in total.
+    let links = 2 * m + m.div_ceil(m - 1);
+    let vector_bytes = dimension as u64 * element_type.size_bytes();
+    let per_point = vector_bytes + POINT_OVERHEAD_BYTES + links * NEIGHBOUR_LINK_BYTES;
+    count.saturating_mul(per_point + std::mem::size_of::<usize>() as u64)
 }
 
 uniffi::setup_scaffolding!();
@@ -0,0 +1,74 @@
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::HnswError;
+
+// Advisory lock on `{basename}.hnsw.lock`, held for the duration of a dump or
+// reload so a writer in one process never races a reader in another (e.g. an
+// app and its extensions sharing an app-group container). Released on drop.
+pub(crate) struct DumpLock {
+    _file: Option<File>,
+}
+
+fn lock_path(directory: &str, basename: &str) -> PathBuf {
+    Path::new(directory).join(format!("{basename}.hnsw.lock"))
+}
+
+#[cfg(unix)]
+fn flock(file: &File, op: libc::c_int) -> io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+    loop {
+        if unsafe { libc::flock(file.as_raw_fd(), op) } == 0 {
+            return Ok(());
+        }
+        let err = io::Error::last_os_error();
+        if err.kind() != io::ErrorKind::Interrupted {
+            return Err(err);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn flock(_file: &File, _op: i32) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(unix)]
+const LOCK_SH: libc::c_int = libc::LOCK_SH;
+#[cfg(unix)]
+const LOCK_EX: libc::c_int = libc::LOCK_EX;
+#[cfg(not(unix))]
+const LOCK_SH: i32 = 0;
+#[cfg(not(unix))]
+const LOCK_EX: i32 = 0;
+
+impl DumpLock {
+    // Readers may sit in read-only locations (app bundles), where the lock
+    // file can't be created; nobody can be writing there, so go unlocked.
+    pub(crate) fn shared(directory: &str, basename: &str) -> Result<Self, HnswError> {
+        let file = match OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(lock_path(directory, basename))
+        {
+            Ok(file) => file,
+            Err(_) => return Ok(DumpLock { _file: None }),
+        };
+        flock(&file, LOCK_SH)?;
+        Ok(DumpLock { _file: Some(file) })
+    }
+
+    pub(crate) fn exclusive(directory: &str, basename: &str) -> Result<Self, HnswError> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(lock_path(directory, basename))?;
+        flock(&file, LOCK_EX)?;
+        Ok(DumpLock { _file: Some(file) })
+    }
+}
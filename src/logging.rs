@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, uniffi::Enum)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct LogEvent {
+    pub level: LogLevel,
+    pub event: String,
+    pub duration_us: Option<u64>,
+    pub fields: HashMap<String, String>,
+}
+
+#[uniffi::export(callback_interface)]
+pub trait LogListener: Send + Sync {
+    fn on_log(&self, event: LogEvent);
+}
+
+struct Logger {
+    level: LogLevel,
+    listener: Arc<dyn LogListener>,
+}
+
+static LOGGER: RwLock<Option<Logger>> = RwLock::new(None);
+
+// Fields are built lazily so call sites cost one lock read when logging is off.
+pub(crate) fn emit<F>(level: LogLevel, event: &str, duration: Option<Duration>, fields: F)
+where
+    F: FnOnce() -> Vec<(&'static str, String)>,
+{
+    let listener = match &*LOGGER.read().unwrap_or_else(PoisonError::into_inner) {
+        Some(logger) if level >= logger.level => Arc::clone(&logger.listener),
+        _ => return,
+    };
+    listener.on_log(LogEvent {
+        level,
+        event: event.to_string(),
+        duration_us: duration.map(|d| d.as_micros() as u64),
+        fields: fields()
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect(),
+    });
+}
+
+#[uniffi::export]
+pub fn set_log_callback(listener: Box<dyn LogListener>, level: LogLevel) {
+    *LOGGER.write().unwrap_or_else(PoisonError::into_inner) = Some(Logger {
+        level,
+        listener: Arc::from(listener),
+    });
+}
+
+#[uniffi::export]
+pub fn clear_log_callback() {
+    *LOGGER.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
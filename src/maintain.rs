@@ -0,0 +1,94 @@
+use std::sync::PoisonError;
+use std::time::{Duration, Instant};
+
+use crate::{HnswError, HnswIndex};
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MaintenanceReport {
+    // The steps that did work, in order: "load", "purge", "sketches",
+    // "shrink".
+    pub performed: Vec<String>,
+    // False when the budget ran out before every step had its turn; call
+    // again at the next opportunity.
+    pub finished: bool,
+    pub elapsed_ms: u64,
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Does deferred housekeeping so the next foreground call doesn't have to,
+    // e.g. from a BGProcessingTask while the device charges. In order: loads a
+    // load_lazy index, rebuilds the graph without points removed since the
+    // last purge, rebuilds stale binary sketches, and releases spare capacity
+    // in the id set and metadata. A step isn't started once the budget is
+    // spent, but one that has started runs to completion.
+    //
+    // There is no write-ahead log: writes go straight into the graph. Saving
+    // stays with the app, which knows where the index lives.
+    #[uniffi::method]
+    pub fn maintain(&self, budget_ms: u64) -> Result<MaintenanceReport, HnswError> {
+        let start = Instant::now();
+        let budget = Duration::from_millis(budget_ms);
+        let mut performed = Vec::new();
+        let mut finished = false;
+        for step in ["load", "purge", "sketches", "shrink"] {
+            if start.elapsed() >= budget {
+                break;
+            }
+            let did_work = match step {
+                "load" => {
+                    let pending = self
+                        .lazy
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .is_some();
+                    if pending {
+                        drop(self.lock_inner()?);
+                    }
+                    pending
+                }
+                "purge" => self.purge()?,
+                "sketches" => self.refresh_sketches()?,
+                _ => {
+                    self.ids
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .shrink_to_fit();
+                    self.meta
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .shrink_to_fit();
+                    finished = true;
+                    true
+                }
+            };
+            if did_work {
+                performed.push(step.to_string());
+            }
+        }
+        Ok(MaintenanceReport {
+            performed,
+            finished,
+            elapsed_ms: start.elapsed().as_millis() as u64,
+        })
+    }
+
+    // Repairs the links that removals leave dangling, for apps that delete
+    // heavily and want recall back before maintain or the automatic purge
+    // gets to it. hnsw_rs can't rewire a neighbour list in place, so the
+    // repair is the purge's rebuild from the live points, and `max_work`
+    // caps how many points it may reinsert: a graph bigger than that is left
+    // alone rather than half rebuilt. Returns how many removed points are
+    // still linked in, so 0 means the graph is repaired.
+    #[uniffi::method]
+    pub fn repair_graph(&self, max_work: u64) -> Result<u64, HnswError> {
+        self.check_writable()?;
+        let mut guard = self.lock_inner()?;
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        if !meta.tombstones.is_empty() && known.len() as u64 <= max_work {
+            self.purge_locked(&mut guard, &mut meta)?;
+        }
+        Ok(meta.tombstones.len() as u64)
+    }
+}
@@ -0,0 +1,145 @@
+use std::sync::PoisonError;
+use std::sync::atomic::Ordering;
+
+use crate::lock::DumpLock;
+use crate::{
+    ElementType, HnswError, HnswIndex, HnswIndexInner, dump_modified, estimate_memory_bytes,
+};
+
+#[uniffi::export]
+impl HnswIndex {
+    // Estimate of heap bytes held by the index, using the same per-point model
+    // as estimate_memory_bytes. Vector data of an mmapped index is
+    // file-backed and not counted.
+    #[uniffi::method]
+    pub fn memory_footprint(&self) -> Result<u64, HnswError> {
+        let count = self.len()?;
+        let mut bytes = estimate_memory_bytes(
+            self.dimension,
+            count,
+            self.config.max_nb_connection,
+            ElementType::F32,
+        );
+        if self.mmapped.load(Ordering::Relaxed) {
+            bytes = bytes.saturating_sub(count * self.dimension as u64 * 4);
+        }
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        bytes += known.capacity() as u64 * 9;
+        drop(known);
+        bytes += self
+            .meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .heap_bytes();
+        if let Some(sketches) = &*self.sketches.lock().unwrap_or_else(PoisonError::into_inner) {
+            bytes += sketches.heap_bytes();
+        }
+        if let Some(points) = &*self.points.lock().unwrap_or_else(PoisonError::into_inner) {
+            bytes += points.heap_bytes();
+        }
+        if let Some(reducer) = &self.reducer {
+            let (input, output) = (
+                reducer.input_dimension() as u64,
+                reducer.output_dimension() as u64,
+            );
+            bytes += (input * output + input) * 4;
+        }
+        Ok(bytes)
+    }
+
+    // True when vector data is read from the mapped dump rather than held on
+    // the heap: read-only loads, load_resource with mmap, and evict_vectors.
+    // Anything that rebuilds the graph brings the vectors back onto the heap.
+    #[uniffi::method]
+    pub fn is_mmapped(&self) -> bool {
+        self.mmapped.load(Ordering::Relaxed)
+    }
+
+    // The vector stored for `id`, as it was indexed (after any reducer,
+    // weights and normalization), or None for an unknown id. On an mmapped
+    // index it is copied straight out of the mapped data file, so reading a
+    // few vectors doesn't page in the rest. The lookup is the shared point
+    // map, so only the first call after the graph changes walks the graph.
+    #[uniffi::method]
+    pub fn get_point_data(&self, id: u64) -> Result<Option<Vec<f32>>, HnswError> {
+        let guard = self.lock_inner()?;
+        if !self
+            .ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains(&id)
+        {
+            return Ok(None);
+        }
+        Ok(self.point_map(&guard).vector(id))
+    }
+
+    // Releases spare capacity in the id set and metadata maps, and drops the
+    // binary sketches and the point lookup, which are rebuilt on next use. hnsw_rs sizes
+    // its own storage exactly, so the graph itself is untouched.
+    #[uniffi::method]
+    pub fn shrink_to_fit(&self) -> Result<(), HnswError> {
+        self.ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .shrink_to_fit();
+        self.meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .shrink_to_fit();
+        *self.sketches.lock().unwrap_or_else(PoisonError::into_inner) = None;
+        self.invalidate_points();
+        Ok(())
+    }
+
+    // For memory warnings: swaps heap-held vectors for a memory-mapped reload
+    // of the dump the index was loaded from, keeping the graph and all
+    // metadata. The kernel can then drop vector pages instead of the app being
+    // killed. Returns false when there is nothing to do or the index no longer
+    // matches its dump (unsaved inserts or deletes, or a newer dump on disk).
+    #[uniffi::method]
+    pub fn evict_vectors(&self) -> Result<bool, HnswError> {
+        if self.mmapped.load(Ordering::Relaxed) {
+            return Ok(false);
+        }
+        let source = self.source.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(source) = source.as_ref() else {
+            return Ok(false);
+        };
+        let (directory, basename) = (&source.directory, &source.basename);
+        let _lock = DumpLock::shared(directory, basename)?;
+        if dump_modified(directory, basename)? != source.modified {
+            return Ok(false);
+        }
+        let inner = HnswIndexInner::load(directory.clone(), basename.clone(), self.distance, true)?;
+        let ids = inner.ids();
+
+        // The dump must hold the same points, removed ones included, since
+        // the tombstones carry over to it.
+        let mut guard = self.lock_inner()?;
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        if ids.len() != known.len() + meta.tombstones.len()
+            || !known
+                .iter()
+                .chain(&meta.tombstones)
+                .all(|id| ids.contains(id))
+        {
+            return Ok(false);
+        }
+        *guard = inner;
+        guard.set_build_options(self.build_options());
+        self.mmapped.store(true, Ordering::Relaxed);
+        drop((meta, known, guard));
+        self.invalidate_points();
+        if let Some(sketches) = self
+            .sketches
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            sketches.invalidate();
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::{Arc, PoisonError};
+
+use crate::{
+    CancellationToken, HnswError, HnswIndex, HnswIndexConfig, PointMeta, ProgressListener,
+};
+
+// Points embedded and inserted per insert_batch call.
+const MIGRATE_BATCH: usize = 256;
+// Points migrated between checkpoint saves.
+const CHECKPOINT_EVERY: usize = 4096;
+
+// Produces a point's vector under the new model from what the old index
+// kept for it, typically the text or a reference to it in the payload.
+// Called without any index lock held.
+#[uniffi::export(callback_interface)]
+pub trait MigrationEmbedder: Send + Sync {
+    fn embed(&self, id: u64, key: Option<String>, payload: Option<Vec<u8>>) -> Vec<f32>;
+}
+
+// Per-point metadata other than vectors carries over unchanged.
+fn copy_meta(from: &PointMeta, to: &mut PointMeta, ids: &[u64]) {
+    for &id in ids {
+        if let Some(key) = from.keys.get(&id) {
+            to.key_ids.insert(key.clone(), id);
+            to.keys.insert(id, key.clone());
+        }
+        if let Some(&namespace) = from.namespaces.get(&id) {
+            to.namespaces.insert(id, namespace);
+        }
+        if let Some(payload) = from.payloads.get(&id) {
+            to.payloads.insert(id, payload.clone());
+        }
+        if let Some(&document) = from.documents.get(&id) {
+            to.documents.insert(id, document);
+        }
+        if let Some(attrs) = from.attrs.get(&id) {
+            to.attrs.insert(id, attrs.clone());
+        }
+        if let Some(&timestamp) = from.timestamps.get(&id) {
+            to.timestamps.insert(id, timestamp);
+        }
+        if from.hidden.contains(&id) {
+            to.hidden.insert(id);
+        }
+    }
+    to.next_key_id = to.next_key_id.max(from.next_key_id);
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Builds a new index for another embedding model next to this one: every
+    // point is re-embedded through `embedder` and inserted with the same id
+    // and metadata. The new index is saved under `directory`/`basename` every
+    // few thousand points, on cancellation and at the end. Calling again with
+    // the same arguments resumes from the last save, picking up points added
+    // to this index since and dropping ones removed from it.
+    #[uniffi::method]
+    #[allow(clippy::too_many_arguments)]
+    pub fn migrate(
+        &self,
+        embedder: Box<dyn MigrationEmbedder>,
+        new_dimension: u32,
+        new_config: HnswIndexConfig,
+        directory: String,
+        basename: String,
+        listener: Box<dyn ProgressListener>,
+        token: Option<Arc<CancellationToken>>,
+    ) -> Result<Arc<HnswIndex>, HnswError> {
+        let config = HnswIndexConfig {
+            dimension: new_dimension,
+            ..new_config
+        };
+        let checkpoint = Path::new(&directory).join(format!("{basename}.hnsw.graph"));
+        let target = if checkpoint.exists() {
+            HnswIndex::load(directory.clone(), basename.clone(), config)?
+        } else {
+            HnswIndex::new(config)
+        };
+        target.set_auto_grow(true);
+
+        let source: HashSet<u64> = self.lock_purged()?.ids();
+        let migrated = target
+            .ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let stale: Vec<u64> = migrated.difference(&source).copied().collect();
+        if !stale.is_empty() {
+            target.remove_batch(stale)?;
+        }
+        let mut pending: Vec<u64> = source.difference(&migrated).copied().collect();
+        pending.sort_unstable();
+
+        let total = source.len() as u64;
+        let mut done = total - pending.len() as u64;
+        listener.on_progress(done, total);
+        let mut unsaved = 0;
+        for ids in pending.chunks(MIGRATE_BATCH) {
+            if let Some(token) = token.as_deref()
+                && token.is_cancelled()
+            {
+                target.save(directory, basename)?;
+                return Err(HnswError::Cancelled);
+            }
+            let inputs: Vec<(Option<String>, Option<Vec<u8>>)> = {
+                let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+                ids.iter()
+                    .map(|id| (meta.keys.get(id).cloned(), meta.payloads.get(id).cloned()))
+                    .collect()
+            };
+            let vectors = ids
+                .iter()
+                .zip(inputs)
+                .map(|(&id, (key, payload))| embedder.embed(id, key, payload))
+                .collect();
+            target.insert_batch(vectors, ids.to_vec())?;
+            {
+                let from = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+                let mut to = target.meta.lock().unwrap_or_else(PoisonError::into_inner);
+                copy_meta(&from, &mut to, ids);
+            }
+            done += ids.len() as u64;
+            listener.on_progress(done, total);
+            unsaved += ids.len();
+            if unsaved >= CHECKPOINT_EVERY {
+                target.save(directory.clone(), basename.clone())?;
+                unsaved = 0;
+            }
+        }
+        target.save(directory, basename)?;
+        Ok(Arc::new(target))
+    }
+}
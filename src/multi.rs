@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use rayon::prelude::*;
+
+use crate::{HnswError, HnswIndex, threads};
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct MultiSearchResult {
+    // Position of the index the hit came from, as passed to new().
+    pub index: u32,
+    pub id: u64,
+    pub distance: f32,
+}
+
+// Searches several indexes as one, for data sharded across indexes or kept
+// per source (photos, notes, messages). They must share the input dimension
+// and distance so their distances can be merged.
+#[derive(uniffi::Object)]
+pub struct MultiIndexSearcher {
+    indexes: Vec<Arc<HnswIndex>>,
+}
+
+#[uniffi::export]
+impl MultiIndexSearcher {
+    #[uniffi::constructor]
+    pub fn new(indexes: Vec<Arc<HnswIndex>>) -> Result<Self, HnswError> {
+        if let Some(first) = indexes.first()
+            && let Some(other) = indexes.iter().find(|index| {
+                index.config.dimension != first.config.dimension || index.distance != first.distance
+            })
+        {
+            return Err(HnswError::InvalidArgument(format!(
+                "Can't search a {}-dimensional {:?} index with a {}-dimensional {:?} one",
+                other.config.dimension, other.distance, first.config.dimension, first.distance
+            )));
+        }
+        Ok(MultiIndexSearcher { indexes })
+    }
+
+    #[uniffi::method]
+    pub fn index_count(&self) -> u32 {
+        self.indexes.len() as u32
+    }
+
+    // Searches every index on the worker pool and returns the k closest hits
+    // overall. Fails if any index's search does.
+    #[uniffi::method]
+    pub fn search_all(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<MultiSearchResult>, HnswError> {
+        let per_index: Vec<Vec<MultiSearchResult>> = threads::install(|| {
+            self.indexes
+                .par_iter()
+                .enumerate()
+                .map(|(i, index)| {
+                    let hits = index.search(query.clone(), k, ef_search)?;
+                    Ok(hits
+                        .into_iter()
+                        .map(|hit| MultiSearchResult {
+                            index: i as u32,
+                            id: hit.id,
+                            distance: hit.distance,
+                        })
+                        .collect())
+                })
+                .collect::<Result<_, HnswError>>()
+        })?;
+        let mut results: Vec<MultiSearchResult> = per_index.into_iter().flatten().collect();
+        results.sort_by(|a, b| {
+            a.distance
+                .total_cmp(&b.distance)
+                .then(a.index.cmp(&b.index))
+                .then(a.id.cmp(&b.id))
+        });
+        results.truncate(k as usize);
+        Ok(results)
+    }
+}
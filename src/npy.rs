@@ -0,0 +1,406 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, Write};
+
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::export::ExportRows;
+use crate::{HnswError, HnswIndex};
+
+// Rows handed to insert_batch at a time, so only one chunk of the file is
+// ever held in memory.
+const CHUNK_ROWS: usize = 1024;
+
+#[derive(Debug, Clone, Copy)]
+enum Dtype {
+    F32,
+    F64,
+    I32,
+    I64,
+    U32,
+    U64,
+}
+
+impl Dtype {
+    fn size(self) -> usize {
+        match self {
+            Dtype::F32 | Dtype::I32 | Dtype::U32 => 4,
+            Dtype::F64 | Dtype::I64 | Dtype::U64 => 8,
+        }
+    }
+}
+
+fn invalid(message: impl Into<String>) -> HnswError {
+    HnswError::InvalidArgument(message.into())
+}
+
+fn decode<const N: usize, T>(
+    buf: &[u8],
+    big_endian: bool,
+    from_le: fn([u8; N]) -> T,
+    from_be: fn([u8; N]) -> T,
+) -> Vec<T> {
+    buf.as_chunks::<N>()
+        .0
+        .iter()
+        .map(|&bytes| {
+            if big_endian {
+                from_be(bytes)
+            } else {
+                from_le(bytes)
+            }
+        })
+        .collect()
+}
+
+// The header is a Python dict literal, e.g.
+// {'descr': '<f4', 'fortran_order': False, 'shape': (1000, 128), }
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, HnswError> {
+    let pattern = format!("'{key}':");
+    let start = header
+        .find(&pattern)
+        .ok_or_else(|| invalid(format!("npy header is missing '{key}'")))?;
+    Ok(header[start + pattern.len()..].trim_start())
+}
+
+fn parse_descr(value: &str) -> Result<(Dtype, bool), HnswError> {
+    let descr = value
+        .strip_prefix('\'')
+        .and_then(|v| v.split('\'').next())
+        .ok_or_else(|| invalid("malformed npy dtype"))?;
+    let big_endian = match descr.chars().next() {
+        Some('>') => true,
+        Some('=') => cfg!(target_endian = "big"),
+        _ => false,
+    };
+    let dtype = match descr.trim_start_matches(['<', '>', '|', '=']) {
+        "f4" => Dtype::F32,
+        "f8" => Dtype::F64,
+        "i4" => Dtype::I32,
+        "i8" => Dtype::I64,
+        "u4" => Dtype::U32,
+        "u8" => Dtype::U64,
+        other => return Err(invalid(format!("unsupported npy dtype: {other}"))),
+    };
+    Ok((dtype, big_endian))
+}
+
+fn parse_shape(value: &str) -> Result<Vec<usize>, HnswError> {
+    let inner = value
+        .strip_prefix('(')
+        .and_then(|v| v.split(')').next())
+        .ok_or_else(|| invalid("malformed npy shape"))?;
+    inner
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| {
+            dim.parse()
+                .map_err(|_| invalid(format!("malformed npy shape: {inner}")))
+        })
+        .collect()
+}
+
+struct NpyReader<R> {
+    reader: R,
+    dtype: Dtype,
+    big_endian: bool,
+    shape: Vec<usize>,
+    buf: Vec<u8>,
+}
+
+impl<R: Read> NpyReader<R> {
+    // `len` is the size of the file or archive entry, which the header's
+    // shape is checked against before anything is allocated from it.
+    fn new(mut reader: R, len: u64) -> Result<Self, HnswError> {
+        let mut preamble = [0u8; 8];
+        reader.read_exact(&mut preamble)?;
+        if &preamble[..6] != b"\x93NUMPY" {
+            return Err(invalid("not a .npy file"));
+        }
+        let header_len = match preamble[6] {
+            1 => {
+                let mut len = [0u8; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            2 | 3 => {
+                let mut len = [0u8; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            version => return Err(invalid(format!("unsupported npy version: {version}"))),
+        };
+        let preamble_len = if preamble[6] == 1 { 10 } else { 12 };
+        let Some(data_len) = len.checked_sub(preamble_len + header_len as u64) else {
+            return Err(invalid("npy header runs past the end of the file"));
+        };
+        let mut header = vec![0u8; header_len];
+        reader.read_exact(&mut header)?;
+        let header = String::from_utf8_lossy(&header);
+
+        if header_value(&header, "fortran_order")?.starts_with("True") {
+            return Err(invalid("Fortran-ordered npy arrays are not supported"));
+        }
+        let (dtype, big_endian) = parse_descr(header_value(&header, "descr")?)?;
+        let shape = parse_shape(header_value(&header, "shape")?)?;
+        let needed = shape
+            .iter()
+            .try_fold(dtype.size(), |bytes, &dim| bytes.checked_mul(dim));
+        if !needed.is_some_and(|needed| needed as u64 <= data_len) {
+            return Err(invalid(format!(
+                "npy shape {shape:?} needs more data than the file holds"
+            )));
+        }
+        Ok(Self {
+            reader,
+            dtype,
+            big_endian,
+            shape,
+            buf: Vec::new(),
+        })
+    }
+
+    fn fill(&mut self, count: usize) -> Result<(), HnswError> {
+        let bytes = count
+            .checked_mul(self.dtype.size())
+            .ok_or_else(|| invalid("npy read size overflows"))?;
+        self.buf.resize(bytes, 0);
+        self.reader.read_exact(&mut self.buf)?;
+        Ok(())
+    }
+
+    fn read_f32(&mut self, count: usize) -> Result<Vec<f32>, HnswError> {
+        let big_endian = self.big_endian;
+        match self.dtype {
+            Dtype::F32 => {
+                self.fill(count)?;
+                Ok(decode(
+                    &self.buf,
+                    big_endian,
+                    f32::from_le_bytes,
+                    f32::from_be_bytes,
+                ))
+            }
+            Dtype::F64 => {
+                self.fill(count)?;
+                let values = decode(
+                    &self.buf,
+                    big_endian,
+                    f64::from_le_bytes,
+                    f64::from_be_bytes,
+                );
+                Ok(values.into_iter().map(|v| v as f32).collect())
+            }
+            other => Err(invalid(format!(
+                "vectors must be float32 or float64, got {other:?}"
+            ))),
+        }
+    }
+
+    // Read CHUNK_ROWS at a time, like vectors, so the raw bytes are never
+    // held in one buffer next to the decoded ids.
+    fn read_ids(&mut self) -> Result<Vec<u64>, HnswError> {
+        let [count] = self.shape[..] else {
+            return Err(invalid(format!(
+                "ids must be a 1-d array, got shape {:?}",
+                self.shape
+            )));
+        };
+        let mut ids = Vec::with_capacity(count.min(CHUNK_ROWS));
+        while ids.len() < count {
+            let chunk = CHUNK_ROWS.min(count - ids.len());
+            ids.extend(self.read_id_chunk(chunk)?);
+        }
+        Ok(ids)
+    }
+
+    fn read_id_chunk(&mut self, count: usize) -> Result<Vec<u64>, HnswError> {
+        let big_endian = self.big_endian;
+        let negative = |id: i64| invalid(format!("negative id: {id}"));
+        match self.dtype {
+            Dtype::U64 => {
+                self.fill(count)?;
+                Ok(decode(
+                    &self.buf,
+                    big_endian,
+                    u64::from_le_bytes,
+                    u64::from_be_bytes,
+                ))
+            }
+            Dtype::U32 => {
+                self.fill(count)?;
+                let ids = decode(
+                    &self.buf,
+                    big_endian,
+                    u32::from_le_bytes,
+                    u32::from_be_bytes,
+                );
+                Ok(ids.into_iter().map(u64::from).collect())
+            }
+            Dtype::I64 => {
+                self.fill(count)?;
+                decode(
+                    &self.buf,
+                    big_endian,
+                    i64::from_le_bytes,
+                    i64::from_be_bytes,
+                )
+                .into_iter()
+                .map(|id| u64::try_from(id).map_err(|_| negative(id)))
+                .collect()
+            }
+            Dtype::I32 => {
+                self.fill(count)?;
+                decode(
+                    &self.buf,
+                    big_endian,
+                    i32::from_le_bytes,
+                    i32::from_be_bytes,
+                )
+                .into_iter()
+                .map(|id| u64::try_from(id).map_err(|_| negative(id.into())))
+                .collect()
+            }
+            other => Err(invalid(format!("ids must be integers, got {other:?}"))),
+        }
+    }
+}
+
+// The entry and its uncompressed size.
+fn npz_entry<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    name: &str,
+) -> Result<(impl Read, u64), HnswError> {
+    let entry = archive
+        .by_name(&format!("{name}.npy"))
+        .map_err(|_| invalid(format!("npz archive has no array named '{name}'")))?;
+    let len = entry.size();
+    Ok((entry, len))
+}
+
+// Format 1.0 header, padded so the data starts 64-byte aligned.
+fn npy_header(descr: &str, shape: &str) -> Vec<u8> {
+    let mut header = format!("{{'descr': '{descr}', 'fortran_order': False, 'shape': {shape}, }}");
+    // Magic and version (8 bytes), header length (2) and the final newline.
+    let padded = (11 + header.len()).next_multiple_of(64) - 11;
+    header.push_str(&" ".repeat(padded - header.len()));
+    header.push('\n');
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend(header.into_bytes());
+    bytes
+}
+
+fn zip_error(e: impl ToString) -> HnswError {
+    HnswError::IoError(e.to_string())
+}
+
+// The `ids` and `vectors` arrays np.load reads back from an .npz, stored
+// uncompressed since float vectors barely deflate.
+pub(crate) fn write_npz(path: &str, dimension: u32, rows: &ExportRows) -> Result<(), HnswError> {
+    let mut archive = ZipWriter::new(BufWriter::new(File::create(path)?));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+    let count = rows.ids().len();
+
+    archive.start_file("ids.npy", options).map_err(zip_error)?;
+    archive.write_all(&npy_header("<u8", &format!("({count},)")))?;
+    for id in rows.ids() {
+        archive.write_all(&id.to_le_bytes())?;
+    }
+
+    archive
+        .start_file("vectors.npy", options)
+        .map_err(zip_error)?;
+    let shape = format!("({count}, {dimension})");
+    archive.write_all(&npy_header("<f4", &shape))?;
+    for row in rows.chunks(CHUNK_ROWS).flatten() {
+        let bytes: Vec<u8> = row.vector.iter().flat_map(|x| x.to_le_bytes()).collect();
+        archive.write_all(&bytes)?;
+    }
+    archive.finish().map_err(zip_error)?.flush()?;
+    Ok(())
+}
+
+impl HnswIndex {
+    // Rows already inserted stay in the index if a later chunk fails.
+    fn import_rows<R: Read>(
+        &self,
+        vectors: &mut NpyReader<R>,
+        ids: Option<&[u64]>,
+        id_offset: u64,
+    ) -> Result<u64, HnswError> {
+        let [rows, cols] = vectors.shape[..] else {
+            return Err(invalid(format!(
+                "vectors must be a 2-d array, got shape {:?}",
+                vectors.shape
+            )));
+        };
+        self.check_dimension(cols)?;
+        if let Some(ids) = ids
+            && ids.len() != rows
+        {
+            return Err(invalid(format!(
+                "ids array has {} entries, expected {rows}",
+                ids.len()
+            )));
+        }
+        let mut start = 0;
+        while start < rows {
+            let end = rows.min(start + CHUNK_ROWS);
+            let data = vectors
+                .read_f32((end - start) * cols)?
+                .chunks_exact(cols)
+                .map(<[f32]>::to_vec)
+                .collect();
+            let chunk_ids = (start..end)
+                .map(|row| {
+                    let id = ids.map_or(row as u64, |ids| ids[row]);
+                    id.checked_add(id_offset)
+                        .ok_or_else(|| invalid(format!("id {id} + offset {id_offset} overflows")))
+                })
+                .collect::<Result<Vec<u64>, HnswError>>()?;
+            self.insert_batch(data, chunk_ids)?;
+            start = end;
+        }
+        Ok(rows as u64)
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Row i is inserted with id `id_offset + i`. Returns the number of rows.
+    #[uniffi::method]
+    pub fn import_npy(&self, path: String, id_offset: u64) -> Result<u64, HnswError> {
+        self.check_writable()?;
+        let file = File::open(path)?;
+        let len = file.metadata()?.len();
+        let mut vectors = NpyReader::new(BufReader::new(file), len)?;
+        self.import_rows(&mut vectors, None, id_offset)
+    }
+
+    // Reads arrays written by np.savez / np.savez_compressed. Without
+    // `ids_name` rows are numbered from zero; `id_offset` is added either way.
+    #[uniffi::method]
+    pub fn import_npz(
+        &self,
+        path: String,
+        vectors_name: String,
+        ids_name: Option<String>,
+        id_offset: u64,
+    ) -> Result<u64, HnswError> {
+        self.check_writable()?;
+        let mut archive = ZipArchive::new(BufReader::new(File::open(path)?))
+            .map_err(|e| HnswError::IoError(e.to_string()))?;
+        let ids = match ids_name {
+            Some(name) => {
+                let (entry, len) = npz_entry(&mut archive, &name)?;
+                Some(NpyReader::new(entry, len)?.read_ids()?)
+            }
+            None => None,
+        };
+        let (entry, len) = npz_entry(&mut archive, &vectors_name)?;
+        let mut vectors = NpyReader::new(BufReader::new(entry), len)?;
+        self.import_rows(&mut vectors, ids.as_deref(), id_offset)
+    }
+}
@@ -0,0 +1,73 @@
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, PoisonError};
+use std::thread;
+
+use crate::{HnswError, HnswIndex};
+
+// Callbacks for changes to an index. They run in order on a thread of their
+// own, never on the thread that made the change, so they may call back into
+// the index and a slow observer doesn't hold up writers.
+#[uniffi::export(callback_interface)]
+pub trait IndexObserver: Send + Sync {
+    // Once per insert call, with every id it added.
+    fn on_insert(&self, ids: Vec<u64>);
+    fn on_remove(&self, ids: Vec<u64>);
+    fn on_save(&self, directory: String, basename: String);
+    // The index passed 90% of its capacity and auto-grow is off.
+    fn on_capacity_warning(&self, len: u64, capacity: u64);
+}
+
+pub(crate) enum Event {
+    Insert(Vec<u64>),
+    Remove(Vec<u64>),
+    Save(String, String),
+    CapacityWarning(u64, u64),
+}
+
+// Dropping the sender lets the thread finish the queued events and exit.
+pub(crate) struct Notifier(Sender<Event>);
+
+impl Notifier {
+    fn spawn(observer: Arc<dyn IndexObserver>) -> Result<Self, HnswError> {
+        let (sender, receiver) = mpsc::channel();
+        thread::Builder::new()
+            .name("hnsw-observer".to_string())
+            .spawn(move || {
+                for event in receiver {
+                    match event {
+                        Event::Insert(ids) => observer.on_insert(ids),
+                        Event::Remove(ids) => observer.on_remove(ids),
+                        Event::Save(directory, basename) => observer.on_save(directory, basename),
+                        Event::CapacityWarning(len, capacity) => {
+                            observer.on_capacity_warning(len, capacity)
+                        }
+                    }
+                }
+            })?;
+        Ok(Notifier(sender))
+    }
+}
+
+impl HnswIndex {
+    // Safe to call with any other lock held: the observer lock comes last in
+    // the lock order and sending never blocks.
+    pub(crate) fn notify(&self, event: impl FnOnce() -> Event) {
+        if let Some(notifier) = &*self.observer.lock().unwrap_or_else(PoisonError::into_inner) {
+            let _ = notifier.0.send(event());
+        }
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Replaces any previous observer, which still receives the events already
+    // queued for it. None removes it.
+    #[uniffi::method]
+    pub fn set_observer(&self, observer: Option<Box<dyn IndexObserver>>) -> Result<(), HnswError> {
+        let notifier = observer
+            .map(|o| Notifier::spawn(Arc::from(o)))
+            .transpose()?;
+        *self.observer.lock().unwrap_or_else(PoisonError::into_inner) = notifier;
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::PoisonError;
+
+use crate::{DistanceType, HnswError, HnswIndex, SearchResult};
+
+#[derive(Debug, Clone, Copy, Default, uniffi::Record)]
+pub struct SearchOptions {
+    #[uniffi(default = false)]
+    pub include_rank: bool,
+    #[uniffi(default = false)]
+    pub include_score: bool,
+    #[uniffi(default = false)]
+    pub include_payload: bool,
+}
+
+// Higher is more similar. Cosine and dot map back to the raw similarity
+// (hnsw_rs reports both as 1 - x); L1/L2 are squashed into (0, 1].
+pub(crate) fn similarity(distance_type: DistanceType, distance: f32) -> f32 {
+    match distance_type {
+        DistanceType::Cosine | DistanceType::Dot => 1.0 - distance,
+        DistanceType::L2 | DistanceType::L1 => 1.0 / (1.0 + distance),
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    #[uniffi::method]
+    pub fn insert_with_payload(
+        &self,
+        data: Vec<f32>,
+        id: u64,
+        payload: Vec<u8>,
+    ) -> Result<(), HnswError> {
+        self.insert_with(data, id, |meta| {
+            meta.payloads.insert(id, payload);
+        })
+    }
+
+    #[uniffi::method]
+    pub fn set_payload(&self, id: u64, payload: Option<Vec<u8>>) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        match payload {
+            Some(payload) => meta.payloads.insert(id, payload),
+            None => meta.payloads.remove(&id),
+        };
+        meta.touch(&[id]);
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn get_payload(&self, id: u64) -> Result<Option<Vec<u8>>, HnswError> {
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(meta.payloads.get(&id).cloned())
+    }
+
+    // Ranks are 1-based, best match first.
+    #[uniffi::method]
+    pub fn search_with_options(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+        options: SearchOptions,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let mut results = self.search(query, k, ef_search)?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        for (i, result) in results.iter_mut().enumerate() {
+            if options.include_rank {
+                result.rank = Some(i as u32 + 1);
+            }
+            if options.include_score {
+                result.score = Some(similarity(self.distance, result.distance));
+            }
+            if options.include_payload {
+                result.payload = meta.payloads.get(&result.id).cloned();
+            }
+        }
+        Ok(results)
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError};
+
+use hnsw_rs::hnsw::{Hnsw, Point, PointId};
+use hnsw_rs::prelude::*;
+
+use crate::{HnswIndex, HnswIndexInner, graph_points};
+
+// hnsw_rs has no public lookup by PointId, so the points are gathered into
+// one map per graph and shared by every traversal until the graph changes.
+pub(crate) struct PointMap {
+    // Point count of the graph the map was built from; inserts change it.
+    nb_point: usize,
+    points: HashMap<PointId, Arc<Point<'static, f32>>>,
+    // Where each id sits. Ids are unique in a graph: removing one leaves a
+    // tombstone that is purged before the id can be inserted again.
+    ids: HashMap<u64, PointId>,
+}
+
+impl PointMap {
+    fn build<D>(hnsw: &Hnsw<'static, f32, D>) -> Self
+    where
+        D: Distance<f32> + Send + Sync,
+    {
+        let points: HashMap<PointId, Arc<Point<'static, f32>>> = graph_points(hnsw)
+            .map(|point| (point.get_point_id(), point))
+            .collect();
+        let ids = points
+            .iter()
+            .map(|(&p_id, point)| (point.get_origin_id() as u64, p_id))
+            .collect();
+        PointMap {
+            nb_point: hnsw.get_nb_point(),
+            points,
+            ids,
+        }
+    }
+
+    pub(crate) fn get(&self, p_id: &PointId) -> Option<&Arc<Point<'static, f32>>> {
+        self.points.get(p_id)
+    }
+
+    // The point holding `id`, tombstones included.
+    pub(crate) fn point(&self, id: u64) -> Option<Arc<Point<'static, f32>>> {
+        self.get(self.ids.get(&id)?).cloned()
+    }
+
+    pub(crate) fn vector(&self, id: u64) -> Option<Vec<f32>> {
+        Some(self.point(id)?.get_v().to_vec())
+    }
+
+    pub(crate) fn heap_bytes(&self) -> u64 {
+        // PointId, Arc and a control byte per slot, then id, PointId and a
+        // control byte.
+        (self.points.capacity() * 17 + self.ids.capacity() * 17) as u64
+    }
+}
+
+impl HnswIndex {
+    // The map for `guard`, built on first use after an insert or a new graph.
+    // Building walks every point, so it costs time linear in the index size.
+    pub(crate) fn point_map(&self, guard: &HnswIndexInner) -> Arc<PointMap> {
+        let mut cached = self.points.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(map) = cached.as_ref()
+            && map.nb_point == guard.nb_point()
+        {
+            return Arc::clone(map);
+        }
+        let map = Arc::new(match guard {
+            HnswIndexInner::L2(inner) => PointMap::build(&inner.hnsw),
+            HnswIndexInner::Cosine(inner) => PointMap::build(&inner.hnsw),
+            HnswIndexInner::Dot(inner) => PointMap::build(&inner.hnsw),
+            HnswIndexInner::L1(inner) => PointMap::build(&inner.hnsw),
+        });
+        *cached = Some(Arc::clone(&map));
+        map
+    }
+
+    // For anything that swaps the graph out: the map would otherwise keep the
+    // old points alive, and may not notice when the point count is the same.
+    pub(crate) fn invalidate_points(&self) {
+        *self.points.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
@@ -0,0 +1,459 @@
+use std::fs;
+use std::mem::ManuallyDrop;
+use std::path::{Path, PathBuf};
+use std::ptr::NonNull;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use hnsw_rs::api::AnnT;
+use hnsw_rs::hnsw::Hnsw;
+use hnsw_rs::hnswio::HnswIo;
+use hnsw_rs::prelude::*;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    DistanceType, HnswError, HnswIndexConfig, SearchResult, check_finite, check_metric, guarded,
+    normalize_vector, rename_dump,
+};
+
+const KMEANS_ITERATIONS: usize = 25;
+const RERANK_FACTOR: usize = 4;
+
+// Per-subspace contribution; summing these over all subspaces and applying
+// `finish` yields the same value as the full-vector metric.
+fn partial(metric: DistanceType, a: &[f32], b: &[f32]) -> f32 {
+    let pairs = a.iter().zip(b);
+    match metric {
+        DistanceType::L2 | DistanceType::Cosine => pairs.map(|(x, y)| (x - y) * (x - y)).sum(),
+        DistanceType::L1 => pairs.map(|(x, y)| (x - y).abs()).sum(),
+        DistanceType::Dot => pairs.map(|(x, y)| x * y).sum(),
+    }
+}
+
+fn finish(metric: DistanceType, sum: f32) -> f32 {
+    match metric {
+        DistanceType::L2 => sum.sqrt(),
+        DistanceType::L1 => sum,
+        DistanceType::Dot => (1.0 - sum).max(0.0),
+        // Cosine vectors are normalized before training and encoding, so
+        // ||a - b||^2 = 2 - 2cos.
+        DistanceType::Cosine => sum / 2.0,
+    }
+}
+
+fn kmeans(points: &[&[f32]], ksub: usize, dsub: usize) -> Vec<f32> {
+    let n = points.len();
+    let mut centroids: Vec<f32> = (0..ksub)
+        .flat_map(|c| points[c * n / ksub % n].iter().copied())
+        .collect();
+    let mut assignment = vec![0usize; n];
+    for _ in 0..KMEANS_ITERATIONS {
+        assignment.par_iter_mut().enumerate().for_each(|(i, slot)| {
+            *slot = (0..ksub)
+                .map(|c| {
+                    let centroid = &centroids[c * dsub..(c + 1) * dsub];
+                    (c, partial(DistanceType::L2, points[i], centroid))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map_or(0, |(c, _)| c);
+        });
+        let mut sums = vec![0.0f32; ksub * dsub];
+        let mut counts = vec![0usize; ksub];
+        for (point, &c) in points.iter().zip(&assignment) {
+            counts[c] += 1;
+            for (s, &x) in sums[c * dsub..(c + 1) * dsub].iter_mut().zip(point.iter()) {
+                *s += x;
+            }
+        }
+        for c in 0..ksub {
+            // Empty clusters keep their previous centroid.
+            if counts[c] == 0 {
+                continue;
+            }
+            for d in 0..dsub {
+                centroids[c * dsub + d] = sums[c * dsub + d] / counts[c] as f32;
+            }
+        }
+    }
+    centroids
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PqCodebook {
+    metric: DistanceType,
+    m: usize,
+    ksub: usize,
+    dsub: usize,
+    centroids: Vec<f32>,
+    // Symmetric centroid-to-centroid table, m * ksub * ksub entries, used for
+    // code-to-code distances while building and traversing the graph.
+    sdc: Vec<f32>,
+}
+
+impl PqCodebook {
+    fn train(samples: &[Vec<f32>], metric: DistanceType, m: usize, nbits: u32) -> Self {
+        let dimension = samples[0].len();
+        let (ksub, dsub) = (1usize << nbits, dimension / m);
+        let centroids: Vec<f32> = (0..m)
+            .into_par_iter()
+            .map(|sub| {
+                let points: Vec<&[f32]> = samples
+                    .iter()
+                    .map(|v| &v[sub * dsub..(sub + 1) * dsub])
+                    .collect();
+                kmeans(&points, ksub, dsub)
+            })
+            .collect::<Vec<_>>()
+            .concat();
+        let mut codebook = PqCodebook {
+            metric,
+            m,
+            ksub,
+            dsub,
+            centroids,
+            sdc: Vec::new(),
+        };
+        codebook.sdc = (0..m)
+            .flat_map(|sub| {
+                let codebook = &codebook;
+                (0..ksub).flat_map(move |a| {
+                    (0..ksub).map(move |b| {
+                        partial(metric, codebook.centroid(sub, a), codebook.centroid(sub, b))
+                    })
+                })
+            })
+            .collect();
+        codebook
+    }
+
+    fn centroid(&self, sub: usize, c: usize) -> &[f32] {
+        let start = (sub * self.ksub + c) * self.dsub;
+        &self.centroids[start..start + self.dsub]
+    }
+
+    fn encode(&self, v: &[f32]) -> Vec<u8> {
+        (0..self.m)
+            .map(|sub| {
+                let chunk = &v[sub * self.dsub..(sub + 1) * self.dsub];
+                (0..self.ksub)
+                    .map(|c| (c, partial(DistanceType::L2, chunk, self.centroid(sub, c))))
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .map_or(0, |(c, _)| c as u8)
+            })
+            .collect()
+    }
+
+    // Asymmetric lookup table: the exact query against every centroid.
+    fn adc_table(&self, query: &[f32]) -> Vec<f32> {
+        (0..self.m)
+            .flat_map(|sub| {
+                let chunk = &query[sub * self.dsub..(sub + 1) * self.dsub];
+                (0..self.ksub).map(move |c| partial(self.metric, chunk, self.centroid(sub, c)))
+            })
+            .collect()
+    }
+
+    fn adc_distance(&self, table: &[f32], code: &[u8]) -> f32 {
+        let sum = code
+            .iter()
+            .enumerate()
+            .map(|(sub, &c)| table[sub * self.ksub + c as usize])
+            .sum();
+        finish(self.metric, sum)
+    }
+}
+
+#[derive(Clone)]
+struct DistPq {
+    codebook: Arc<PqCodebook>,
+}
+
+impl Distance<u8> for DistPq {
+    fn eval(&self, va: &[u8], vb: &[u8]) -> f32 {
+        let cb = &self.codebook;
+        let sum = va
+            .iter()
+            .zip(vb)
+            .enumerate()
+            .map(|(sub, (&a, &b))| cb.sdc[(sub * cb.ksub + a as usize) * cb.ksub + b as usize])
+            .sum();
+        finish(cb.metric, sum)
+    }
+}
+
+struct PqGraph {
+    hnsw: ManuallyDrop<Hnsw<'static, u8, DistPq>>,
+    io_ptr: Option<NonNull<HnswIo>>,
+}
+
+impl Drop for PqGraph {
+    fn drop(&mut self) {
+        unsafe {
+            ManuallyDrop::drop(&mut self.hnsw);
+            if let Some(ptr) = self.io_ptr.take() {
+                drop(Box::from_raw(ptr.as_ptr()));
+            }
+        }
+    }
+}
+
+unsafe impl Send for PqGraph {}
+unsafe impl Sync for PqGraph {}
+
+struct PqState {
+    codebook: Arc<PqCodebook>,
+    graph: PqGraph,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PqSidecar {
+    config: HnswIndexConfig,
+    codebook: Option<PqCodebook>,
+}
+
+fn sidecar_path(directory: &str, basename: &str) -> PathBuf {
+    Path::new(directory).join(format!("{basename}.hnsw.pq"))
+}
+
+#[derive(uniffi::Object)]
+pub struct HnswPqIndex {
+    config: HnswIndexConfig,
+    state: Mutex<Option<PqState>>,
+}
+
+impl HnswPqIndex {
+    fn check_dimension(&self, len: usize) -> Result<(), HnswError> {
+        if len != self.config.dimension as usize {
+            return Err(HnswError::DimensionMismatch {
+                expected: self.config.dimension,
+                got: len as u32,
+            });
+        }
+        Ok(())
+    }
+
+    fn prepare(&self, mut vector: Vec<f32>, index: usize) -> Result<Vec<f32>, HnswError> {
+        self.check_dimension(vector.len())?;
+        check_finite(&vector, index)?;
+        check_metric(&vector, self.config.distance, index)?;
+        // The cosine codebook works on unit vectors whatever the config says.
+        if self.config.normalize_vectors || self.config.distance == DistanceType::Cosine {
+            normalize_vector(&mut vector)?;
+        }
+        Ok(vector)
+    }
+}
+
+#[uniffi::export]
+impl HnswPqIndex {
+    #[uniffi::constructor]
+    pub fn new(config: HnswIndexConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(None),
+        }
+    }
+
+    #[uniffi::constructor]
+    pub fn load(directory: String, basename: String) -> Result<Self, HnswError> {
+        let bytes = fs::read(sidecar_path(&directory, &basename))?;
+        let sidecar: PqSidecar =
+            bincode::deserialize(&bytes).map_err(|e| HnswError::Corrupted(e.to_string()))?;
+        let Some(codebook) = sidecar.codebook else {
+            return Ok(Self::new(sidecar.config));
+        };
+        let codebook = Arc::new(codebook);
+        let io = Box::new(HnswIo::new(Path::new(&directory), &basename));
+        let io_ptr = Box::into_raw(io);
+        let dist = DistPq {
+            codebook: Arc::clone(&codebook),
+        };
+        let hnsw: Hnsw<'static, u8, DistPq> = unsafe {
+            (*io_ptr)
+                .load_hnsw_with_dist(dist)
+                .map_err(|e| HnswError::ReloadError(e.to_string()))?
+        };
+        Ok(Self {
+            config: sidecar.config,
+            state: Mutex::new(Some(PqState {
+                codebook,
+                graph: PqGraph {
+                    hnsw: ManuallyDrop::new(hnsw),
+                    io_ptr: NonNull::new(io_ptr),
+                },
+            })),
+        })
+    }
+
+    #[uniffi::method]
+    pub fn train_pq(
+        &self,
+        sample_vectors: Vec<Vec<f32>>,
+        m: u32,
+        nbits: u32,
+    ) -> Result<(), HnswError> {
+        if sample_vectors.is_empty() {
+            return Err(HnswError::InvalidArgument(
+                "Training requires at least one sample".to_string(),
+            ));
+        }
+        if m == 0 || !self.config.dimension.is_multiple_of(m) {
+            return Err(HnswError::InvalidArgument(format!(
+                "m ({m}) must be a non-zero divisor of the dimension ({})",
+                self.config.dimension
+            )));
+        }
+        if !(1..=8).contains(&nbits) {
+            return Err(HnswError::InvalidArgument(format!(
+                "nbits must be between 1 and 8, got {nbits}"
+            )));
+        }
+        let sample_vectors: Vec<Vec<f32>> = sample_vectors
+            .into_iter()
+            .enumerate()
+            .map(|(i, sample)| self.prepare(sample, i))
+            .collect::<Result<_, _>>()?;
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.is_some() {
+            return Err(HnswError::InvalidArgument(
+                "PQ codebooks are already trained".to_string(),
+            ));
+        }
+        let config = self.config;
+        let codebook = Arc::new(PqCodebook::train(
+            &sample_vectors,
+            config.distance,
+            m as usize,
+            nbits,
+        ));
+        let hnsw = Hnsw::new(
+            config.max_nb_connection as usize,
+            config.max_elements as usize,
+            config.max_layer as usize,
+            config.ef_construction as usize,
+            DistPq {
+                codebook: Arc::clone(&codebook),
+            },
+        );
+        *state = Some(PqState {
+            codebook,
+            graph: PqGraph {
+                hnsw: ManuallyDrop::new(hnsw),
+                io_ptr: None,
+            },
+        });
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn is_trained(&self) -> Result<bool, HnswError> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(state.is_some())
+    }
+
+    #[uniffi::method]
+    pub fn insert(&self, data: Vec<f32>, id: u64) -> Result<(), HnswError> {
+        self.insert_batch(vec![data], vec![id])
+    }
+
+    #[uniffi::method]
+    pub fn insert_batch(&self, data: Vec<Vec<f32>>, ids: Vec<u64>) -> Result<(), HnswError> {
+        if data.len() != ids.len() {
+            return Err(HnswError::LengthMismatch {
+                vectors: data.len() as u64,
+                ids: ids.len() as u64,
+            });
+        }
+        let data: Vec<Vec<f32>> = data
+            .into_iter()
+            .enumerate()
+            .map(|(i, vec)| self.prepare(vec, i))
+            .collect::<Result<_, _>>()?;
+        let mut guard = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let state = guard.as_mut().ok_or(HnswError::NotTrained)?;
+        let codes: Vec<Vec<u8>> = data.par_iter().map(|v| state.codebook.encode(v)).collect();
+        let pairs: Vec<(&Vec<u8>, usize)> = codes
+            .iter()
+            .zip(ids.iter().map(|&id| id as usize))
+            .collect();
+        guarded("pq_insert_batch", || {
+            crate::threads::install(|| state.graph.hnsw.parallel_insert(&pairs))
+        })?;
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn search(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let query = self.prepare(query, 0)?;
+        let guard = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let state = guard.as_ref().ok_or(HnswError::NotTrained)?;
+        let k = k as usize;
+        let candidates = k * RERANK_FACTOR;
+        let code = state.codebook.encode(&query);
+        let table = state.codebook.adc_table(&query);
+        let ef = (ef_search as usize).max(candidates);
+        let hits = guarded("pq_search", || {
+            state.graph.hnsw.search(&code, candidates, ef)
+        })?;
+        // Each hit's code is read back from its point in the graph.
+        let indexation = state.graph.hnsw.get_point_indexation();
+        let mut results: Vec<SearchResult> = hits
+            .into_iter()
+            .map(|n| {
+                let distance = indexation
+                    .get_point_data(&n.p_id)
+                    .map_or(n.distance, |c| state.codebook.adc_distance(&table, &c));
+                SearchResult::new(n.d_id as u64, distance)
+            })
+            .collect();
+        results.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        results.truncate(k);
+        Ok(results)
+    }
+
+    #[uniffi::method]
+    pub fn len(&self) -> Result<u64, HnswError> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(state
+            .as_ref()
+            .map_or(0, |state| state.graph.hnsw.get_nb_point() as u64))
+    }
+
+    #[uniffi::method]
+    pub fn is_empty(&self) -> Result<bool, HnswError> {
+        Ok(self.len()? == 0)
+    }
+
+    #[uniffi::method]
+    pub fn get_dimension(&self) -> u32 {
+        self.config.dimension
+    }
+
+    #[uniffi::method]
+    pub fn save(&self, directory: String, basename: String) -> Result<(), HnswError> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        // As in HnswIndex::save: dump under a staging name and rename the
+        // files file_dump reports into place.
+        if let Some(state) = state.as_ref() {
+            let dumped = state
+                .graph
+                .hnsw
+                .file_dump(Path::new(&directory), &format!("{basename}.staging"))
+                .map_err(|e| HnswError::DumpError(e.to_string()))?;
+            rename_dump(&directory, &dumped, &basename)?;
+        }
+        let sidecar = PqSidecar {
+            config: self.config,
+            codebook: state.as_ref().map(|state| (*state.codebook).clone()),
+        };
+        let bytes =
+            bincode::serialize(&sidecar).map_err(|e| HnswError::DumpError(e.to_string()))?;
+        fs::write(sidecar_path(&directory, &basename), bytes)?;
+        Ok(())
+    }
+}
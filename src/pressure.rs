@@ -0,0 +1,139 @@
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+
+use crate::{HnswError, HnswIndex};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum MemoryPressureLevel {
+    // Frees caches and spare capacity; nothing needs recomputing except the
+    // binary sketches on the next search_bq.
+    Warning,
+    // Also swaps heap-held vectors for the mapped dump, see evict_vectors.
+    Critical,
+}
+
+// Indexes registered with set_memory_pressure_handler.
+static HANDLERS: Mutex<Vec<Weak<HnswIndex>>> = Mutex::new(Vec::new());
+
+fn registered() -> Vec<Arc<HnswIndex>> {
+    let mut handlers = HANDLERS.lock().unwrap_or_else(PoisonError::into_inner);
+    handlers.retain(|index| index.strong_count() > 0);
+    handlers.iter().filter_map(Weak::upgrade).collect()
+}
+
+// Memory pressure notifications from libdispatch, so registered indexes
+// respond without the app forwarding its memory warnings.
+#[cfg(target_vendor = "apple")]
+mod dispatch {
+    use std::ffi::c_void;
+    use std::sync::Once;
+
+    use super::{MemoryPressureLevel, notify_memory_pressure};
+
+    #[repr(C)]
+    struct Object {
+        _private: [u8; 0],
+    }
+
+    const DISPATCH_MEMORYPRESSURE_WARN: usize = 0x02;
+    const DISPATCH_MEMORYPRESSURE_CRITICAL: usize = 0x04;
+    const DISPATCH_QUEUE_PRIORITY_BACKGROUND: isize = -(1 << 15);
+
+    unsafe extern "C" {
+        static _dispatch_source_type_memorypressure: Object;
+        fn dispatch_get_global_queue(identifier: isize, flags: usize) -> *mut Object;
+        fn dispatch_source_create(
+            kind: *const Object,
+            handle: usize,
+            mask: usize,
+            queue: *mut Object,
+        ) -> *mut Object;
+        fn dispatch_set_context(object: *mut Object, context: *mut c_void);
+        fn dispatch_source_set_event_handler_f(
+            source: *mut Object,
+            handler: extern "C" fn(*mut c_void),
+        );
+        fn dispatch_source_get_data(source: *mut Object) -> usize;
+        fn dispatch_resume(object: *mut Object);
+    }
+
+    // The source is passed as its own context.
+    extern "C" fn on_pressure(source: *mut c_void) {
+        let flags = unsafe { dispatch_source_get_data(source.cast()) };
+        let level = if flags & DISPATCH_MEMORYPRESSURE_CRITICAL != 0 {
+            MemoryPressureLevel::Critical
+        } else {
+            MemoryPressureLevel::Warning
+        };
+        let _ = notify_memory_pressure(level);
+    }
+
+    // Started once and never cancelled; with nothing registered it costs a
+    // call that finds an empty list.
+    pub(super) fn start() {
+        static START: Once = Once::new();
+        START.call_once(|| unsafe {
+            let queue = dispatch_get_global_queue(DISPATCH_QUEUE_PRIORITY_BACKGROUND, 0);
+            let source = dispatch_source_create(
+                &_dispatch_source_type_memorypressure,
+                0,
+                DISPATCH_MEMORYPRESSURE_WARN | DISPATCH_MEMORYPRESSURE_CRITICAL,
+                queue,
+            );
+            if source.is_null() {
+                return;
+            }
+            dispatch_set_context(source, source.cast());
+            dispatch_source_set_event_handler_f(source, on_pressure);
+            dispatch_resume(source);
+        });
+    }
+}
+
+#[cfg(not(target_vendor = "apple"))]
+mod dispatch {
+    pub(super) fn start() {}
+}
+
+// Runs handle_memory_pressure on every registered index, e.g. from Android's
+// onTrimMemory. On Apple platforms it already runs on the system's memory
+// pressure events. Returns the bytes freed across all of them.
+#[uniffi::export]
+pub fn notify_memory_pressure(level: MemoryPressureLevel) -> Result<u64, HnswError> {
+    let mut freed = 0;
+    for index in registered() {
+        freed += index.handle_memory_pressure(level)?;
+    }
+    Ok(freed)
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // For memory warnings: clears the query cache, drops the binary sketches
+    // and releases spare capacity, and at Critical also moves vectors of an
+    // index loaded from an unchanged dump onto the mapped file. Returns how
+    // much memory_footprint dropped; the query cache isn't part of that
+    // estimate.
+    #[uniffi::method]
+    pub fn handle_memory_pressure(&self, level: MemoryPressureLevel) -> Result<u64, HnswError> {
+        let before = self.memory_footprint()?;
+        self.clear_query_cache();
+        self.shrink_to_fit()?;
+        if level == MemoryPressureLevel::Critical {
+            self.evict_vectors()?;
+        }
+        Ok(before.saturating_sub(self.memory_footprint()?))
+    }
+
+    // Registers the index to handle memory pressure by itself: on Apple
+    // platforms from the system's memory pressure events, elsewhere through
+    // notify_memory_pressure. The registration doesn't keep the index alive.
+    #[uniffi::method]
+    pub fn set_memory_pressure_handler(self: Arc<Self>, enabled: bool) {
+        let mut handlers = HANDLERS.lock().unwrap_or_else(PoisonError::into_inner);
+        handlers.retain(|index| index.strong_count() > 0 && !std::ptr::eq(index.as_ptr(), &*self));
+        if enabled {
+            handlers.push(Arc::downgrade(&self));
+            dispatch::start();
+        }
+    }
+}
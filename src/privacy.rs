@@ -0,0 +1,100 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::PoisonError;
+
+use crate::{HnswError, HnswIndex};
+
+// splitmix64 seeded from the per-process keys std uses for HashMap, so noise
+// differs between vectors and runs without pulling in a rand dependency.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn from_entropy() -> Self {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(0);
+        SplitMix64(hasher.finish())
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in (0, 1].
+    fn unit(&mut self) -> f64 {
+        ((self.next() >> 11) + 1) as f64 / (1u64 << 53) as f64
+    }
+
+    fn gaussian(&mut self) -> f64 {
+        (-2.0 * self.unit().ln()).sqrt() * (std::f64::consts::TAU * self.unit()).cos()
+    }
+}
+
+// Noise with density proportional to exp(-epsilon * |z|), the usual
+// mechanism for metric differential privacy on embeddings: a uniformly random
+// direction scaled by a Gamma(dimension, 1 / epsilon) length. The expected
+// length is dimension / epsilon.
+fn add_noise(vector: &mut [f32], epsilon: f64) {
+    let mut rng = SplitMix64::from_entropy();
+    let direction: Vec<f64> = vector.iter().map(|_| rng.gaussian()).collect();
+    let norm = direction.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return;
+    }
+    let length: f64 = (0..vector.len()).map(|_| -rng.unit().ln()).sum::<f64>() / epsilon;
+    for (x, d) in vector.iter_mut().zip(direction) {
+        *x += (d / norm * length) as f32;
+    }
+}
+
+impl HnswIndex {
+    // Applied to vectors as they are stored, after normalization, which is
+    // then redone so normalizing and Dot indexes keep unit vectors.
+    pub(crate) fn perturb(&self, vector: &mut [f32]) {
+        let epsilon = self
+            .meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .privacy_epsilon;
+        if let Some(epsilon) = epsilon {
+            add_noise(vector, epsilon);
+            self.restore_norm(vector);
+        }
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Opt-in privacy for indexes that leave the device: every vector inserted
+    // from now on is stored with random noise, so saved files, bundles and
+    // change sets never hold the exact embeddings. Smaller epsilon means more
+    // noise and lower recall; the noise length averages dimension / epsilon,
+    // so for unit vectors epsilon should be well above the dimension to keep
+    // neighbours recognisable. Points already in the index are unchanged.
+    // Saved with the index; None turns it off.
+    #[uniffi::method]
+    pub fn set_privacy_epsilon(&self, epsilon: Option<f64>) -> Result<(), HnswError> {
+        self.check_writable()?;
+        if let Some(epsilon) = epsilon
+            && !(epsilon.is_finite() && epsilon > 0.0)
+        {
+            return Err(HnswError::InvalidArgument(format!(
+                "epsilon must be a positive number, got {epsilon}"
+            )));
+        }
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        meta.privacy_epsilon = epsilon;
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn get_privacy_epsilon(&self) -> Option<f64> {
+        self.meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .privacy_epsilon
+    }
+}
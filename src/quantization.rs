@@ -0,0 +1,431 @@
+use std::collections::HashMap;
+use std::fs;
+use std::mem::ManuallyDrop;
+use std::path::Path;
+use std::ptr::NonNull;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use hnsw_rs::api::AnnT;
+use hnsw_rs::hnsw::Hnsw;
+use hnsw_rs::hnswio::HnswIo;
+use hnsw_rs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    DistanceType, HnswError, HnswIndexConfig, SearchResult, check_finite, check_metric, guarded,
+    normalize_vector, rename_dump, simd,
+};
+
+const RERANK_FACTOR: usize = 4;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Sq8Params {
+    min: Vec<f32>,
+    scale: Vec<f32>,
+}
+
+impl Sq8Params {
+    fn train(samples: &[Vec<f32>], dimension: usize) -> Self {
+        let mut min = vec![f32::INFINITY; dimension];
+        let mut max = vec![f32::NEG_INFINITY; dimension];
+        for sample in samples {
+            for (i, &v) in sample.iter().enumerate() {
+                min[i] = min[i].min(v);
+                max[i] = max[i].max(v);
+            }
+        }
+        let scale = min
+            .iter_mut()
+            .zip(&max)
+            .map(|(lo, &hi)| {
+                if !lo.is_finite() {
+                    *lo = 0.0;
+                    return 0.0;
+                }
+                (hi - *lo) / 255.0
+            })
+            .collect();
+        Sq8Params { min, scale }
+    }
+
+    fn encode(&self, v: &[f32]) -> Vec<u8> {
+        v.iter()
+            .zip(self.min.iter().zip(&self.scale))
+            .map(|(&x, (&lo, &scale))| {
+                if scale == 0.0 {
+                    0
+                } else {
+                    ((x - lo) / scale).round().clamp(0.0, 255.0) as u8
+                }
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn decode(&self, i: usize, code: u8) -> f32 {
+        self.min[i] + code as f32 * self.scale[i]
+    }
+}
+
+// Distances are evaluated directly on the codes, decoding each component on
+// the fly. The formulas mirror the hnsw_rs metrics used by the f32 index.
+#[derive(Clone)]
+struct DistSq8 {
+    params: Arc<Sq8Params>,
+    metric: DistanceType,
+}
+
+impl Distance<u8> for DistSq8 {
+    fn eval(&self, va: &[u8], vb: &[u8]) -> f32 {
+        let p = &self.params;
+        let pairs = va
+            .iter()
+            .zip(vb)
+            .enumerate()
+            .map(|(i, (&a, &b))| (p.decode(i, a), p.decode(i, b)));
+        match self.metric {
+            DistanceType::L2 => pairs.map(|(a, b)| (a - b) * (a - b)).sum::<f32>().sqrt(),
+            DistanceType::L1 => pairs.map(|(a, b)| (a - b).abs()).sum(),
+            DistanceType::Dot => (1.0 - pairs.map(|(a, b)| a * b).sum::<f32>()).max(0.0),
+            DistanceType::Cosine => {
+                let (mut dot, mut na, mut nb) = (0.0f32, 0.0f32, 0.0f32);
+                for (a, b) in pairs {
+                    dot += a * b;
+                    na += a * a;
+                    nb += b * b;
+                }
+                if na == 0.0 || nb == 0.0 {
+                    0.0
+                } else {
+                    (1.0 - dot / (na.sqrt() * nb.sqrt())).max(0.0)
+                }
+            }
+        }
+    }
+}
+
+struct Sq8Graph {
+    hnsw: ManuallyDrop<Hnsw<'static, u8, DistSq8>>,
+    io_ptr: Option<NonNull<HnswIo>>,
+}
+
+impl Drop for Sq8Graph {
+    fn drop(&mut self) {
+        unsafe {
+            ManuallyDrop::drop(&mut self.hnsw);
+            if let Some(ptr) = self.io_ptr.take() {
+                drop(Box::from_raw(ptr.as_ptr()));
+            }
+        }
+    }
+}
+
+unsafe impl Send for Sq8Graph {}
+unsafe impl Sync for Sq8Graph {}
+
+#[derive(Serialize, Deserialize)]
+struct Sq8Sidecar {
+    config: HnswIndexConfig,
+    training_size: u64,
+    params: Option<Sq8Params>,
+    pending: Vec<(u64, Vec<f32>)>,
+    originals: Option<HashMap<u64, Vec<f32>>>,
+}
+
+struct Sq8State {
+    params: Option<Arc<Sq8Params>>,
+    graph: Option<Sq8Graph>,
+    pending: Vec<(u64, Vec<f32>)>,
+    originals: Option<HashMap<u64, Vec<f32>>>,
+}
+
+fn sidecar_path(directory: &str, basename: &str) -> std::path::PathBuf {
+    Path::new(directory).join(format!("{basename}.hnsw.sq8"))
+}
+
+#[derive(uniffi::Object)]
+pub struct HnswSq8Index {
+    config: HnswIndexConfig,
+    training_size: u64,
+    state: Mutex<Sq8State>,
+}
+
+impl HnswSq8Index {
+    fn check_dimension(&self, len: usize) -> Result<(), HnswError> {
+        if len != self.config.dimension as usize {
+            return Err(HnswError::DimensionMismatch {
+                expected: self.config.dimension,
+                got: len as u32,
+            });
+        }
+        Ok(())
+    }
+
+    fn prepare(&self, mut vector: Vec<f32>, index: usize) -> Result<Vec<f32>, HnswError> {
+        self.check_dimension(vector.len())?;
+        check_finite(&vector, index)?;
+        check_metric(&vector, self.config.distance, index)?;
+        if self.config.normalize_vectors {
+            normalize_vector(&mut vector)?;
+        }
+        Ok(vector)
+    }
+
+    fn build_graph(&self, params: Arc<Sq8Params>) -> Sq8Graph {
+        let config = self.config;
+        Sq8Graph {
+            hnsw: ManuallyDrop::new(Hnsw::new(
+                config.max_nb_connection as usize,
+                config.max_elements as usize,
+                config.max_layer as usize,
+                config.ef_construction as usize,
+                DistSq8 {
+                    params,
+                    metric: config.distance,
+                },
+            )),
+            io_ptr: None,
+        }
+    }
+
+    fn train_locked(&self, state: &mut Sq8State, samples: &[Vec<f32>]) {
+        let params = Arc::new(Sq8Params::train(samples, self.config.dimension as usize));
+        let graph = self.build_graph(Arc::clone(&params));
+        for (id, data) in state.pending.drain(..) {
+            graph.hnsw.insert((&params.encode(&data), id as usize));
+        }
+        state.params = Some(params);
+        state.graph = Some(graph);
+    }
+
+    fn insert_locked(&self, state: &mut Sq8State, data: Vec<f32>, id: u64) {
+        if let Some(originals) = state.originals.as_mut() {
+            originals.insert(id, data.clone());
+        }
+        match (&state.params, &state.graph) {
+            (Some(params), Some(graph)) => {
+                graph.hnsw.insert((&params.encode(&data), id as usize));
+            }
+            _ => {
+                state.pending.push((id, data));
+                if state.pending.len() as u64 >= self.training_size {
+                    let samples: Vec<Vec<f32>> =
+                        state.pending.iter().map(|(_, v)| v.clone()).collect();
+                    self.train_locked(state, &samples);
+                }
+            }
+        }
+    }
+}
+
+#[uniffi::export]
+impl HnswSq8Index {
+    #[uniffi::constructor]
+    pub fn new(config: HnswIndexConfig, training_size: u64, keep_originals: bool) -> Self {
+        Self {
+            config,
+            training_size: training_size.max(1),
+            state: Mutex::new(Sq8State {
+                params: None,
+                graph: None,
+                pending: Vec::new(),
+                originals: keep_originals.then(HashMap::new),
+            }),
+        }
+    }
+
+    #[uniffi::constructor]
+    pub fn load(directory: String, basename: String) -> Result<Self, HnswError> {
+        let bytes = fs::read(sidecar_path(&directory, &basename))?;
+        let sidecar: Sq8Sidecar =
+            bincode::deserialize(&bytes).map_err(|e| HnswError::Corrupted(e.to_string()))?;
+        let params = sidecar.params.map(Arc::new);
+        let graph = match &params {
+            Some(params) => {
+                let io = Box::new(HnswIo::new(Path::new(&directory), &basename));
+                let io_ptr = Box::into_raw(io);
+                let dist = DistSq8 {
+                    params: Arc::clone(params),
+                    metric: sidecar.config.distance,
+                };
+                let hnsw: Hnsw<'static, u8, DistSq8> = unsafe {
+                    (*io_ptr)
+                        .load_hnsw_with_dist(dist)
+                        .map_err(|e| HnswError::ReloadError(e.to_string()))?
+                };
+                Some(Sq8Graph {
+                    hnsw: ManuallyDrop::new(hnsw),
+                    io_ptr: NonNull::new(io_ptr),
+                })
+            }
+            None => None,
+        };
+        Ok(Self {
+            config: sidecar.config,
+            training_size: sidecar.training_size,
+            state: Mutex::new(Sq8State {
+                params,
+                graph,
+                pending: sidecar.pending,
+                originals: sidecar.originals,
+            }),
+        })
+    }
+
+    #[uniffi::method]
+    pub fn train(&self, samples: Vec<Vec<f32>>) -> Result<(), HnswError> {
+        if samples.is_empty() {
+            return Err(HnswError::InvalidArgument(
+                "Training requires at least one sample".to_string(),
+            ));
+        }
+        let samples: Vec<Vec<f32>> = samples
+            .into_iter()
+            .enumerate()
+            .map(|(i, sample)| self.prepare(sample, i))
+            .collect::<Result<_, _>>()?;
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.params.is_some() {
+            return Err(HnswError::InvalidArgument(
+                "Quantizer is already trained".to_string(),
+            ));
+        }
+        guarded("sq8_train", || self.train_locked(&mut state, &samples))
+    }
+
+    #[uniffi::method]
+    pub fn is_trained(&self) -> Result<bool, HnswError> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(state.params.is_some())
+    }
+
+    #[uniffi::method]
+    pub fn insert(&self, data: Vec<f32>, id: u64) -> Result<(), HnswError> {
+        let data = self.prepare(data, 0)?;
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        guarded("sq8_insert", || self.insert_locked(&mut state, data, id))
+    }
+
+    #[uniffi::method]
+    pub fn insert_batch(&self, data: Vec<Vec<f32>>, ids: Vec<u64>) -> Result<(), HnswError> {
+        if data.len() != ids.len() {
+            return Err(HnswError::LengthMismatch {
+                vectors: data.len() as u64,
+                ids: ids.len() as u64,
+            });
+        }
+        let data: Vec<Vec<f32>> = data
+            .into_iter()
+            .enumerate()
+            .map(|(i, vec)| self.prepare(vec, i))
+            .collect::<Result<_, _>>()?;
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        guarded("sq8_insert_batch", || {
+            for (vec, id) in data.into_iter().zip(ids) {
+                self.insert_locked(&mut state, vec, id);
+            }
+        })
+    }
+
+    #[uniffi::method]
+    pub fn search(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+        rerank: bool,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let query = self.prepare(query, 0)?;
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let metric = self.config.distance;
+        let k = k as usize;
+        let (params, graph) = match (&state.params, &state.graph) {
+            (Some(params), Some(graph)) => (params, graph),
+            _ => {
+                // Not trained yet: the pending vectors are still full precision.
+                let mut results: Vec<SearchResult> = state
+                    .pending
+                    .iter()
+                    .map(|(id, v)| SearchResult::new(*id, simd::eval(metric, &query, v)))
+                    .collect();
+                results.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+                results.truncate(k);
+                return Ok(results);
+            }
+        };
+        let code = params.encode(&query);
+        if !rerank {
+            let hits = guarded("sq8_search", || {
+                graph.hnsw.search(&code, k, ef_search as usize)
+            })?;
+            return Ok(hits.into_iter().map(SearchResult::from).collect());
+        }
+        let originals = state.originals.as_ref().ok_or_else(|| {
+            HnswError::InvalidArgument(
+                "Re-ranking requires an index created with keep_originals".to_string(),
+            )
+        })?;
+        let candidates = k * RERANK_FACTOR;
+        let ef = (ef_search as usize).max(candidates);
+        let hits = guarded("sq8_search", || graph.hnsw.search(&code, candidates, ef))?;
+        let mut results: Vec<SearchResult> = hits
+            .into_iter()
+            .map(|n| {
+                let id = n.d_id as u64;
+                let distance = originals
+                    .get(&id)
+                    .map_or(n.distance, |v| simd::eval(metric, &query, v));
+                SearchResult::new(id, distance)
+            })
+            .collect();
+        results.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        results.truncate(k);
+        Ok(results)
+    }
+
+    #[uniffi::method]
+    pub fn len(&self) -> Result<u64, HnswError> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let graph_len = state
+            .graph
+            .as_ref()
+            .map_or(0, |graph| graph.hnsw.get_nb_point());
+        Ok((graph_len + state.pending.len()) as u64)
+    }
+
+    #[uniffi::method]
+    pub fn is_empty(&self) -> Result<bool, HnswError> {
+        Ok(self.len()? == 0)
+    }
+
+    #[uniffi::method]
+    pub fn get_dimension(&self) -> u32 {
+        self.config.dimension
+    }
+
+    #[uniffi::method]
+    pub fn save(&self, directory: String, basename: String) -> Result<(), HnswError> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        // Dumped under a staging name and renamed into place, as in
+        // HnswIndex::save, so a graph mapped from the old files is never
+        // written over and load finds what was just dumped.
+        if let Some(graph) = &state.graph {
+            let dumped = graph
+                .hnsw
+                .file_dump(Path::new(&directory), &format!("{basename}.staging"))
+                .map_err(|e| HnswError::DumpError(e.to_string()))?;
+            rename_dump(&directory, &dumped, &basename)?;
+        }
+        let sidecar = Sq8Sidecar {
+            config: self.config,
+            training_size: self.training_size,
+            params: state.params.as_deref().cloned(),
+            pending: state.pending.clone(),
+            originals: state.originals.clone(),
+        };
+        let bytes =
+            bincode::serialize(&sidecar).map_err(|e| HnswError::DumpError(e.to_string()))?;
+        fs::write(sidecar_path(&directory, &basename), bytes)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::PoisonError;
+
+use hnsw_rs::hnsw::Hnsw;
+use hnsw_rs::prelude::*;
+
+use crate::{HnswError, HnswIndex, HnswIndexInner, PointMeta, graph_points};
+
+// Beyond this many neighbours count_within stops searching and samples.
+const COUNT_SEARCH_LIMIT: u32 = 1024;
+const COUNT_SAMPLE: usize = 2048;
+
+// Counts the visible points of an evenly spaced sample that are within
+// `radius`, returning (within, sampled).
+fn sample_within<D>(
+    hnsw: &Hnsw<'static, f32, D>,
+    meta: &PointMeta,
+    query: &[f32],
+    radius: f32,
+) -> (u64, u64)
+where
+    D: Distance<f32> + Send + Sync,
+{
+    let step = (hnsw.get_nb_point() / COUNT_SAMPLE).max(1);
+    let (mut within, mut sampled) = (0, 0);
+    for point in graph_points(hnsw).step_by(step) {
+        if !meta.visible(point.get_origin_id() as u64) {
+            continue;
+        }
+        sampled += 1;
+        if hnsw.get_distance().eval(query, point.get_v()) <= radius {
+            within += 1;
+        }
+    }
+    (within, sampled)
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Roughly how many points are within `radius` of `query`, for labels like
+    // "~1,200 similar photos". Small counts come from searches of growing
+    // size and are as accurate as search itself; once more than 1024 points
+    // match, the rest is extrapolated from a sample of about 2048 points.
+    #[uniffi::method]
+    pub fn count_within(
+        &self,
+        query: Vec<f32>,
+        radius: f32,
+        ef_search: u32,
+    ) -> Result<u64, HnswError> {
+        if radius.is_nan() || radius < 0.0 {
+            return Err(HnswError::InvalidArgument(
+                "Radius must be a non-negative number".to_string(),
+            ));
+        }
+        let ef_search = self.resolve_ef(ef_search);
+        let mut k = ef_search.clamp(16, COUNT_SEARCH_LIMIT);
+        loop {
+            let results = self.search(query.clone(), k, ef_search.max(k))?;
+            let within = results.iter().filter(|r| r.distance <= radius).count();
+            // Either the radius ends inside the results or the index ran out.
+            if within < results.len() || results.len() < k as usize {
+                return Ok(within as u64);
+            }
+            if k == COUNT_SEARCH_LIMIT {
+                break;
+            }
+            k = k.saturating_mul(2).min(COUNT_SEARCH_LIMIT);
+        }
+
+        let query = self.prepare_query(query, 0)?;
+        let guard = self.lock_inner()?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let (within, sampled) = match &*guard {
+            HnswIndexInner::L2(inner) => sample_within(&inner.hnsw, &meta, &query, radius),
+            HnswIndexInner::Cosine(inner) => sample_within(&inner.hnsw, &meta, &query, radius),
+            HnswIndexInner::Dot(inner) => sample_within(&inner.hnsw, &meta, &query, radius),
+            HnswIndexInner::L1(inner) => sample_within(&inner.hnsw, &meta, &query, radius),
+        };
+        drop((meta, guard));
+        let visible = self.count()?;
+        let estimate = if sampled == 0 {
+            0
+        } else {
+            (within as f64 / sampled as f64 * visible as f64).round() as u64
+        };
+        Ok(estimate.max(COUNT_SEARCH_LIMIT as u64))
+    }
+}
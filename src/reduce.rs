@@ -0,0 +1,242 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::HnswError;
+
+const PCA_ITERATIONS: usize = 20;
+
+// splitmix64; only used to seed projections, so statistical quality beyond
+// "well spread" does not matter.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn next_gaussian(&mut self) -> f32 {
+        let u1 = self.next_f32().max(f32::MIN_POSITIVE);
+        let u2 = self.next_f32();
+        (-2.0 * u1.ln()).sqrt() * (std::f32::consts::TAU * u2).cos()
+    }
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+// Modified Gram-Schmidt, in place. A column that collapses numerically is
+// replaced by a fresh random direction so the basis keeps full rank.
+fn orthonormalize(columns: &mut [Vec<f32>], rng: &mut SplitMix64) {
+    for j in 0..columns.len() {
+        loop {
+            let (done, rest) = columns.split_at_mut(j);
+            let column = &mut rest[0];
+            for prev in done.iter() {
+                let proj = dot(column, prev);
+                for (c, p) in column.iter_mut().zip(prev) {
+                    *c -= proj * p;
+                }
+            }
+            let norm = dot(column, column).sqrt();
+            if norm > 1e-6 {
+                column.iter_mut().for_each(|c| *c /= norm);
+                break;
+            }
+            column.iter_mut().for_each(|c| *c = rng.next_gaussian());
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, uniffi::Object)]
+pub struct DimReducer {
+    input_dim: u32,
+    output_dim: u32,
+    mean: Vec<f32>,
+    // output_dim rows of input_dim weights.
+    components: Vec<f32>,
+}
+
+impl DimReducer {
+    fn check_dims(input_dim: usize, target_dim: u32) -> Result<(), HnswError> {
+        if target_dim == 0 || target_dim as usize > input_dim {
+            return Err(HnswError::InvalidArgument(format!(
+                "Target dimension must be in 1..={input_dim}, got {target_dim}"
+            )));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn project(&self, v: &[f32]) -> Vec<f32> {
+        let centered: Vec<f32> = v.iter().zip(&self.mean).map(|(x, m)| x - m).collect();
+        self.components
+            .chunks_exact(self.input_dim as usize)
+            .map(|row| dot(row, &centered))
+            .collect()
+    }
+
+    pub(crate) fn sidecar_path(directory: &str, basename: &str) -> PathBuf {
+        Path::new(directory).join(format!("{basename}.hnsw.reducer"))
+    }
+
+    pub(crate) fn load_sidecar(directory: &str, basename: &str) -> Result<Option<Self>, HnswError> {
+        let path = Self::sidecar_path(directory, basename);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let bytes = fs::read(path)?;
+        bincode::deserialize(&bytes)
+            .map(Some)
+            .map_err(|e| HnswError::Corrupted(e.to_string()))
+    }
+
+    pub(crate) fn save_sidecar(
+        reducer: Option<&Self>,
+        directory: &str,
+        basename: &str,
+    ) -> Result<(), HnswError> {
+        let path = Self::sidecar_path(directory, basename);
+        match reducer {
+            Some(reducer) => {
+                let bytes =
+                    bincode::serialize(reducer).map_err(|e| HnswError::DumpError(e.to_string()))?;
+                fs::write(path, bytes)?;
+            }
+            // Don't leave a reducer from an earlier save under the same name.
+            None if path.exists() => fs::remove_file(path)?,
+            None => {}
+        }
+        Ok(())
+    }
+}
+
+#[uniffi::export]
+impl DimReducer {
+    #[uniffi::constructor]
+    pub fn train_pca(vectors: Vec<Vec<f32>>, target_dim: u32) -> Result<Self, HnswError> {
+        let Some(first) = vectors.first() else {
+            return Err(HnswError::InvalidArgument(
+                "At least one training vector is required".to_string(),
+            ));
+        };
+        let d = first.len();
+        Self::check_dims(d, target_dim)?;
+        if let Some(v) = vectors.iter().find(|v| v.len() != d) {
+            return Err(HnswError::DimensionMismatch {
+                expected: d as u32,
+                got: v.len() as u32,
+            });
+        }
+        let n = vectors.len() as f32;
+        let mut mean = vec![0.0f32; d];
+        for v in &vectors {
+            for (m, x) in mean.iter_mut().zip(v) {
+                *m += x;
+            }
+        }
+        mean.iter_mut().for_each(|m| *m /= n);
+        let centered: Vec<Vec<f32>> = vectors
+            .iter()
+            .map(|v| v.iter().zip(&mean).map(|(x, m)| x - m).collect())
+            .collect();
+
+        let covariance: Vec<Vec<f32>> = (0..d)
+            .into_par_iter()
+            .map(|i| {
+                let mut row = vec![0.0f32; d];
+                for v in &centered {
+                    let xi = v[i];
+                    for (r, x) in row.iter_mut().zip(v) {
+                        *r += xi * x;
+                    }
+                }
+                row.iter_mut().for_each(|r| *r /= n);
+                row
+            })
+            .collect();
+
+        // Subspace iteration for the leading eigenvectors of the covariance.
+        let mut rng = SplitMix64(d as u64);
+        let mut basis: Vec<Vec<f32>> = (0..target_dim)
+            .map(|_| (0..d).map(|_| rng.next_gaussian()).collect())
+            .collect();
+        orthonormalize(&mut basis, &mut rng);
+        for _ in 0..PCA_ITERATIONS {
+            basis = basis
+                .par_iter()
+                .map(|q| covariance.iter().map(|row| dot(row, q)).collect())
+                .collect();
+            orthonormalize(&mut basis, &mut rng);
+        }
+        let mut ranked: Vec<(f32, Vec<f32>)> = basis
+            .into_par_iter()
+            .map(|q| {
+                let variance: f32 = covariance
+                    .iter()
+                    .zip(&q)
+                    .map(|(row, qi)| qi * dot(row, &q))
+                    .sum();
+                (variance, q)
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        Ok(DimReducer {
+            input_dim: d as u32,
+            output_dim: target_dim,
+            mean,
+            components: ranked.into_iter().flat_map(|(_, q)| q).collect(),
+        })
+    }
+
+    #[uniffi::constructor]
+    pub fn random_projection(
+        input_dim: u32,
+        target_dim: u32,
+        seed: u64,
+    ) -> Result<Self, HnswError> {
+        Self::check_dims(input_dim as usize, target_dim)?;
+        let mut rng = SplitMix64(seed);
+        let scale = 1.0 / (target_dim as f32).sqrt();
+        Ok(DimReducer {
+            input_dim,
+            output_dim: target_dim,
+            mean: vec![0.0; input_dim as usize],
+            components: (0..input_dim as usize * target_dim as usize)
+                .map(|_| rng.next_gaussian() * scale)
+                .collect(),
+        })
+    }
+
+    #[uniffi::method]
+    pub fn input_dimension(&self) -> u32 {
+        self.input_dim
+    }
+
+    #[uniffi::method]
+    pub fn output_dimension(&self) -> u32 {
+        self.output_dim
+    }
+
+    #[uniffi::method]
+    pub fn transform(&self, vector: Vec<f32>) -> Result<Vec<f32>, HnswError> {
+        if vector.len() != self.input_dim as usize {
+            return Err(HnswError::DimensionMismatch {
+                expected: self.input_dim,
+                got: vector.len() as u32,
+            });
+        }
+        Ok(self.project(&vector))
+    }
+}
@@ -0,0 +1,96 @@
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::sync::PoisonError;
+use std::sync::atomic::Ordering;
+
+use crate::{HnswError, HnswIndex};
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ResidencyStats {
+    // False when the vectors are on the heap; the other fields are then 0.
+    pub mmapped: bool,
+    // Size of the mapped data file.
+    pub file_bytes: u64,
+    // How much of it is in the page cache right now, to the page.
+    pub resident_bytes: u64,
+    pub resident_fraction: f64,
+}
+
+// Maps the file again just to ask mincore about it. The page cache is shared,
+// so this sees the same pages hnsw_rs's mapping does, and mincore never faults
+// anything in.
+#[cfg(unix)]
+fn resident_bytes(file: &File, len: usize) -> io::Result<u64> {
+    use std::os::unix::io::AsRawFd;
+    if len == 0 {
+        return Ok(0);
+    }
+    let page = unsafe { libc::sysconf(libc::_SC_PAGESIZE) }.max(1) as usize;
+    let addr = unsafe {
+        libc::mmap(
+            std::ptr::null_mut(),
+            len,
+            libc::PROT_READ,
+            libc::MAP_SHARED,
+            file.as_raw_fd(),
+            0,
+        )
+    };
+    if addr == libc::MAP_FAILED {
+        return Err(io::Error::last_os_error());
+    }
+    let mut pages = vec![0u8; len.div_ceil(page)];
+    let result = unsafe { libc::mincore(addr, len, pages.as_mut_ptr().cast()) };
+    let error = io::Error::last_os_error();
+    unsafe { libc::munmap(addr, len) };
+    if result != 0 {
+        return Err(error);
+    }
+    let resident = pages.iter().filter(|&&page| page & 1 != 0).count();
+    Ok((resident * page).min(len) as u64)
+}
+
+#[cfg(not(unix))]
+fn resident_bytes(_file: &File, len: usize) -> io::Result<u64> {
+    Ok(len as u64)
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // How much of an mmapped index's vector data is paged in, so an app can
+    // warm the index up (e.g. a throwaway search) before a latency-sensitive
+    // interaction. The graph itself is always on the heap.
+    #[uniffi::method]
+    pub fn residency_stats(&self) -> Result<ResidencyStats, HnswError> {
+        let not_mapped = ResidencyStats {
+            mmapped: false,
+            file_bytes: 0,
+            resident_bytes: 0,
+            resident_fraction: 0.0,
+        };
+        if !self.mmapped.load(Ordering::Relaxed) {
+            return Ok(not_mapped);
+        }
+        let dump = match &*self.source.lock().unwrap_or_else(PoisonError::into_inner) {
+            Some(source) => Some((source.directory.clone(), source.basename.clone())),
+            None => self.resource.clone(),
+        };
+        let Some((directory, basename)) = dump else {
+            return Ok(not_mapped);
+        };
+        let file = File::open(Path::new(&directory).join(format!("{basename}.hnsw.data")))?;
+        let len = file.metadata()?.len();
+        let resident = resident_bytes(&file, len as usize)?;
+        Ok(ResidencyStats {
+            mmapped: true,
+            file_bytes: len,
+            resident_bytes: resident,
+            resident_fraction: if len == 0 {
+                1.0
+            } else {
+                resident as f64 / len as f64
+            },
+        })
+    }
+}
@@ -0,0 +1,343 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, Shutdown, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{HnswError, HnswIndex};
+
+// Bodies are JSON vectors; this is well beyond any real query, and batches
+// bigger than it can be split.
+const MAX_BODY: usize = 16 << 20;
+const MAX_HEADER_LINE: usize = 8 << 10;
+// Connections past this are closed unanswered, so a stuck or hostile client
+// can hold at most this many threads and bodies.
+const MAX_CONNECTIONS: usize = 16;
+const IO_TIMEOUT: Duration = Duration::from_secs(10);
+
+// 128 bits from the per-process keys std seeds from the OS for HashMap, so
+// no rand dependency is needed; see privacy.rs.
+fn random_token() -> String {
+    let mut token = String::with_capacity(32);
+    for i in 0..2 {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(i);
+        token.push_str(&format!("{:016x}", hasher.finish()));
+    }
+    token
+}
+
+// Compares every byte, so the time taken says nothing about how much of a
+// guess was right.
+fn token_matches(given: &str, token: &str) -> bool {
+    given.len() == token.len()
+        && given
+            .bytes()
+            .zip(token.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+#[derive(Deserialize)]
+struct SearchRequest {
+    vector: Vec<f32>,
+    k: u32,
+    #[serde(default = "default_ef")]
+    ef_search: u32,
+}
+
+fn default_ef() -> u32 {
+    64
+}
+
+#[derive(Deserialize)]
+struct InsertRequest {
+    vectors: Vec<Vec<f32>>,
+    ids: Vec<u64>,
+}
+
+#[derive(Serialize)]
+struct Hit {
+    id: u64,
+    distance: f32,
+}
+
+#[derive(Serialize)]
+struct Info {
+    points: u64,
+    dimension: u32,
+    read_only: bool,
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+struct Response {
+    status: &'static str,
+    body: Vec<u8>,
+}
+
+impl Response {
+    fn json(value: &impl Serialize) -> Self {
+        match serde_json::to_vec(value) {
+            Ok(body) => Response {
+                status: "200 OK",
+                body,
+            },
+            Err(e) => Response::error("500 Internal Server Error", e.to_string()),
+        }
+    }
+
+    fn error(status: &'static str, message: impl Into<String>) -> Self {
+        let body = serde_json::to_vec(&ErrorBody {
+            error: message.into(),
+        })
+        .unwrap_or_default();
+        Response { status, body }
+    }
+
+    fn from_result<T: Serialize>(result: Result<T, HnswError>) -> Self {
+        match result {
+            Ok(value) => Response::json(&value),
+            Err(e) => Response::error("400 Bad Request", e.to_string()),
+        }
+    }
+}
+
+fn route(index: &HnswIndex, writable: bool, method: &str, path: &str, body: &[u8]) -> Response {
+    match (method, path) {
+        ("GET", "/") => Response::from_result(index.len().map(|points| Info {
+            points,
+            dimension: index.config.dimension,
+            read_only: index.is_read_only(),
+        })),
+        ("POST", "/search") => match serde_json::from_slice::<SearchRequest>(body) {
+            Ok(req) => Response::from_result(index.search(req.vector, req.k, req.ef_search).map(
+                |results| {
+                    results
+                        .into_iter()
+                        .map(|r| Hit {
+                            id: r.id,
+                            distance: r.distance,
+                        })
+                        .collect::<Vec<_>>()
+                },
+            )),
+            Err(e) => Response::error("400 Bad Request", e.to_string()),
+        },
+        ("POST", "/insert") if !writable => {
+            Response::error("403 Forbidden", "inserts are not enabled on this server")
+        }
+        ("POST", "/insert") => match serde_json::from_slice::<InsertRequest>(body) {
+            Ok(req) => Response::from_result(
+                index
+                    .insert_batch(req.vectors, req.ids)
+                    .and_then(|()| index.len()),
+            ),
+            Err(e) => Response::error("400 Bad Request", e.to_string()),
+        },
+        (_, "/" | "/search" | "/insert") => Response::error("405 Method Not Allowed", method),
+        _ => Response::error("404 Not Found", path),
+    }
+}
+
+fn read_line(reader: &mut impl BufRead) -> std::io::Result<String> {
+    let mut line = String::new();
+    reader.take(MAX_HEADER_LINE as u64).read_line(&mut line)?;
+    Ok(line.trim_end().to_string())
+}
+
+struct Headers {
+    length: usize,
+    authorization: Option<String>,
+    json: bool,
+    origin: bool,
+}
+
+// One request per connection; the response closes it.
+fn handle(index: &HnswIndex, settings: &Settings, stream: TcpStream) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(IO_TIMEOUT))?;
+    stream.set_write_timeout(Some(IO_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let request_line = read_line(&mut reader)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Ok(());
+    };
+    let mut headers = Headers {
+        length: 0,
+        authorization: None,
+        json: false,
+        origin: false,
+    };
+    loop {
+        let header = read_line(&mut reader)?;
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            headers.length = value.parse().unwrap_or(usize::MAX);
+        } else if name.eq_ignore_ascii_case("authorization") {
+            headers.authorization = value.strip_prefix("Bearer ").map(str::to_string);
+        } else if name.eq_ignore_ascii_case("content-type") {
+            headers.json = value
+                .split(';')
+                .next()
+                .is_some_and(|t| t.trim().eq_ignore_ascii_case("application/json"));
+        } else if name.eq_ignore_ascii_case("origin") {
+            headers.origin = true;
+        }
+    }
+    // Browsers send Origin on every cross-site request and the tools this is
+    // for never do, so refusing it keeps web pages out even before the token.
+    let response = if headers.origin {
+        Response::error("403 Forbidden", "requests from web pages are not accepted")
+    } else if !headers
+        .authorization
+        .as_deref()
+        .is_some_and(|given| token_matches(given, &settings.token))
+    {
+        Response::error("401 Unauthorized", "missing or wrong bearer token")
+    } else if method == "POST" && !headers.json {
+        Response::error("415 Unsupported Media Type", "expected application/json")
+    } else if headers.length > MAX_BODY {
+        Response::error("413 Payload Too Large", "request body too large")
+    } else {
+        let mut body = vec![0u8; headers.length];
+        reader.read_exact(&mut body)?;
+        let path = path.split('?').next().unwrap_or(path);
+        route(index, settings.writable, method, path, &body)
+    };
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        response.body.len()
+    )?;
+    stream.write_all(&response.body)?;
+    stream.flush()?;
+    stream.shutdown(Shutdown::Both)
+}
+
+struct Settings {
+    token: String,
+    writable: bool,
+}
+
+// Serves an index over plain HTTP on the loopback interface, for desktop
+// tools and simulators that query the app's index from another process.
+// It only ever binds to 127.0.0.1, and every request must carry
+// `Authorization: Bearer <token()>`; hand the token to the tool out of band.
+// Requests with an Origin header are refused, so web pages can't reach it.
+//
+// Routes, all JSON (POST bodies need Content-Type: application/json):
+//   GET  /        {"points", "dimension", "read_only"}
+//   POST /search  {"vector", "k", "ef_search"?} -> [{"id", "distance"}]
+//   POST /insert  {"vectors", "ids"} -> point count; only with allow_insert
+#[derive(uniffi::Object)]
+pub struct QueryServer {
+    port: u16,
+    token: String,
+    stopped: Arc<AtomicBool>,
+    thread: Mutex<Option<JoinHandle<()>>>,
+}
+
+#[uniffi::export]
+impl QueryServer {
+    // Port 0 picks a free port; read it back with port(). The token is new
+    // for every server. POST /insert answers 403 unless `allow_insert` is set.
+    #[uniffi::constructor]
+    pub fn serve(index: Arc<HnswIndex>, port: u16, allow_insert: bool) -> Result<Self, HnswError> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        let port = listener.local_addr()?.port();
+        let token = random_token();
+        let settings = Arc::new(Settings {
+            token: token.clone(),
+            writable: allow_insert,
+        });
+        let open = Arc::new(AtomicUsize::new(0));
+        let stopped = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&stopped);
+        let thread = thread::Builder::new()
+            .name("hnsw-server".to_string())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    if flag.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    let Ok(stream) = stream else {
+                        continue;
+                    };
+                    if open.fetch_add(1, Ordering::SeqCst) >= MAX_CONNECTIONS {
+                        open.fetch_sub(1, Ordering::SeqCst);
+                        continue;
+                    }
+                    let (index, settings, done) =
+                        (Arc::clone(&index), Arc::clone(&settings), Arc::clone(&open));
+                    let spawned = thread::Builder::new()
+                        .name("hnsw-server-conn".to_string())
+                        .spawn(move || {
+                            let _ = handle(&index, &settings, stream);
+                            done.fetch_sub(1, Ordering::SeqCst);
+                        });
+                    if spawned.is_err() {
+                        open.fetch_sub(1, Ordering::SeqCst);
+                    }
+                }
+            })?;
+        Ok(QueryServer {
+            port,
+            token,
+            stopped,
+            thread: Mutex::new(Some(thread)),
+        })
+    }
+
+    #[uniffi::method]
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    #[uniffi::method]
+    pub fn token(&self) -> String {
+        self.token.clone()
+    }
+
+    // Stops accepting connections; requests already being handled finish.
+    // Also happens when the server is dropped.
+    #[uniffi::method]
+    pub fn stop(&self) {
+        if self.stopped.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        // Wake the accept loop so it sees the flag.
+        let _ = TcpStream::connect((Ipv4Addr::LOCALHOST, self.port));
+        if let Some(thread) = self
+            .thread
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+        {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for QueryServer {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::{HnswError, HnswIndex, check_finite};
+
+#[derive(Default)]
+struct Buffer {
+    data: Vec<Vec<f32>>,
+    ids: Vec<u64>,
+    buffered: HashSet<u64>,
+    committed: u64,
+}
+
+// Vectors are validated on push so a chunk rarely fails at flush time. When
+// one does, the push or commit that flushed it returns the error and the
+// chunk stays buffered for the next commit to retry. Anything pushed but not
+// yet flushed is dropped with the session.
+#[derive(uniffi::Object)]
+pub struct InsertSession {
+    index: Arc<HnswIndex>,
+    chunk_size: usize,
+    buffer: Mutex<Buffer>,
+}
+
+impl InsertSession {
+    fn flush(&self, buffer: &mut Buffer) -> Result<(), HnswError> {
+        if buffer.ids.is_empty() {
+            return Ok(());
+        }
+        // insert_batch takes the vectors, so it gets a copy of the chunk.
+        self.index
+            .insert_batch(buffer.data.clone(), buffer.ids.clone())?;
+        buffer.committed += buffer.ids.len() as u64;
+        buffer.data.clear();
+        buffer.ids.clear();
+        buffer.buffered.clear();
+        Ok(())
+    }
+}
+
+#[uniffi::export]
+impl InsertSession {
+    #[uniffi::method]
+    pub fn push(&self, data: Vec<f32>, id: u64) -> Result<(), HnswError> {
+        self.index.check_writable()?;
+        self.index.check_dimension(data.len())?;
+        let mut buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+        check_finite(&data, buffer.ids.len())?;
+        let guard = self.index.lock_inner()?;
+        let known = self
+            .index
+            .ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if known.contains(&id) || buffer.buffered.contains(&id) {
+            return Err(HnswError::DuplicateId(id));
+        }
+        drop((known, guard));
+        buffer.buffered.insert(id);
+        buffer.data.push(data);
+        buffer.ids.push(id);
+        if buffer.ids.len() >= self.chunk_size {
+            self.flush(&mut buffer)?;
+        }
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn pending(&self) -> Result<u64, HnswError> {
+        let buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(buffer.ids.len() as u64)
+    }
+
+    // Flushes whatever is buffered and returns the total number of vectors
+    // this session has inserted.
+    #[uniffi::method]
+    pub fn commit(&self) -> Result<u64, HnswError> {
+        let mut buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+        self.flush(&mut buffer)?;
+        Ok(buffer.committed)
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    #[uniffi::method]
+    pub fn insert_session(
+        self: Arc<Self>,
+        chunk_size: u32,
+    ) -> Result<Arc<InsertSession>, HnswError> {
+        self.check_writable()?;
+        if chunk_size == 0 {
+            return Err(HnswError::InvalidArgument(
+                "chunk_size must be at least 1".to_string(),
+            ));
+        }
+        Ok(Arc::new(InsertSession {
+            index: self,
+            chunk_size: chunk_size as usize,
+            buffer: Mutex::new(Buffer::default()),
+        }))
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+// Begin/end pairs map directly onto OSSignposter intervals; `interval_id` is
+// unique per interval so overlapping calls from different threads can be
+// told apart.
+#[uniffi::export(callback_interface)]
+pub trait SignpostListener: Send + Sync {
+    fn begin_interval(&self, name: String, interval_id: u64);
+    fn end_interval(&self, name: String, interval_id: u64);
+}
+
+static LISTENER: RwLock<Option<Arc<dyn SignpostListener>>> = RwLock::new(None);
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+pub(crate) struct Interval {
+    name: &'static str,
+    id: u64,
+    listener: Arc<dyn SignpostListener>,
+}
+
+impl Drop for Interval {
+    fn drop(&mut self) {
+        self.listener.end_interval(self.name.to_string(), self.id);
+    }
+}
+
+// Declare the returned guard before taking any index locks: it is dropped
+// last, so the end callback never runs with a lock held.
+pub(crate) fn interval(name: &'static str) -> Option<Interval> {
+    let listener = LISTENER
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()?;
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    listener.begin_interval(name.to_string(), id);
+    Some(Interval { name, id, listener })
+}
+
+#[uniffi::export]
+pub fn set_signpost_listener(listener: Box<dyn SignpostListener>) {
+    *LISTENER.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::from(listener));
+}
+
+#[uniffi::export]
+pub fn clear_signpost_listener() {
+    *LISTENER.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
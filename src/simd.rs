@@ -0,0 +1,137 @@
+use crate::DistanceType;
+
+// Same definitions as the anndists kernels hnsw_rs traverses with, so exact
+// and reranked distances line up with the ones the graph reports to within
+// rounding: these sum in f32, and anndists sums cosine in f64.
+pub(crate) fn eval(distance: DistanceType, a: &[f32], b: &[f32]) -> f32 {
+    match distance {
+        DistanceType::L2 => l2_squared(a, b).sqrt(),
+        DistanceType::Cosine => {
+            let (dot, norm_a, norm_b) = dot_and_norms(a, b);
+            if norm_a > 0.0 && norm_b > 0.0 {
+                (1.0 - dot / (norm_a * norm_b).sqrt()).max(0.0)
+            } else {
+                0.0
+            }
+        }
+        DistanceType::Dot => 1.0 - dot(a, b),
+        DistanceType::L1 => a.iter().zip(b).map(|(x, y)| (x - y).abs()).sum(),
+    }
+}
+
+pub(crate) fn dot(a: &[f32], b: &[f32]) -> f32 {
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        return unsafe { neon::dot(a, b) };
+    }
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+pub(crate) fn l2_squared(a: &[f32], b: &[f32]) -> f32 {
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        return unsafe { neon::l2_squared(a, b) };
+    }
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+// One pass for cosine: (a·b, |a|², |b|²).
+pub(crate) fn dot_and_norms(a: &[f32], b: &[f32]) -> (f32, f32, f32) {
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        return unsafe { neon::dot_and_norms(a, b) };
+    }
+    a.iter()
+        .zip(b)
+        .fold((0.0, 0.0, 0.0), |(d, na, nb), (x, y)| {
+            (d + x * y, na + x * x, nb + y * y)
+        })
+}
+
+// Four independent accumulators keep the FMA pipeline full; the tail that
+// doesn't fill a 16-wide block is finished in scalar code.
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use std::arch::aarch64::*;
+
+    const BLOCK: usize = 16;
+
+    #[target_feature(enable = "neon")]
+    fn sum4(acc: [float32x4_t; 4]) -> f32 {
+        vaddvq_f32(vaddq_f32(
+            vaddq_f32(acc[0], acc[1]),
+            vaddq_f32(acc[2], acc[3]),
+        ))
+    }
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn dot(a: &[f32], b: &[f32]) -> f32 {
+        let n = a.len().min(b.len());
+        let blocks = n / BLOCK * BLOCK;
+        let mut sum = unsafe {
+            let mut acc = [vdupq_n_f32(0.0); 4];
+            for i in (0..blocks).step_by(BLOCK) {
+                for (j, lane) in acc.iter_mut().enumerate() {
+                    let va = vld1q_f32(a.as_ptr().add(i + 4 * j));
+                    let vb = vld1q_f32(b.as_ptr().add(i + 4 * j));
+                    *lane = vfmaq_f32(*lane, va, vb);
+                }
+            }
+            sum4(acc)
+        };
+        for i in blocks..n {
+            sum += a[i] * b[i];
+        }
+        sum
+    }
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn l2_squared(a: &[f32], b: &[f32]) -> f32 {
+        let n = a.len().min(b.len());
+        let blocks = n / BLOCK * BLOCK;
+        let mut sum = unsafe {
+            let mut acc = [vdupq_n_f32(0.0); 4];
+            for i in (0..blocks).step_by(BLOCK) {
+                for (j, lane) in acc.iter_mut().enumerate() {
+                    let va = vld1q_f32(a.as_ptr().add(i + 4 * j));
+                    let vb = vld1q_f32(b.as_ptr().add(i + 4 * j));
+                    let diff = vsubq_f32(va, vb);
+                    *lane = vfmaq_f32(*lane, diff, diff);
+                }
+            }
+            sum4(acc)
+        };
+        for i in blocks..n {
+            let diff = a[i] - b[i];
+            sum += diff * diff;
+        }
+        sum
+    }
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn dot_and_norms(a: &[f32], b: &[f32]) -> (f32, f32, f32) {
+        let n = a.len().min(b.len());
+        let blocks = n / BLOCK * BLOCK;
+        let (mut dot, mut norm_a, mut norm_b) = unsafe {
+            let mut acc_dot = [vdupq_n_f32(0.0); 4];
+            let mut acc_a = [vdupq_n_f32(0.0); 4];
+            let mut acc_b = [vdupq_n_f32(0.0); 4];
+            for i in (0..blocks).step_by(BLOCK) {
+                for j in 0..4 {
+                    let va = vld1q_f32(a.as_ptr().add(i + 4 * j));
+                    let vb = vld1q_f32(b.as_ptr().add(i + 4 * j));
+                    acc_dot[j] = vfmaq_f32(acc_dot[j], va, vb);
+                    acc_a[j] = vfmaq_f32(acc_a[j], va, va);
+                    acc_b[j] = vfmaq_f32(acc_b[j], vb, vb);
+                }
+            }
+            (sum4(acc_dot), sum4(acc_a), sum4(acc_b))
+        };
+        for i in blocks..n {
+            dot += a[i] * b[i];
+            norm_a += a[i] * a[i];
+            norm_b += b[i] * b[i];
+        }
+        (dot, norm_a, norm_b)
+    }
+}
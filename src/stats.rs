@@ -0,0 +1,261 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashSet};
+use std::sync::{Arc, PoisonError};
+use std::time::Instant;
+
+use hnsw_rs::hnsw::{Hnsw, Point, PointId};
+use hnsw_rs::prelude::*;
+
+use crate::graph::entry_point;
+use crate::points::PointMap;
+use crate::{HnswError, HnswIndex, HnswIndexInner, PointMeta, SearchResult, guarded};
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct SearchStats {
+    pub nodes_visited: u64,
+    pub distance_computations: u64,
+    pub layers_descended: u32,
+    pub time_us: u64,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct SearchWithStats {
+    pub results: Vec<SearchResult>,
+    pub stats: SearchStats,
+}
+
+pub(crate) struct Candidate {
+    pub(crate) distance: f32,
+    pub(crate) point: Arc<Point<'static, f32>>,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance.total_cmp(&other.distance)
+    }
+}
+
+pub(crate) struct Traversal<'a, D: Distance<f32>> {
+    hnsw: &'a Hnsw<'static, f32, D>,
+    query: &'a [f32],
+    points: Arc<PointMap>,
+    visited: HashSet<PointId>,
+    distance_computations: u64,
+    max_visited: usize,
+    deadline: Option<Instant>,
+    // Set once a limit stops the traversal; nothing more is visited after.
+    pub(crate) truncated: bool,
+}
+
+impl<'a, D> Traversal<'a, D>
+where
+    D: Distance<f32> + Send + Sync,
+{
+    pub(crate) fn new(
+        hnsw: &'a Hnsw<'static, f32, D>,
+        points: Arc<PointMap>,
+        query: &'a [f32],
+    ) -> Self {
+        Traversal {
+            hnsw,
+            query,
+            points,
+            visited: HashSet::new(),
+            distance_computations: 0,
+            max_visited: usize::MAX,
+            deadline: None,
+            truncated: false,
+        }
+    }
+
+    pub(crate) fn with_limits(mut self, max_visited: usize, deadline: Option<Instant>) -> Self {
+        self.max_visited = max_visited;
+        self.deadline = deadline;
+        self
+    }
+
+    // Greedy descent from the entry point to the best point on layer 1, and
+    // how many layers that took.
+    pub(crate) fn descend(&mut self) -> Option<(Candidate, u32)> {
+        let entry = entry_point(self.hnsw)?;
+        let mut current = self.visit(entry.get_point_id())?;
+        let top = entry.get_point_id().0 as usize;
+        for layer in (1..=top).rev() {
+            current = self.greedy(current, layer);
+        }
+        Some((current, top as u32))
+    }
+
+    pub(crate) fn visit(&mut self, p_id: PointId) -> Option<Candidate> {
+        if self.truncated {
+            return None;
+        }
+        // The clock is only read every 32 points; it costs about as much as a
+        // short distance.
+        if self.visited.len() >= self.max_visited
+            || self
+                .deadline
+                .is_some_and(|d| self.visited.len().is_multiple_of(32) && Instant::now() >= d)
+        {
+            self.truncated = true;
+            return None;
+        }
+        if !self.visited.insert(p_id) {
+            return None;
+        }
+        let point = Arc::clone(self.points.get(&p_id)?);
+        self.distance_computations += 1;
+        Some(Candidate {
+            distance: self.hnsw.get_distance().eval(self.query, point.get_v()),
+            point,
+        })
+    }
+
+    fn greedy(&mut self, mut current: Candidate, layer: usize) -> Candidate {
+        loop {
+            let mut next = None;
+            let links = current.point.get_neighborhood_id();
+            for n in links.get(layer).into_iter().flatten() {
+                if let Some(candidate) = self.visit(n.p_id)
+                    && candidate.distance < next.as_ref().unwrap_or(&current).distance
+                {
+                    next = Some(candidate);
+                }
+            }
+            match next {
+                Some(next) => current = next,
+                None => return current,
+            }
+        }
+    }
+
+    // The ef-wide search of layer 0 from `entries`, returning the best
+    // points found, closest first.
+    pub(crate) fn beam(&mut self, entries: Vec<Candidate>, ef: usize) -> Vec<Candidate> {
+        let mut candidates = BinaryHeap::new();
+        let mut best = BinaryHeap::new();
+        for entry in entries {
+            candidates.push(Reverse(Candidate {
+                distance: entry.distance,
+                point: Arc::clone(&entry.point),
+            }));
+            best.push(entry);
+            if best.len() > ef {
+                best.pop();
+            }
+        }
+        while let Some(Reverse(closest)) = candidates.pop() {
+            if self.truncated {
+                break;
+            }
+            if best.len() >= ef && best.peek().is_some_and(|w| closest.distance > w.distance) {
+                break;
+            }
+            let links = closest.point.get_neighborhood_id();
+            for n in links.first().into_iter().flatten() {
+                let Some(candidate) = self.visit(n.p_id) else {
+                    continue;
+                };
+                if best.len() < ef || best.peek().is_some_and(|w| candidate.distance < w.distance) {
+                    candidates.push(Reverse(Candidate {
+                        distance: candidate.distance,
+                        point: Arc::clone(&candidate.point),
+                    }));
+                    best.push(candidate);
+                    if best.len() > ef {
+                        best.pop();
+                    }
+                }
+            }
+        }
+        best.into_sorted_vec()
+    }
+}
+
+// hnsw_rs keeps no counters of its own, so the counts come from replaying the
+// standard HNSW traversal (greedy descent, then an ef-wide beam on layer 0)
+// over the same graph. Results and timing are from the real search.
+fn search_with_stats<D>(
+    hnsw: &Hnsw<'static, f32, D>,
+    points: Arc<PointMap>,
+    meta: &PointMeta,
+    query: &[f32],
+    k: usize,
+    ef: usize,
+) -> Result<SearchWithStats, HnswError>
+where
+    D: Distance<f32> + Send + Sync,
+{
+    let start = Instant::now();
+    let hits = guarded("search_with_stats", || {
+        if meta.all_visible() {
+            hnsw.search(query, k, ef)
+        } else {
+            let filter = |id: &DataId| meta.visible(*id as u64);
+            hnsw.search_filter(query, k, ef, Some(&filter))
+        }
+    })?;
+    let results: Vec<SearchResult> = hits.into_iter().map(SearchResult::from).collect();
+    let time_us = start.elapsed().as_micros() as u64;
+
+    let mut traversal = Traversal::new(hnsw, points, query);
+    let mut layers_descended = 0;
+    if let Some((current, layers)) = traversal.descend() {
+        layers_descended = layers;
+        traversal.beam(vec![current], ef.max(k));
+    }
+    Ok(SearchWithStats {
+        results,
+        stats: SearchStats {
+            nodes_visited: traversal.visited.len() as u64,
+            distance_computations: traversal.distance_computations,
+            layers_descended,
+            time_us,
+        },
+    })
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    #[uniffi::method]
+    pub fn search_with_stats(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<SearchWithStats, HnswError> {
+        let query = self.prepare_query(query, 0)?;
+        let guard = self.lock_inner()?;
+        let points = self.point_map(&guard);
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let (k, ef) = (k as usize, self.resolve_ef(ef_search) as usize);
+        match &*guard {
+            HnswIndexInner::L2(inner) => {
+                search_with_stats(&inner.hnsw, points, &meta, &query, k, ef)
+            }
+            HnswIndexInner::Cosine(inner) => {
+                search_with_stats(&inner.hnsw, points, &meta, &query, k, ef)
+            }
+            HnswIndexInner::Dot(inner) => {
+                search_with_stats(&inner.hnsw, points, &meta, &query, k, ef)
+            }
+            HnswIndexInner::L1(inner) => {
+                search_with_stats(&inner.hnsw, points, &meta, &query, k, ef)
+            }
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::PoisonError;
+use std::sync::atomic::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::attrs::Attrs;
+use crate::observer::Event;
+use crate::{DistanceType, HnswError, HnswIndex, HnswIndexInner, guarded, threads};
+
+// Layout: magic, u32 version, then the bincode ChangeSet.
+const SYNC_MAGIC: &[u8; 8] = b"HNSWSYNC";
+const SYNC_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PointChange {
+    id: u64,
+    // As stored, i.e. after any reducer and normalization.
+    vector: Vec<f32>,
+    namespace: Option<u32>,
+    key: Option<String>,
+    payload: Option<Vec<u8>>,
+    document: Option<u64>,
+    attrs: Option<Attrs>,
+    timestamp: Option<i64>,
+    hidden: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ChangeSet {
+    // Sender's change_version when exported.
+    to: u64,
+    dimension: u32,
+    distance: DistanceType,
+    upserts: Vec<PointChange>,
+    removals: Vec<u64>,
+}
+
+impl ChangeSet {
+    fn encode(&self) -> Result<Vec<u8>, HnswError> {
+        let body = bincode::serialize(self).map_err(|e| HnswError::DumpError(e.to_string()))?;
+        let mut bytes = Vec::with_capacity(SYNC_MAGIC.len() + 4 + body.len());
+        bytes.extend_from_slice(SYNC_MAGIC);
+        bytes.extend_from_slice(&SYNC_VERSION.to_le_bytes());
+        bytes.extend(body);
+        Ok(bytes)
+    }
+
+    fn decode(bytes: &[u8]) -> Result<Self, HnswError> {
+        let Some(rest) = bytes.strip_prefix(SYNC_MAGIC) else {
+            return Err(HnswError::Corrupted("not a change set".to_string()));
+        };
+        if rest.len() < 4 {
+            return Err(HnswError::Corrupted(
+                "change set header is truncated".to_string(),
+            ));
+        }
+        let (version, body) = rest.split_at(4);
+        let version = u32::from_le_bytes(version.try_into().unwrap());
+        if version > SYNC_VERSION {
+            return Err(HnswError::UnsupportedVersion {
+                found: version,
+                supported: SYNC_VERSION,
+            });
+        }
+        bincode::deserialize(body).map_err(|e| HnswError::Corrupted(e.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ChangeApplyReport {
+    // The sender's version the changes run up to; pass it back as
+    // `since_version` on the next export from that device.
+    pub version: u64,
+    pub inserted: u64,
+    pub updated: u64,
+    pub removed: u64,
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Increases with every insert, metadata change and removal, and is saved
+    // with the index.
+    #[uniffi::method]
+    pub fn change_version(&self) -> Result<u64, HnswError> {
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(meta.clock)
+    }
+
+    // Everything that changed after `since_version`, as an opaque blob for
+    // apply_changes on another index with the same config. Each changed point
+    // is sent in full, vector and metadata, so 0 exports the whole index.
+    #[uniffi::method]
+    pub fn export_changes(&self, since_version: u64) -> Result<Vec<u8>, HnswError> {
+        let guard = self.lock_inner()?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let changed: HashSet<u64> = meta
+            .versions
+            .iter()
+            .filter(|&(_, &version)| version > since_version)
+            .map(|(&id, _)| id)
+            .collect();
+        let vectors = guard.vectors(&changed);
+        drop(guard);
+
+        let mut upserts: Vec<PointChange> = vectors
+            .into_iter()
+            .map(|(id, vector)| PointChange {
+                id,
+                vector,
+                namespace: meta.namespaces.get(&id).copied(),
+                key: meta.keys.get(&id).cloned(),
+                payload: meta.payloads.get(&id).cloned(),
+                document: meta.documents.get(&id).copied(),
+                attrs: meta.attrs.get(&id).cloned(),
+                timestamp: meta.timestamps.get(&id).copied(),
+                hidden: meta.hidden.contains(&id),
+            })
+            .collect();
+        upserts.sort_unstable_by_key(|change| change.id);
+        let mut removals: Vec<u64> = meta
+            .removed
+            .iter()
+            .filter(|&(_, &version)| version > since_version)
+            .map(|(&id, _)| id)
+            .collect();
+        removals.sort_unstable();
+        ChangeSet {
+            to: meta.clock,
+            dimension: self.config.dimension,
+            distance: self.config.distance,
+            upserts,
+            removals,
+        }
+        .encode()
+    }
+
+    // Points whose vector changed are removed and reinserted; points whose
+    // vector is unchanged only get their metadata replaced. The applied
+    // changes count as local changes too, so they are passed on by this
+    // index's own export_changes.
+    #[uniffi::method]
+    pub fn apply_changes(&self, changes: Vec<u8>) -> Result<ChangeApplyReport, HnswError> {
+        self.check_writable()?;
+        let changes = ChangeSet::decode(&changes)?;
+        if changes.dimension != self.config.dimension {
+            return Err(HnswError::DimensionMismatch {
+                expected: self.config.dimension,
+                got: changes.dimension,
+            });
+        }
+        if changes.distance != self.config.distance {
+            return Err(HnswError::InvalidArgument(format!(
+                "Change set uses {:?} distance, index uses {:?}",
+                changes.distance, self.config.distance
+            )));
+        }
+        if changes.upserts.iter().any(|change| {
+            change.vector.len() != changes.dimension as usize
+                || change.vector.iter().any(|x| !x.is_finite())
+        }) {
+            return Err(HnswError::Corrupted(
+                "change set has a bad vector".to_string(),
+            ));
+        }
+
+        let mut guard = self.lock_inner()?;
+        let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        // Only live ids: a tombstoned point is still in the graph.
+        let touched: HashSet<u64> = changes
+            .upserts
+            .iter()
+            .map(|change| change.id)
+            .filter(|id| known.contains(id))
+            .collect();
+        let existing = guard.vectors(&touched);
+        let mut deleted: Vec<u64> = changes
+            .removals
+            .iter()
+            .copied()
+            .filter(|id| known.contains(id))
+            .collect();
+        let removed = deleted.len() as u64;
+        let moved: Vec<&PointChange> = changes
+            .upserts
+            .iter()
+            .filter(|change| {
+                existing
+                    .get(&change.id)
+                    .is_some_and(|vector| *vector != change.vector)
+            })
+            .collect();
+        // Nothing has changed yet, so a change set that can't fit is refused
+        // whole rather than half applied.
+        let upserted: HashSet<u64> = changes.upserts.iter().map(|change| change.id).collect();
+        let dropped: HashSet<u64> = deleted
+            .iter()
+            .copied()
+            .filter(|id| !upserted.contains(id))
+            .collect();
+        let added = upserted.iter().filter(|id| !known.contains(id)).count();
+        let attempted = (known.len() - dropped.len() + added) as u64;
+        let max = self.capacity.load(Ordering::Relaxed);
+        if attempted > max && !self.auto_grow.load(Ordering::Relaxed) {
+            return Err(HnswError::CapacityExceeded { max, attempted });
+        }
+        deleted.extend(moved.iter().map(|change| change.id));
+        if !deleted.is_empty() {
+            self.remove_locked(&mut guard, &mut known, &mut meta, &deleted)?;
+        }
+
+        let fresh: Vec<&PointChange> = changes
+            .upserts
+            .iter()
+            .filter(|change| !known.contains(&change.id))
+            .collect();
+        let inserted = fresh.len() as u64 - moved.len() as u64;
+        let fresh_ids: Vec<u64> = fresh.iter().map(|change| change.id).collect();
+        self.ensure_capacity_locked(&mut guard, &known, &mut meta, &fresh_ids)?;
+        let pairs: Vec<(&Vec<f32>, usize)> = fresh
+            .iter()
+            .map(|change| (&change.vector, change.id as usize))
+            .collect();
+        guarded("apply_changes", || {
+            threads::install(|| match &*guard {
+                HnswIndexInner::L2(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::Cosine(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::Dot(inner) => inner.hnsw.parallel_insert(&pairs),
+                HnswIndexInner::L1(inner) => inner.hnsw.parallel_insert(&pairs),
+            })
+        })?;
+        known.extend(&fresh_ids);
+        self.sketch_inserted(&pairs);
+        self.notify(|| Event::Insert(fresh_ids.clone()));
+
+        for change in &changes.upserts {
+            let id = change.id;
+            if let Some(old) = meta.keys.remove(&id) {
+                meta.key_ids.remove(&old);
+            }
+            if let Some(key) = &change.key {
+                if let Some(previous) = meta.key_ids.insert(key.clone(), id) {
+                    meta.keys.remove(&previous);
+                }
+                meta.keys.insert(id, key.clone());
+                meta.next_key_id = meta.next_key_id.max(id + 1);
+            }
+            set_or_remove(&mut meta.namespaces, id, change.namespace);
+            set_or_remove(&mut meta.payloads, id, change.payload.clone());
+            set_or_remove(&mut meta.documents, id, change.document);
+            set_or_remove(&mut meta.attrs, id, change.attrs.clone());
+            set_or_remove(&mut meta.timestamps, id, change.timestamp);
+            if change.hidden {
+                meta.hidden.insert(id);
+            } else {
+                meta.hidden.remove(&id);
+            }
+        }
+        let ids: Vec<u64> = changes.upserts.iter().map(|change| change.id).collect();
+        meta.touch(&ids);
+        drop((meta, known, guard));
+        Ok(ChangeApplyReport {
+            version: changes.to,
+            inserted,
+            updated: changes.upserts.len() as u64 - inserted,
+            removed,
+        })
+    }
+}
+
+fn set_or_remove<V>(map: &mut HashMap<u64, V>, id: u64, value: Option<V>) {
+    match value {
+        Some(value) => map.insert(id, value),
+        None => map.remove(&id),
+    };
+}
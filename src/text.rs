@@ -0,0 +1,75 @@
+use std::sync::{Arc, PoisonError};
+
+use crate::{HnswError, HnswIndex, SearchResult};
+
+// Texts embedded and inserted per insert_batch call.
+const EMBED_BATCH: usize = 256;
+
+// Turns text into a vector, e.g. with Core ML or NaturalLanguage on Apple
+// platforms. May be called from any thread, but never while the index holds
+// a lock, so it may call back into the index.
+#[uniffi::export(callback_interface)]
+pub trait Embedder: Send + Sync {
+    fn embed(&self, text: String) -> Vec<f32>;
+}
+
+impl HnswIndex {
+    fn embedder(&self) -> Result<Arc<dyn Embedder>, HnswError> {
+        self.embedder
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+            .ok_or_else(|| HnswError::InvalidArgument("No embedder set".to_string()))
+    }
+}
+
+// Embeddings go through the same validation as vectors passed directly, so a
+// wrong-sized embedding fails with DimensionMismatch.
+#[uniffi::export]
+impl HnswIndex {
+    #[uniffi::method]
+    pub fn set_embedder(&self, embedder: Option<Box<dyn Embedder>>) {
+        *self.embedder.lock().unwrap_or_else(PoisonError::into_inner) = embedder.map(Arc::from);
+    }
+
+    #[uniffi::method]
+    pub fn insert_text(&self, text: String, id: u64) -> Result<(), HnswError> {
+        let vector = self.embedder()?.embed(text);
+        self.insert(vector, id)
+    }
+
+    // Embeds and inserts in batches; if one fails, earlier batches stay
+    // inserted.
+    #[uniffi::method]
+    pub fn insert_texts(&self, texts: Vec<String>, ids: Vec<u64>) -> Result<(), HnswError> {
+        self.check_writable()?;
+        if texts.len() != ids.len() {
+            return Err(HnswError::LengthMismatch {
+                vectors: texts.len() as u64,
+                ids: ids.len() as u64,
+            });
+        }
+        let embedder = self.embedder()?;
+        let mut texts = texts.into_iter();
+        for ids in ids.chunks(EMBED_BATCH) {
+            let vectors = texts
+                .by_ref()
+                .take(ids.len())
+                .map(|text| embedder.embed(text))
+                .collect();
+            self.insert_batch(vectors, ids.to_vec())?;
+        }
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn search_text(
+        &self,
+        text: String,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let query = self.embedder()?.embed(text);
+        self.search(query, k, ef_search)
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::HnswError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, uniffi::Enum)]
+pub enum ThreadQos {
+    #[default]
+    Default,
+    Utility,
+    Background,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PoolSettings {
+    num_threads: u32,
+    qos: ThreadQos,
+}
+
+const DEFAULT_SETTINGS: PoolSettings = PoolSettings {
+    num_threads: 0,
+    qos: ThreadQos::Default,
+};
+
+static SETTINGS: Mutex<PoolSettings> = Mutex::new(DEFAULT_SETTINGS);
+static POOL: RwLock<Option<Arc<ThreadPool>>> = RwLock::new(None);
+static QOS_POOLS: Mutex<Option<HashMap<ThreadQos, Arc<ThreadPool>>>> = Mutex::new(None);
+
+#[cfg(target_vendor = "apple")]
+fn apply_qos(qos: ThreadQos) {
+    let class = match qos {
+        ThreadQos::Default => return,
+        ThreadQos::Utility => libc::qos_class_t::QOS_CLASS_UTILITY,
+        ThreadQos::Background => libc::qos_class_t::QOS_CLASS_BACKGROUND,
+    };
+    unsafe {
+        libc::pthread_set_qos_class_self_np(class, 0);
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn apply_qos(qos: ThreadQos) {
+    let nice = match qos {
+        ThreadQos::Default => return,
+        ThreadQos::Utility => 10,
+        ThreadQos::Background => 19,
+    };
+    // On Linux, PRIO_PROCESS with who = 0 targets the calling thread only.
+    unsafe {
+        libc::setpriority(libc::PRIO_PROCESS, 0, nice);
+    }
+}
+
+#[cfg(not(any(target_vendor = "apple", target_os = "linux", target_os = "android")))]
+fn apply_qos(_qos: ThreadQos) {}
+
+fn build_pool(settings: PoolSettings) -> Result<ThreadPool, HnswError> {
+    let qos = settings.qos;
+    ThreadPoolBuilder::new()
+        .num_threads(settings.num_threads as usize)
+        .thread_name(|i| format!("hnsw-worker-{i}"))
+        .start_handler(move |_| apply_qos(qos))
+        .build()
+        .map_err(|e| HnswError::ThreadPoolError(e.to_string()))
+}
+
+fn update_settings(update: impl FnOnce(&mut PoolSettings)) -> Result<(), HnswError> {
+    let mut settings = SETTINGS.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut next = *settings;
+    update(&mut next);
+    let pool = if next == DEFAULT_SETTINGS {
+        None
+    } else {
+        Some(Arc::new(build_pool(next)?))
+    };
+    *POOL.write().unwrap_or_else(PoisonError::into_inner) = pool;
+    *QOS_POOLS.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    *settings = next;
+    Ok(())
+}
+
+fn qos_pool(qos: ThreadQos) -> Result<Arc<ThreadPool>, HnswError> {
+    let num_threads = SETTINGS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .num_threads;
+    let mut pools = QOS_POOLS.lock().unwrap_or_else(PoisonError::into_inner);
+    let pools = pools.get_or_insert_with(HashMap::new);
+    if let Some(pool) = pools.get(&qos) {
+        return Ok(Arc::clone(pool));
+    }
+    let pool = Arc::new(build_pool(PoolSettings { num_threads, qos })?);
+    pools.insert(qos, Arc::clone(&pool));
+    Ok(pool)
+}
+
+pub(crate) fn install<OP, R>(op: OP) -> R
+where
+    OP: FnOnce() -> R + Send,
+    R: Send,
+{
+    let pool = POOL.read().unwrap_or_else(PoisonError::into_inner).clone();
+    match pool {
+        Some(pool) => pool.install(op),
+        None => op(),
+    }
+}
+
+// Runs `op` on workers at the requested priority, overriding the module-level
+// QoS for this call only. The caller blocks until `op` returns.
+pub(crate) fn install_with_qos<OP, R>(qos: ThreadQos, op: OP) -> Result<R, HnswError>
+where
+    OP: FnOnce() -> R + Send,
+    R: Send,
+{
+    if qos == ThreadQos::Default {
+        return Ok(install(op));
+    }
+    Ok(qos_pool(qos)?.install(op))
+}
+
+#[uniffi::export]
+pub fn set_num_threads(num_threads: u32) -> Result<(), HnswError> {
+    update_settings(|settings| settings.num_threads = num_threads)
+}
+
+#[uniffi::export]
+pub fn set_thread_qos(qos: ThreadQos) -> Result<(), HnswError> {
+    update_settings(|settings| settings.qos = qos)
+}
+
+#[uniffi::export]
+pub fn get_num_threads() -> u32 {
+    let pool = POOL.read().unwrap_or_else(PoisonError::into_inner).clone();
+    match pool {
+        Some(pool) => pool.current_num_threads() as u32,
+        None => rayon::current_num_threads() as u32,
+    }
+}
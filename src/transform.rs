@@ -0,0 +1,102 @@
+use std::sync::{Arc, PoisonError};
+
+use crate::{HnswError, HnswIndex};
+
+// y = matrix · x + bias, with one matrix row per output dimension.
+pub(crate) struct QueryTransform {
+    rows: Vec<Vec<f32>>,
+    bias: Vec<f32>,
+}
+
+impl QueryTransform {
+    fn input_dimension(&self) -> usize {
+        self.rows[0].len()
+    }
+
+    fn apply(&self, x: &[f32]) -> Vec<f32> {
+        self.rows
+            .iter()
+            .zip(&self.bias)
+            .map(|(row, b)| row.iter().zip(x).map(|(m, x)| m * x).sum::<f32>() + b)
+            .collect()
+    }
+}
+
+impl HnswIndex {
+    // prepare for queries: the query transform, if any, runs first.
+    pub(crate) fn prepare_query(
+        &self,
+        query: Vec<f32>,
+        index: usize,
+    ) -> Result<Vec<f32>, HnswError> {
+        let transform = self
+            .query_transform
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        let Some(transform) = transform else {
+            return self.prepare(query, index);
+        };
+        if query.len() != transform.input_dimension() {
+            return Err(HnswError::DimensionMismatch {
+                expected: transform.input_dimension() as u32,
+                got: query.len() as u32,
+            });
+        }
+        self.prepare(transform.apply(&query), index)
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Maps queries into the space of the stored vectors before every search,
+    // for retrieval models whose query and document encoders differ. Each of
+    // `matrix`'s rows gives one output dimension, so it needs as many rows as
+    // inserted vectors have dimensions; queries then have the rows' length.
+    // Inserts are unaffected. Not saved with the index.
+    #[uniffi::method]
+    pub fn set_query_transform(
+        &self,
+        matrix: Vec<Vec<f32>>,
+        bias: Vec<f32>,
+    ) -> Result<(), HnswError> {
+        let output = self.input_dimension();
+        if matrix.len() != output as usize {
+            return Err(HnswError::DimensionMismatch {
+                expected: output,
+                got: matrix.len() as u32,
+            });
+        }
+        if bias.len() != matrix.len() {
+            return Err(HnswError::DimensionMismatch {
+                expected: output,
+                got: bias.len() as u32,
+            });
+        }
+        let input = matrix[0].len();
+        if input == 0 || matrix.iter().any(|row| row.len() != input) {
+            return Err(HnswError::InvalidArgument(
+                "Transform rows must all have the same, non-zero length".to_string(),
+            ));
+        }
+        if matrix.iter().flatten().chain(&bias).any(|x| !x.is_finite()) {
+            return Err(HnswError::InvalidArgument(
+                "Transform contains NaN or infinity".to_string(),
+            ));
+        }
+        *self
+            .query_transform
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) =
+            Some(Arc::new(QueryTransform { rows: matrix, bias }));
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn clear_query_transform(&self) {
+        *self
+            .query_transform
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
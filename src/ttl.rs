@@ -0,0 +1,101 @@
+use std::sync::PoisonError;
+
+use hnsw_rs::prelude::*;
+
+use crate::{HnswError, HnswIndex, HnswIndexInner, SearchResult, guarded};
+
+// Timestamps are opaque i64s to the index; unix seconds or milliseconds both
+// work as long as the app is consistent.
+#[uniffi::export]
+impl HnswIndex {
+    #[uniffi::method]
+    pub fn insert_with_timestamp(
+        &self,
+        data: Vec<f32>,
+        id: u64,
+        timestamp: i64,
+    ) -> Result<(), HnswError> {
+        self.insert_with(data, id, |meta| {
+            meta.timestamps.insert(id, timestamp);
+        })
+    }
+
+    #[uniffi::method]
+    pub fn set_timestamp(&self, id: u64, timestamp: Option<i64>) -> Result<(), HnswError> {
+        self.check_writable()?;
+        let _guard = self.lock_inner()?;
+        if !self
+            .ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains(&id)
+        {
+            return Err(HnswError::InvalidArgument(format!("Unknown id: {id}")));
+        }
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        match timestamp {
+            Some(timestamp) => meta.timestamps.insert(id, timestamp),
+            None => meta.timestamps.remove(&id),
+        };
+        meta.touch(&[id]);
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn get_timestamp(&self, id: u64) -> Result<Option<i64>, HnswError> {
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        Ok(meta.timestamps.get(&id).copied())
+    }
+
+    // Points without a timestamp never match.
+    #[uniffi::method]
+    pub fn search_since(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+        min_timestamp: i64,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let query = self.prepare_query(query, 0)?;
+        let guard = self.lock_inner()?;
+        let meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let filter = |id: &DataId| {
+            meta.visible(*id as u64)
+                && meta
+                    .timestamps
+                    .get(&(*id as u64))
+                    .is_some_and(|&ts| ts >= min_timestamp)
+        };
+        let filter: Option<&dyn FilterT> = Some(&filter);
+        let (k, ef_search) = (k as usize, self.resolve_ef(ef_search) as usize);
+        let results = guarded("search_since", || match &*guard {
+            HnswIndexInner::L2(inner) => inner.hnsw.search_filter(&query, k, ef_search, filter),
+            HnswIndexInner::Cosine(inner) => inner.hnsw.search_filter(&query, k, ef_search, filter),
+            HnswIndexInner::Dot(inner) => inner.hnsw.search_filter(&query, k, ef_search, filter),
+            HnswIndexInner::L1(inner) => inner.hnsw.search_filter(&query, k, ef_search, filter),
+        })?;
+        Ok(results.into_iter().map(SearchResult::from).collect())
+    }
+
+    // Removes every point stamped before `timestamp` and returns how many went.
+    // hnsw_rs can't delete in place, so this rebuilds the graph from the
+    // surviving vectors, like grow_to; run it from a maintenance task.
+    #[uniffi::method]
+    pub fn purge_older_than(&self, timestamp: i64) -> Result<u64, HnswError> {
+        self.check_writable()?;
+        let mut guard = self.lock_inner()?;
+        let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        let expired: Vec<u64> = meta
+            .timestamps
+            .iter()
+            .filter(|&(id, &ts)| ts < timestamp && known.contains(id))
+            .map(|(&id, _)| id)
+            .collect();
+        if expired.is_empty() {
+            return Ok(0);
+        }
+        self.remove_locked(&mut guard, &mut known, &mut meta, &expired)?;
+        Ok(expired.len() as u64)
+    }
+}
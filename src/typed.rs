@@ -0,0 +1,507 @@
+use std::fmt::Debug;
+use std::fs;
+use std::mem::ManuallyDrop;
+use std::path::Path;
+use std::ptr::NonNull;
+use std::sync::{Mutex, PoisonError};
+
+use hnsw_rs::api::AnnT;
+use hnsw_rs::hnsw::Hnsw;
+use hnsw_rs::hnswio::HnswIo;
+use hnsw_rs::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::{DistanceType, HnswError, HnswIndexConfig, SearchResult, guarded};
+
+// Element types other than f32 that hnsw_rs has L2 and cosine distances for.
+pub(crate) trait Element:
+    Copy + Default + PartialEq + Send + Sync + Serialize + DeserializeOwned + Debug + 'static
+{
+    // Also the sidecar extension, so a dump can't be loaded as another type.
+    const NAME: &'static str;
+
+    fn prepare(vector: &mut [Self], normalize: bool, index: usize) -> Result<(), HnswError>;
+}
+
+impl Element for f64 {
+    const NAME: &'static str = "f64";
+
+    fn prepare(vector: &mut [f64], normalize: bool, index: usize) -> Result<(), HnswError> {
+        if let Some(dim) = vector.iter().position(|x| !x.is_finite()) {
+            return Err(HnswError::InvalidVector {
+                index: index as u64,
+                reason: format!(
+                    "{} at dimension {dim}",
+                    if vector[dim].is_nan() {
+                        "NaN"
+                    } else {
+                        "Infinity"
+                    }
+                ),
+            });
+        }
+        if normalize {
+            let norm = vector.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                return Err(HnswError::ZeroVector);
+            }
+            vector.iter_mut().for_each(|x| *x /= norm);
+        }
+        Ok(())
+    }
+}
+
+impl Element for u16 {
+    const NAME: &'static str = "u16";
+
+    fn prepare(_: &mut [u16], _: bool, _: usize) -> Result<(), HnswError> {
+        Ok(())
+    }
+}
+
+impl Element for i32 {
+    const NAME: &'static str = "i32";
+
+    fn prepare(_: &mut [i32], _: bool, _: usize) -> Result<(), HnswError> {
+        Ok(())
+    }
+}
+
+struct Graph<T: Element, D: Distance<T>> {
+    hnsw: ManuallyDrop<Hnsw<'static, T, D>>,
+    io_ptr: Option<NonNull<HnswIo>>,
+}
+
+impl<T: Element, D: Distance<T>> Drop for Graph<T, D> {
+    fn drop(&mut self) {
+        unsafe {
+            ManuallyDrop::drop(&mut self.hnsw);
+            if let Some(ptr) = self.io_ptr.take() {
+                drop(Box::from_raw(ptr.as_ptr()));
+            }
+        }
+    }
+}
+
+unsafe impl<T: Element, D: Distance<T>> Send for Graph<T, D> {}
+unsafe impl<T: Element, D: Distance<T>> Sync for Graph<T, D> {}
+
+impl<T: Element, D: Distance<T> + Default + Send + Sync> Graph<T, D> {
+    fn new(config: &HnswIndexConfig) -> Self {
+        Graph {
+            hnsw: ManuallyDrop::new(Hnsw::new(
+                config.max_nb_connection as usize,
+                config.max_elements as usize,
+                config.max_layer as usize,
+                config.ef_construction as usize,
+                D::default(),
+            )),
+            io_ptr: None,
+        }
+    }
+
+    fn load(directory: &str, basename: &str) -> Result<Self, HnswError> {
+        let io = Box::new(HnswIo::new(Path::new(directory), basename));
+        let io_ptr = Box::into_raw(io);
+        let hnsw: Hnsw<'static, T, D> = unsafe {
+            (*io_ptr).load_hnsw().map_err(|e| {
+                drop(Box::from_raw(io_ptr));
+                HnswError::ReloadError(e.to_string())
+            })?
+        };
+        Ok(Graph {
+            hnsw: ManuallyDrop::new(hnsw),
+            io_ptr: NonNull::new(io_ptr),
+        })
+    }
+}
+
+enum Inner<T: Element>
+where
+    DistL2: Distance<T>,
+    DistCosine: Distance<T>,
+{
+    L2(Graph<T, DistL2>),
+    Cosine(Graph<T, DistCosine>),
+}
+
+// Written as JSON, like the bundle manifest, so fields added to the config
+// later read back as their defaults.
+#[derive(Serialize, Deserialize)]
+struct Sidecar {
+    config: HnswIndexConfig,
+}
+
+// The bincode sidecar written before that, from before the config had a
+// level scale.
+#[derive(Deserialize)]
+struct LegacySidecar {
+    max_nb_connection: u32,
+    max_elements: u64,
+    max_layer: u32,
+    ef_construction: u32,
+    dimension: u32,
+    distance: DistanceType,
+    normalize_vectors: bool,
+}
+
+impl Sidecar {
+    fn decode(bytes: &[u8]) -> Result<Self, HnswError> {
+        if let Ok(sidecar) = serde_json::from_slice(bytes) {
+            return Ok(sidecar);
+        }
+        let old: LegacySidecar =
+            bincode::deserialize(bytes).map_err(|e| HnswError::Corrupted(e.to_string()))?;
+        Ok(Sidecar {
+            config: HnswIndexConfig {
+                max_nb_connection: old.max_nb_connection,
+                max_elements: old.max_elements,
+                max_layer: old.max_layer,
+                ef_construction: old.ef_construction,
+                dimension: old.dimension,
+                distance: old.distance,
+                normalize_vectors: old.normalize_vectors,
+                level_scale: None,
+            },
+        })
+    }
+}
+
+fn sidecar_path<T: Element>(directory: &str, basename: &str) -> std::path::PathBuf {
+    Path::new(directory).join(format!("{basename}.hnsw.{}", T::NAME))
+}
+
+// The shared body of HnswF64Index, HnswU16Index and HnswI32Index, which are
+// thin wrappers because uniffi objects can't be generic. Distances are
+// reported as f32 whatever the element type, as hnsw_rs computes them.
+pub(crate) struct TypedIndex<T: Element>
+where
+    DistL2: Distance<T>,
+    DistCosine: Distance<T>,
+{
+    config: HnswIndexConfig,
+    inner: Mutex<Inner<T>>,
+}
+
+impl<T: Element> TypedIndex<T>
+where
+    DistL2: Distance<T>,
+    DistCosine: Distance<T>,
+{
+    fn check_config(config: &HnswIndexConfig, normalize: bool) -> Result<(), HnswError> {
+        if !matches!(config.distance, DistanceType::L2 | DistanceType::Cosine) {
+            return Err(HnswError::InvalidArgument(format!(
+                "{} indexes support L2 and Cosine distance, not {:?}",
+                T::NAME,
+                config.distance
+            )));
+        }
+        if config.normalize_vectors && !normalize {
+            return Err(HnswError::InvalidArgument(format!(
+                "{} vectors can't be normalized",
+                T::NAME
+            )));
+        }
+        Ok(())
+    }
+
+    // `normalize` says whether the element type can honour
+    // normalize_vectors; integer vectors can't.
+    pub(crate) fn new(config: HnswIndexConfig, normalize: bool) -> Result<Self, HnswError> {
+        Self::check_config(&config, normalize)?;
+        let inner = match config.distance {
+            DistanceType::Cosine => Inner::Cosine(Graph::new(&config)),
+            _ => Inner::L2(Graph::new(&config)),
+        };
+        Ok(Self {
+            config,
+            inner: Mutex::new(inner),
+        })
+    }
+
+    pub(crate) fn load(
+        directory: &str,
+        basename: &str,
+        normalize: bool,
+    ) -> Result<Self, HnswError> {
+        let bytes = fs::read(sidecar_path::<T>(directory, basename))?;
+        let config = Sidecar::decode(&bytes)?.config;
+        Self::check_config(&config, normalize)?;
+        let inner = match config.distance {
+            DistanceType::Cosine => Inner::Cosine(Graph::load(directory, basename)?),
+            _ => Inner::L2(Graph::load(directory, basename)?),
+        };
+        Ok(Self {
+            config,
+            inner: Mutex::new(inner),
+        })
+    }
+
+    fn prepare(&self, mut vector: Vec<T>, index: usize) -> Result<Vec<T>, HnswError> {
+        if vector.len() != self.config.dimension as usize {
+            return Err(HnswError::DimensionMismatch {
+                expected: self.config.dimension,
+                got: vector.len() as u32,
+            });
+        }
+        if self.config.distance == DistanceType::Cosine && vector.iter().all(|x| *x == T::default())
+        {
+            return Err(HnswError::InvalidVector {
+                index: index as u64,
+                reason: "zero vector has no Cosine distance".to_string(),
+            });
+        }
+        T::prepare(&mut vector, self.config.normalize_vectors, index)?;
+        Ok(vector)
+    }
+
+    pub(crate) fn insert(&self, data: Vec<T>, id: u64) -> Result<(), HnswError> {
+        let data = self.prepare(data, 0)?;
+        let inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        guarded("insert", || match &*inner {
+            Inner::L2(graph) => graph.hnsw.insert((&data, id as usize)),
+            Inner::Cosine(graph) => graph.hnsw.insert((&data, id as usize)),
+        })
+    }
+
+    pub(crate) fn insert_batch(&self, data: Vec<Vec<T>>, ids: Vec<u64>) -> Result<(), HnswError> {
+        if data.len() != ids.len() {
+            return Err(HnswError::LengthMismatch {
+                vectors: data.len() as u64,
+                ids: ids.len() as u64,
+            });
+        }
+        let data: Vec<Vec<T>> = data
+            .into_iter()
+            .enumerate()
+            .map(|(i, vec)| self.prepare(vec, i))
+            .collect::<Result<_, _>>()?;
+        let pairs: Vec<(&Vec<T>, usize)> = data
+            .iter()
+            .zip(ids)
+            .map(|(v, id)| (v, id as usize))
+            .collect();
+        let inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        guarded("insert_batch", || match &*inner {
+            Inner::L2(graph) => graph.hnsw.parallel_insert(&pairs),
+            Inner::Cosine(graph) => graph.hnsw.parallel_insert(&pairs),
+        })
+    }
+
+    pub(crate) fn search(
+        &self,
+        query: Vec<T>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let query = self.prepare(query, 0)?;
+        let inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        let neighbours = guarded("search", || match &*inner {
+            Inner::L2(graph) => graph.hnsw.search(&query, k as usize, ef_search as usize),
+            Inner::Cosine(graph) => graph.hnsw.search(&query, k as usize, ef_search as usize),
+        })?;
+        Ok(neighbours.into_iter().map(SearchResult::from).collect())
+    }
+
+    pub(crate) fn len(&self) -> u64 {
+        let inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        let len = match &*inner {
+            Inner::L2(graph) => graph.hnsw.get_nb_point(),
+            Inner::Cosine(graph) => graph.hnsw.get_nb_point(),
+        };
+        len as u64
+    }
+
+    pub(crate) fn dimension(&self) -> u32 {
+        self.config.dimension
+    }
+
+    pub(crate) fn save(&self, directory: &str, basename: &str) -> Result<(), HnswError> {
+        let inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        match &*inner {
+            Inner::L2(graph) => graph.hnsw.file_dump(Path::new(directory), basename),
+            Inner::Cosine(graph) => graph.hnsw.file_dump(Path::new(directory), basename),
+        }
+        .map_err(|e| HnswError::DumpError(e.to_string()))?;
+        let bytes = serde_json::to_vec(&Sidecar {
+            config: self.config,
+        })
+        .map_err(|e| HnswError::DumpError(e.to_string()))?;
+        fs::write(sidecar_path::<T>(directory, basename), bytes)?;
+        Ok(())
+    }
+}
+
+// Double-precision counterpart of HnswIndex, for numerical data that loses
+// too much in f32. L2 and Cosine only.
+#[derive(uniffi::Object)]
+pub struct HnswF64Index(TypedIndex<f64>);
+
+#[uniffi::export]
+impl HnswF64Index {
+    #[uniffi::constructor]
+    pub fn new(config: HnswIndexConfig) -> Result<Self, HnswError> {
+        TypedIndex::new(config, true).map(Self)
+    }
+
+    #[uniffi::constructor]
+    pub fn load(directory: String, basename: String) -> Result<Self, HnswError> {
+        TypedIndex::load(&directory, &basename, true).map(Self)
+    }
+
+    #[uniffi::method]
+    pub fn insert(&self, data: Vec<f64>, id: u64) -> Result<(), HnswError> {
+        self.0.insert(data, id)
+    }
+
+    #[uniffi::method]
+    pub fn insert_batch(&self, data: Vec<Vec<f64>>, ids: Vec<u64>) -> Result<(), HnswError> {
+        self.0.insert_batch(data, ids)
+    }
+
+    #[uniffi::method]
+    pub fn search(
+        &self,
+        query: Vec<f64>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        self.0.search(query, k, ef_search)
+    }
+
+    #[uniffi::method]
+    pub fn len(&self) -> Result<u64, HnswError> {
+        Ok(self.0.len())
+    }
+
+    #[uniffi::method]
+    pub fn is_empty(&self) -> Result<bool, HnswError> {
+        Ok(self.0.len() == 0)
+    }
+
+    #[uniffi::method]
+    pub fn get_dimension(&self) -> u32 {
+        self.0.dimension()
+    }
+
+    #[uniffi::method]
+    pub fn save(&self, directory: String, basename: String) -> Result<(), HnswError> {
+        self.0.save(&directory, &basename)
+    }
+}
+
+// Integer vectors, e.g. already-quantized features or counts, at a half
+// (u16) or the same (i32) size as f32 without converting them. L2 and Cosine
+// only, and normalize_vectors must be off.
+#[derive(uniffi::Object)]
+pub struct HnswU16Index(TypedIndex<u16>);
+
+#[uniffi::export]
+impl HnswU16Index {
+    #[uniffi::constructor]
+    pub fn new(config: HnswIndexConfig) -> Result<Self, HnswError> {
+        TypedIndex::new(config, false).map(Self)
+    }
+
+    #[uniffi::constructor]
+    pub fn load(directory: String, basename: String) -> Result<Self, HnswError> {
+        TypedIndex::load(&directory, &basename, false).map(Self)
+    }
+
+    #[uniffi::method]
+    pub fn insert(&self, data: Vec<u16>, id: u64) -> Result<(), HnswError> {
+        self.0.insert(data, id)
+    }
+
+    #[uniffi::method]
+    pub fn insert_batch(&self, data: Vec<Vec<u16>>, ids: Vec<u64>) -> Result<(), HnswError> {
+        self.0.insert_batch(data, ids)
+    }
+
+    #[uniffi::method]
+    pub fn search(
+        &self,
+        query: Vec<u16>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        self.0.search(query, k, ef_search)
+    }
+
+    #[uniffi::method]
+    pub fn len(&self) -> Result<u64, HnswError> {
+        Ok(self.0.len())
+    }
+
+    #[uniffi::method]
+    pub fn is_empty(&self) -> Result<bool, HnswError> {
+        Ok(self.0.len() == 0)
+    }
+
+    #[uniffi::method]
+    pub fn get_dimension(&self) -> u32 {
+        self.0.dimension()
+    }
+
+    #[uniffi::method]
+    pub fn save(&self, directory: String, basename: String) -> Result<(), HnswError> {
+        self.0.save(&directory, &basename)
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct HnswI32Index(TypedIndex<i32>);
+
+#[uniffi::export]
+impl HnswI32Index {
+    #[uniffi::constructor]
+    pub fn new(config: HnswIndexConfig) -> Result<Self, HnswError> {
+        TypedIndex::new(config, false).map(Self)
+    }
+
+    #[uniffi::constructor]
+    pub fn load(directory: String, basename: String) -> Result<Self, HnswError> {
+        TypedIndex::load(&directory, &basename, false).map(Self)
+    }
+
+    #[uniffi::method]
+    pub fn insert(&self, data: Vec<i32>, id: u64) -> Result<(), HnswError> {
+        self.0.insert(data, id)
+    }
+
+    #[uniffi::method]
+    pub fn insert_batch(&self, data: Vec<Vec<i32>>, ids: Vec<u64>) -> Result<(), HnswError> {
+        self.0.insert_batch(data, ids)
+    }
+
+    #[uniffi::method]
+    pub fn search(
+        &self,
+        query: Vec<i32>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        self.0.search(query, k, ef_search)
+    }
+
+    #[uniffi::method]
+    pub fn len(&self) -> Result<u64, HnswError> {
+        Ok(self.0.len())
+    }
+
+    #[uniffi::method]
+    pub fn is_empty(&self) -> Result<bool, HnswError> {
+        Ok(self.0.len() == 0)
+    }
+
+    #[uniffi::method]
+    pub fn get_dimension(&self) -> u32 {
+        self.0.dimension()
+    }
+
+    #[uniffi::method]
+    pub fn save(&self, directory: String, basename: String) -> Result<(), HnswError> {
+        self.0.save(&directory, &basename)
+    }
+}
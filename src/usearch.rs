@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+
+use hnsw_rs::hnsw::Hnsw;
+use hnsw_rs::prelude::*;
+
+use crate::graph::entry_point_id;
+use crate::{DistanceType, HnswError, HnswIndex, HnswIndexConfig, HnswIndexInner, graph_points};
+
+// Layout of a USearch v2 dense index file (index_dense_gt::save):
+//   u32 rows, u32 bytes per vector, then the vectors in slot order
+//   64-byte dense header
+//   graph header: size, connectivity, connectivity_base, max_level, entry_slot
+//   i16 level per node, then one tape per node:
+//     u64 key, i16 level, then per layer a u32 count and a fixed slot array
+const MAGIC: &[u8; 7] = b"usearch";
+const HEADER_BYTES: usize = 64;
+const SCALAR_F32: u8 = 11;
+const SCALAR_U32: u8 = 15;
+const SCALAR_U64: u8 = 14;
+// USearch marks removed slots with this key.
+const FREE_KEY: u64 = u64::MAX;
+// USearch doesn't persist its expansion factor; this is its default.
+const DEFAULT_EF_CONSTRUCTION: u32 = 128;
+const BATCH_ROWS: usize = 1024;
+
+fn invalid(message: impl Into<String>) -> HnswError {
+    HnswError::InvalidArgument(message.into())
+}
+
+fn metric_code(distance: DistanceType) -> Result<u8, HnswError> {
+    match distance {
+        DistanceType::L2 => Ok(b'e'),
+        DistanceType::Cosine => Ok(b'c'),
+        DistanceType::Dot => Ok(b'i'),
+        DistanceType::L1 => Err(invalid("USearch has no L1 metric")),
+    }
+}
+
+fn metric(code: u8) -> Result<DistanceType, HnswError> {
+    match code {
+        b'e' => Ok(DistanceType::L2),
+        b'c' => Ok(DistanceType::Cosine),
+        b'i' => Ok(DistanceType::Dot),
+        other => Err(invalid(format!(
+            "unsupported USearch metric: {}",
+            other as char
+        ))),
+    }
+}
+
+fn read_bytes<const N: usize>(reader: &mut impl Read) -> Result<[u8; N], HnswError> {
+    let mut buf = [0u8; N];
+    reader.read_exact(&mut buf)?;
+    Ok(buf)
+}
+
+fn read_u64(reader: &mut impl Read) -> Result<usize, HnswError> {
+    usize::try_from(u64::from_le_bytes(read_bytes(reader)?))
+        .map_err(|_| invalid("USearch header value out of range"))
+}
+
+struct Node {
+    key: u64,
+    level: usize,
+    vector: Vec<f32>,
+    layers: Vec<Vec<u64>>,
+}
+
+fn graph_nodes<D>(hnsw: &Hnsw<'static, f32, D>) -> (Vec<Node>, Option<u64>)
+where
+    D: Distance<f32> + Send + Sync,
+{
+    let nodes = graph_points(hnsw)
+        .map(|point| {
+            let level = point.get_point_id().0 as usize;
+            let layers = point
+                .get_neighborhood_id()
+                .into_iter()
+                .take(level + 1)
+                .map(|links| links.iter().map(|n| n.d_id as u64).collect())
+                .collect();
+            Node {
+                key: point.get_origin_id() as u64,
+                level,
+                vector: point.get_v().to_vec(),
+                layers,
+            }
+        })
+        .collect();
+    (nodes, entry_point_id(hnsw))
+}
+
+fn write_layer(
+    out: &mut impl Write,
+    links: &[u64],
+    capacity: usize,
+    slots: &HashMap<u64, u32>,
+) -> Result<(), HnswError> {
+    let links: Vec<u32> = links
+        .iter()
+        .filter_map(|id| slots.get(id).copied())
+        .take(capacity)
+        .collect();
+    out.write_all(&(links.len() as u32).to_le_bytes())?;
+    for slot in links
+        .iter()
+        .copied()
+        .chain(std::iter::repeat(0))
+        .take(capacity)
+    {
+        out.write_all(&slot.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // The graph is translated link for link, so USearch can serve the file
+    // without rebuilding. Vectors are written as stored, i.e. after
+    // normalization and dimension reduction.
+    #[uniffi::method]
+    pub fn save_usearch(&self, path: String) -> Result<(), HnswError> {
+        let metric = metric_code(self.distance)?;
+        let (nodes, entry) = {
+            let guard = self.lock_purged()?;
+            match &*guard {
+                HnswIndexInner::L2(inner) => graph_nodes(&inner.hnsw),
+                HnswIndexInner::Cosine(inner) => graph_nodes(&inner.hnsw),
+                HnswIndexInner::Dot(inner) => graph_nodes(&inner.hnsw),
+                HnswIndexInner::L1(inner) => graph_nodes(&inner.hnsw),
+            }
+        };
+        let slots: HashMap<u64, u32> = nodes
+            .iter()
+            .enumerate()
+            .map(|(slot, node)| (node.key, slot as u32))
+            .collect();
+        let dimension = self.dimension as usize;
+        // hnsw_rs allows twice as many links on layer 0 as above it.
+        let connectivity = self.config.max_nb_connection as usize;
+        let connectivity_base = connectivity * 2;
+        let max_level = nodes.iter().map(|node| node.level).max().unwrap_or(0);
+        let entry_slot = entry.and_then(|id| slots.get(&id)).copied().unwrap_or(0);
+
+        let mut out = BufWriter::new(File::create(path)?);
+        out.write_all(&(nodes.len() as u32).to_le_bytes())?;
+        out.write_all(&((dimension * 4) as u32).to_le_bytes())?;
+        for node in &nodes {
+            for x in &node.vector {
+                out.write_all(&x.to_le_bytes())?;
+            }
+        }
+
+        let mut header = [0u8; HEADER_BYTES];
+        header[..7].copy_from_slice(MAGIC);
+        header[7..9].copy_from_slice(&2u16.to_le_bytes());
+        header[13] = metric;
+        header[14] = SCALAR_F32;
+        header[15] = SCALAR_U64;
+        header[16] = SCALAR_U32;
+        header[17..25].copy_from_slice(&(nodes.len() as u64).to_le_bytes());
+        header[33..41].copy_from_slice(&(dimension as u64).to_le_bytes());
+        out.write_all(&header)?;
+
+        for value in [
+            nodes.len(),
+            connectivity,
+            connectivity_base,
+            max_level,
+            entry_slot as usize,
+        ] {
+            out.write_all(&(value as u64).to_le_bytes())?;
+        }
+        for node in &nodes {
+            out.write_all(&(node.level as i16).to_le_bytes())?;
+        }
+        for node in &nodes {
+            out.write_all(&node.key.to_le_bytes())?;
+            out.write_all(&(node.level as i16).to_le_bytes())?;
+            for layer in 0..=node.level {
+                let links = node.layers.get(layer).map_or(&[][..], Vec::as_slice);
+                let capacity = if layer == 0 {
+                    connectivity_base
+                } else {
+                    connectivity
+                };
+                write_layer(&mut out, links, capacity, &slots)?;
+            }
+        }
+        out.flush()?;
+        Ok(())
+    }
+
+    // hnsw_rs can't adopt a foreign graph, so the vectors and keys are read
+    // back and the graph is rebuilt with the file's connectivity. Removed
+    // slots are skipped. Every size read from the file is checked against
+    // the file's length before anything is allocated from it, so a corrupt
+    // or foreign file is an error rather than a huge allocation.
+    #[uniffi::constructor]
+    pub fn load_usearch(path: String) -> Result<Self, HnswError> {
+        let file = File::open(path)?;
+        let file_len = usize::try_from(file.metadata()?.len()).unwrap_or(usize::MAX);
+        let fits = |bytes: Option<usize>| bytes.is_some_and(|bytes| bytes <= file_len);
+        let mut reader = BufReader::new(file);
+        let rows = u32::from_le_bytes(read_bytes(&mut reader)?) as usize;
+        let row_bytes = u32::from_le_bytes(read_bytes(&mut reader)?) as usize;
+        let vector_bytes = rows.checked_mul(row_bytes);
+        if !fits(vector_bytes.and_then(|bytes| bytes.checked_add(8 + HEADER_BYTES))) {
+            return Err(invalid(
+                "not a USearch index: vector block runs past the end of the file",
+            ));
+        }
+        let vector_bytes = vector_bytes.unwrap_or_default();
+
+        // The dense header follows the vectors; check it before reading them.
+        reader.seek(SeekFrom::Start(8 + vector_bytes as u64))?;
+        let header: [u8; HEADER_BYTES] = read_bytes(&mut reader)?;
+        if &header[..7] != MAGIC {
+            return Err(invalid("not a USearch index"));
+        }
+        if header[14] != SCALAR_F32 || header[15] != SCALAR_U64 || header[16] != SCALAR_U32 {
+            return Err(invalid(
+                "only f32 USearch indices with u64 keys and u32 slots are supported",
+            ));
+        }
+        let distance = metric(header[13])?;
+        let dimension = u64::from_le_bytes(header[33..41].try_into().unwrap_or_default());
+        if dimension == 0 || row_bytes != dimension as usize * 4 {
+            return Err(invalid(format!(
+                "USearch vectors are {row_bytes} bytes, expected {} f32 values",
+                dimension
+            )));
+        }
+        reader.seek(SeekFrom::Start(8))?;
+        let mut vectors = vec![0u8; vector_bytes];
+        reader.read_exact(&mut vectors)?;
+        reader.seek(SeekFrom::Current(HEADER_BYTES as i64))?;
+
+        let size = read_u64(&mut reader)?;
+        let connectivity = read_u64(&mut reader)?;
+        let connectivity_base = read_u64(&mut reader)?;
+        read_u64(&mut reader)?;
+        read_u64(&mut reader)?;
+        if size != rows {
+            return Err(invalid(format!(
+                "USearch graph has {size} nodes for {rows} vectors"
+            )));
+        }
+        let base_bytes = connectivity_base
+            .checked_mul(4)
+            .and_then(|n| n.checked_add(4));
+        let layer_bytes = connectivity.checked_mul(4).and_then(|n| n.checked_add(4));
+        if connectivity == 0
+            || u32::try_from(connectivity).is_err()
+            || !fits(base_bytes)
+            || !fits(layer_bytes)
+        {
+            return Err(invalid(format!(
+                "USearch connectivity {connectivity}/{connectivity_base} doesn't fit the file"
+            )));
+        }
+        let (base_bytes, layer_bytes) = (
+            base_bytes.unwrap_or_default(),
+            layer_bytes.unwrap_or_default(),
+        );
+        let mut levels = Vec::with_capacity(size);
+        for _ in 0..size {
+            let level = i16::from_le_bytes(read_bytes(&mut reader)?);
+            let level = usize::try_from(level).map_err(|_| invalid("negative node level"))?;
+            let tape = layer_bytes
+                .checked_mul(level)
+                .and_then(|n| n.checked_add(2 + base_bytes));
+            if !fits(tape) {
+                return Err(invalid("USearch node runs past the end of the file"));
+            }
+            levels.push(level);
+        }
+
+        let index = HnswIndex::new(HnswIndexConfig {
+            max_nb_connection: connectivity as u32,
+            max_elements: size as u64,
+            max_layer: 16,
+            ef_construction: DEFAULT_EF_CONSTRUCTION,
+            dimension: dimension as u32,
+            distance,
+            normalize_vectors: false,
+            level_scale: None,
+        });
+        let mut data = Vec::with_capacity(BATCH_ROWS);
+        let mut ids = Vec::with_capacity(BATCH_ROWS);
+        let mut tape = Vec::new();
+        for (slot, level) in levels.into_iter().enumerate() {
+            let key = u64::from_le_bytes(read_bytes(&mut reader)?);
+            tape.resize(2 + base_bytes + layer_bytes * level, 0);
+            reader.read_exact(&mut tape)?;
+            if key == FREE_KEY {
+                continue;
+            }
+            let row = &vectors[slot * row_bytes..(slot + 1) * row_bytes];
+            data.push(
+                row.as_chunks::<4>()
+                    .0
+                    .iter()
+                    .map(|&b| f32::from_le_bytes(b))
+                    .collect(),
+            );
+            ids.push(key);
+            if data.len() == BATCH_ROWS {
+                index.insert_batch(std::mem::take(&mut data), std::mem::take(&mut ids))?;
+            }
+        }
+        if !data.is_empty() {
+            index.insert_batch(data, ids)?;
+        }
+        Ok(index)
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::{Arc, PoisonError};
+
+use hnsw_rs::hnsw::Hnsw;
+use hnsw_rs::prelude::*;
+
+use crate::{DistanceType, HnswError, HnswIndex, HnswIndexInner, SearchResult, guarded, simd};
+
+// Candidates taken from the index's graph per requested result.
+const VIEW_OVERSAMPLE: usize = 4;
+
+fn rerank<D>(
+    hnsw: &Hnsw<'static, f32, D>,
+    hits: &[Neighbour],
+    query: &[f32],
+    distance: DistanceType,
+) -> Vec<SearchResult>
+where
+    D: Distance<f32> + Send + Sync,
+{
+    // Each hit carries its PointId, so its vector is one indexed lookup away.
+    let indexation = hnsw.get_point_indexation();
+    hits.iter()
+        .filter_map(|hit| {
+            let vector = indexation.get_point_data(&hit.p_id)?;
+            let dist = simd::eval(distance, query, &vector);
+            Some(SearchResult::new(hit.d_id as u64, dist))
+        })
+        .collect()
+}
+
+// Searches an index's points under another distance. hnsw_rs graphs own
+// their vectors, so a second graph would copy every one of them; instead a
+// view walks the index's own graph for candidates and ranks them by its
+// distance, reading the vectors in place. That works well between metrics
+// that mostly agree on neighbourhoods, like cosine and dot, and needs a
+// larger ef_search the more they disagree.
+#[derive(uniffi::Object)]
+pub struct HnswIndexView {
+    index: Arc<HnswIndex>,
+    distance: DistanceType,
+}
+
+#[uniffi::export]
+impl HnswIndexView {
+    #[uniffi::method]
+    pub fn distance(&self) -> DistanceType {
+        self.distance
+    }
+
+    #[uniffi::method]
+    pub fn search(
+        &self,
+        query: Vec<f32>,
+        k: u32,
+        ef_search: u32,
+    ) -> Result<Vec<SearchResult>, HnswError> {
+        let query = self.index.prepare_query(query, 0)?;
+        let guard = self.index.lock_inner()?;
+        let meta = self
+            .index
+            .meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let filter = |id: &DataId| meta.visible(*id as u64);
+        let filter: Option<&dyn FilterT> = Some(&filter);
+        let fetch = k as usize * VIEW_OVERSAMPLE;
+        let ef = (self.index.resolve_ef(ef_search) as usize).max(fetch);
+        let distance = self.distance;
+        let mut results = guarded("view_search", || match &*guard {
+            HnswIndexInner::L2(inner) => {
+                let hits = inner.hnsw.search_filter(&query, fetch, ef, filter);
+                rerank(&inner.hnsw, &hits, &query, distance)
+            }
+            HnswIndexInner::Cosine(inner) => {
+                let hits = inner.hnsw.search_filter(&query, fetch, ef, filter);
+                rerank(&inner.hnsw, &hits, &query, distance)
+            }
+            HnswIndexInner::Dot(inner) => {
+                let hits = inner.hnsw.search_filter(&query, fetch, ef, filter);
+                rerank(&inner.hnsw, &hits, &query, distance)
+            }
+            HnswIndexInner::L1(inner) => {
+                let hits = inner.hnsw.search_filter(&query, fetch, ef, filter);
+                rerank(&inner.hnsw, &hits, &query, distance)
+            }
+        })?;
+        drop((meta, guard));
+        results.sort_by(|a, b| a.distance.total_cmp(&b.distance).then(a.id.cmp(&b.id)));
+        results.truncate(k as usize);
+        Ok(results)
+    }
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // A view ranking this index's points by `distance`. It shares the
+    // vectors and graph, so inserts and removals show up in it immediately.
+    // There is no graph built for `distance`: candidates come from this
+    // index's own metric and are only reordered, so a point the index's
+    // metric ranks far away is never found. Recall under the view's distance
+    // depends on how much the two metrics agree.
+    #[uniffi::method]
+    pub fn create_view(self: Arc<Self>, distance: DistanceType) -> Arc<HnswIndexView> {
+        Arc::new(HnswIndexView {
+            index: self,
+            distance,
+        })
+    }
+}
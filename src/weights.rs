@@ -0,0 +1,105 @@
+use std::sync::PoisonError;
+use std::sync::atomic::Ordering;
+
+use crate::{
+    DistanceType, HnswError, HnswIndex, HnswIndexConfig, HnswIndexInner, guarded, normalize_vector,
+    threads,
+};
+
+pub(crate) fn apply(vector: &mut [f32], weights: &[f32]) {
+    vector
+        .iter_mut()
+        .zip(weights)
+        .for_each(|(x, w)| *x *= w.sqrt());
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // Weights each dimension's contribution to the distance, e.g. to quiet
+    // dimensions known to be noisy. Stored vectors are rescaled and the graph
+    // rebuilt from them, so nothing needs re-embedding; None goes back to
+    // equal weights. Weights must be positive. L2 and cosine only, and with
+    // a reducer attached they apply to the reduced dimensions.
+    #[uniffi::method]
+    pub fn set_dimension_weights(&self, weights: Option<Vec<f32>>) -> Result<(), HnswError> {
+        self.check_writable()?;
+        if !matches!(self.distance, DistanceType::L2 | DistanceType::Cosine) {
+            return Err(HnswError::InvalidArgument(
+                "Dimension weights need L2 or cosine distance".to_string(),
+            ));
+        }
+        if let Some(weights) = &weights {
+            if weights.len() != self.dimension as usize {
+                return Err(HnswError::DimensionMismatch {
+                    expected: self.dimension,
+                    got: weights.len() as u32,
+                });
+            }
+            if weights.iter().any(|w| !w.is_finite() || *w <= 0.0) {
+                return Err(HnswError::InvalidArgument(
+                    "Dimension weights must be positive".to_string(),
+                ));
+            }
+        }
+
+        let mut guard = self.lock_inner()?;
+        let known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+        // The old weights stay in place until the rebuilt graph does, so a
+        // failure leaves the index as it was.
+        let old = meta.weights.clone();
+        let ratios: Vec<f32> = (0..self.dimension as usize)
+            .map(|i| {
+                let old = old.as_ref().map_or(1.0, |w| w[i]);
+                let new = weights.as_ref().map_or(1.0, |w| w[i]);
+                new / old
+            })
+            .collect();
+        if ratios.iter().any(|&r| r != 1.0) && !known.is_empty() {
+            // The rebuild leaves tombstoned points behind.
+            let mut points = guard.points();
+            points.retain(|(id, _)| known.contains(id));
+            for (_, vector) in &mut points {
+                apply(vector, &ratios);
+                if self.normalize {
+                    normalize_vector(vector)?;
+                }
+            }
+            let config = HnswIndexConfig {
+                max_elements: self.capacity.load(Ordering::Relaxed),
+                ..self.config
+            };
+            let mut rebuilt = HnswIndexInner::new(config);
+            rebuilt.set_build_options(self.build_options());
+            let pairs: Vec<(&Vec<f32>, usize)> =
+                points.iter().map(|(id, v)| (v, *id as usize)).collect();
+            guarded("set_dimension_weights", || {
+                threads::install(|| match &rebuilt {
+                    HnswIndexInner::L2(inner) => inner.hnsw.parallel_insert(&pairs),
+                    HnswIndexInner::Cosine(inner) => inner.hnsw.parallel_insert(&pairs),
+                    HnswIndexInner::Dot(inner) => inner.hnsw.parallel_insert(&pairs),
+                    HnswIndexInner::L1(inner) => inner.hnsw.parallel_insert(&pairs),
+                })
+            })?;
+            *guard = rebuilt;
+            meta.tombstones.clear();
+            self.mmapped.store(false, Ordering::Relaxed);
+            let ids: Vec<u64> = known.iter().copied().collect();
+            meta.touch(&ids);
+        }
+        meta.weights = weights;
+        drop((meta, known, guard));
+        self.invalidate_sketches();
+        self.invalidate_points();
+        Ok(())
+    }
+
+    #[uniffi::method]
+    pub fn get_dimension_weights(&self) -> Option<Vec<f32>> {
+        self.meta
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .weights
+            .clone()
+    }
+}
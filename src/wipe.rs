@@ -0,0 +1,127 @@
+use std::fs::{self, OpenOptions};
+use std::hint::black_box;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::PoisonError;
+use std::sync::atomic::Ordering;
+
+use crate::attrs::AttrValue;
+use crate::lock::DumpLock;
+use crate::observer::Event;
+use crate::{HnswError, HnswIndex, HnswIndexConfig, HnswIndexInner, PointMeta};
+
+// Everything this library writes for `basename`: the dump, its sidecars,
+// the lock file and any staging files a failed save left behind.
+fn dump_files(directory: &str, basename: &str) -> Result<Vec<PathBuf>, HnswError> {
+    let prefixes = [format!("{basename}.hnsw."), format!("{basename}.staging")];
+    let mut files = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if entry.file_type()?.is_file() && prefixes.iter().any(|p| name.starts_with(p.as_str())) {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+// Overwrites with zeros and syncs before unlinking. On copy-on-write and
+// flash storage the old blocks can outlive the overwrite; there it is the
+// deletion that protects the data, as iOS and Android discard a deleted
+// file's encryption key.
+fn shred(path: &Path) -> Result<(), HnswError> {
+    let mut left = fs::metadata(path)?.len();
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    let zeros = vec![0u8; 64 << 10];
+    while left > 0 {
+        let n = left.min(zeros.len() as u64) as usize;
+        file.write_all(&zeros[..n])?;
+        left -= n as u64;
+    }
+    file.sync_all()?;
+    drop(file);
+    fs::remove_file(path)?;
+    Ok(())
+}
+
+fn zeroize(bytes: &mut [u8]) {
+    bytes.fill(0);
+    // Keeps the writes from being optimized away as dead stores.
+    black_box(bytes);
+}
+
+// Shreds every file of the dump `basename` in `directory`, for when no index
+// is open on it. Returns the number of files removed.
+#[uniffi::export]
+pub fn destroy_dump(directory: String, basename: String) -> Result<u64, HnswError> {
+    let lock = DumpLock::exclusive(&directory, &basename)?;
+    let lock_file = Path::new(&directory).join(format!("{basename}.hnsw.lock"));
+    let files = dump_files(&directory, &basename)?;
+    let mut removed = 0;
+    for path in files.iter().filter(|path| **path != lock_file) {
+        shred(path)?;
+        removed += 1;
+    }
+    drop(lock);
+    if lock_file.exists() {
+        fs::remove_file(lock_file)?;
+    }
+    Ok(removed)
+}
+
+#[uniffi::export]
+impl HnswIndex {
+    // For logout and similar: empties the index in memory and shreds its dump
+    // `basename` in `directory`. Payloads, keys and text attributes are zeroed
+    // before they are freed. hnsw_rs gives no mutable access to the vectors
+    // it stores, so the graph is dropped rather than overwritten. The index
+    // stays usable, empty, afterwards. Returns the number of files removed.
+    #[uniffi::method]
+    pub fn destroy(&self, directory: String, basename: String) -> Result<u64, HnswError> {
+        {
+            let mut source = self.source.lock().unwrap_or_else(PoisonError::into_inner);
+            let mut guard = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+            let mut lazy = self.lazy.lock().unwrap_or_else(PoisonError::into_inner);
+            let mut known = self.ids.lock().unwrap_or_else(PoisonError::into_inner);
+            let mut meta = self.meta.lock().unwrap_or_else(PoisonError::into_inner);
+            *guard = HnswIndexInner::new(HnswIndexConfig {
+                max_elements: self.capacity.load(Ordering::Relaxed),
+                ..self.config
+            });
+            guard.set_build_options(self.build_options());
+            let meta = &mut *meta;
+            for payload in meta.payloads.values_mut() {
+                zeroize(payload);
+            }
+            // Zero bytes are valid UTF-8, so the strings stay well-formed.
+            let texts = meta.attrs.values_mut().flat_map(|attrs| {
+                attrs.values_mut().filter_map(|value| match value {
+                    AttrValue::Text { value } => Some(value),
+                    _ => None,
+                })
+            });
+            for text in meta.keys.values_mut().chain(texts) {
+                zeroize(unsafe { text.as_mut_vec() });
+            }
+            // Settings survive; only the points go.
+            *meta = PointMeta {
+                weights: meta.weights.take(),
+                build: meta.build,
+                level_scale: meta.level_scale,
+                privacy_epsilon: meta.privacy_epsilon,
+                ..PointMeta::default()
+            };
+            let removed: Vec<u64> = known.drain().collect();
+            self.notify(|| Event::Remove(removed));
+            self.mmapped.store(false, Ordering::Relaxed);
+            *lazy = None;
+            *source = None;
+        }
+        *self.sketches.lock().unwrap_or_else(PoisonError::into_inner) = None;
+        self.invalidate_points();
+        self.clear_query_cache();
+        destroy_dump(directory, basename)
+    }
+}
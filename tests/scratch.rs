@@ -0,0 +1,486 @@
+use hnsw::*;
+use std::sync::Arc;
+fn config() -> HnswIndexConfig {
+    HnswIndexConfig {
+        max_nb_connection: 8,
+        max_elements: 1000,
+        max_layer: 16,
+        ef_construction: 50,
+        dimension: 2,
+        distance: DistanceType::L2,
+        normalize_vectors: false,
+        level_scale: None,
+    }
+}
+#[test]
+fn traversal() {
+    let i = Arc::new(HnswIndex::new(config()));
+    let s0 = i.search_with_stats(vec![0.0, 0.0], 5, 20).unwrap();
+    assert!(s0.results.is_empty());
+    for n in 0..200u64 {
+        i.insert(vec![n as f32, (n * 7 % 13) as f32], n).unwrap();
+    }
+    let s = i.search_with_stats(vec![3.0, 1.0], 5, 20).unwrap();
+    assert!(s.stats.nodes_visited > 0);
+    i.remove_batch((0..100).collect()).unwrap();
+    let s = i.search_with_stats(vec![3.0, 1.0], 5, 20).unwrap();
+    assert!(s.results.iter().all(|r| r.id >= 100));
+    let q = typeahead_check(&i);
+    assert!(!q.is_empty());
+    let b = i
+        .search_bounded(
+            vec![150.0, 1.0],
+            5,
+            20,
+            SearchLimits {
+                max_visited_nodes: None,
+                time_budget_us: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(b.results.len(), 5);
+}
+fn typeahead_check(i: &Arc<HnswIndex>) -> Vec<SearchResult> {
+    let s = QuerySession::new(i.clone(), 20);
+    s.search(vec![150.0, 1.0], 5).unwrap();
+    s.search(vec![151.0, 1.0], 5).unwrap()
+}
+
+#[cfg(feature = "server")]
+fn req(port: u16, raw: String) -> String {
+    use std::io::{Read, Write};
+    let mut s = std::net::TcpStream::connect(("127.0.0.1", port)).unwrap();
+    s.write_all(raw.as_bytes()).unwrap();
+    let mut out = String::new();
+    s.read_to_string(&mut out).unwrap();
+    out.lines().next().unwrap().to_string()
+}
+
+#[cfg(feature = "server")]
+#[test]
+fn server() {
+    let i = Arc::new(HnswIndex::new(config()));
+    let srv = QueryServer::serve(Arc::clone(&i), 0, false).unwrap();
+    let (p, t) = (srv.port(), srv.token());
+    assert_eq!(t.len(), 32);
+    println!("{}", req(p, "GET / HTTP/1.1\r\n\r\n".into()));
+    println!(
+        "{}",
+        req(
+            p,
+            format!("GET / HTTP/1.1\r\nAuthorization: Bearer {t}\r\nOrigin: x\r\n\r\n")
+        )
+    );
+    println!(
+        "{}",
+        req(
+            p,
+            format!("GET / HTTP/1.1\r\nAuthorization: Bearer {t}\r\n\r\n")
+        )
+    );
+    let body = r#"{"vectors":[[1,2]],"ids":[1]}"#;
+    println!(
+        "{}",
+        req(
+            p,
+            format!(
+                "POST /insert HTTP/1.1\r\nAuthorization: Bearer {t}\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            )
+        )
+    );
+    println!(
+        "{}",
+        req(
+            p,
+            format!(
+                "POST /insert HTTP/1.1\r\nAuthorization: Bearer {t}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            )
+        )
+    );
+    let srv2 = QueryServer::serve(Arc::clone(&i), 0, true).unwrap();
+    assert_ne!(srv2.token(), t);
+    println!(
+        "{}",
+        req(
+            srv2.port(),
+            format!(
+                "POST /insert HTTP/1.1\r\nAuthorization: Bearer {}\r\nContent-Type: application/json; charset=utf-8\r\nContent-Length: {}\r\n\r\n{body}",
+                srv2.token(),
+                body.len()
+            )
+        )
+    );
+}
+
+#[test]
+fn usearch_roundtrip() {
+    let i = HnswIndex::new(config());
+    for n in 0..300u64 {
+        i.insert(vec![n as f32, (n * 7 % 13) as f32], n).unwrap();
+    }
+    let p = "/tmp/scratch.usearch".to_string();
+    i.save_usearch(p.clone()).unwrap();
+    let j = HnswIndex::load_usearch(p.clone()).unwrap();
+    assert_eq!(j.len().unwrap(), 300);
+    assert_eq!(j.search(vec![5.0, 9.0], 1, 50).unwrap()[0].id, 5);
+    let mut bytes = std::fs::read(&p).unwrap();
+    bytes[0..4].copy_from_slice(&u32::MAX.to_le_bytes());
+    bytes[4..8].copy_from_slice(&u32::MAX.to_le_bytes());
+    std::fs::write("/tmp/scratch-bad.usearch", &bytes).unwrap();
+    println!(
+        "{:?}",
+        HnswIndex::load_usearch("/tmp/scratch-bad.usearch".into()).err()
+    );
+    std::fs::write("/tmp/scratch-bad.usearch", vec![7u8; 100]).unwrap();
+    println!(
+        "{:?}",
+        HnswIndex::load_usearch("/tmp/scratch-bad.usearch".into()).err()
+    );
+}
+
+#[test]
+fn npz_roundtrip() {
+    let i = HnswIndex::new(config());
+    for n in 0..1000u64 {
+        i.insert(vec![n as f32, (n * 7 % 13) as f32], n * 3)
+            .unwrap();
+    }
+    i.export_all("/tmp/scratch.npz".into(), ExportFormat::Npz)
+        .unwrap();
+    let j = HnswIndex::new(config());
+    assert_eq!(
+        j.import_npz(
+            "/tmp/scratch.npz".into(),
+            "vectors".into(),
+            Some("ids".into()),
+            1
+        )
+        .unwrap(),
+        1000
+    );
+    assert_eq!(j.search(vec![5.0, 9.0], 1, 50).unwrap()[0].id, 16);
+    // raw npy with a lying shape
+    let mut h = b"\x93NUMPY\x01\x00".to_vec();
+    let d = "{'descr': '<f4', 'fortran_order': False, 'shape': (9999999999999, 2), }\n";
+    h.extend((d.len() as u16).to_le_bytes());
+    h.extend(d.as_bytes());
+    h.extend([0u8; 16]);
+    std::fs::write("/tmp/scratch-bad.npy", &h).unwrap();
+    println!("{:?}", j.import_npy("/tmp/scratch-bad.npy".into(), 0).err());
+    let d =
+        "{'descr': '<f4', 'fortran_order': False, 'shape': (99999999999999999, 999999999999), }\n";
+    let mut h = b"\x93NUMPY\x01\x00".to_vec();
+    h.extend((d.len() as u16).to_le_bytes());
+    h.extend(d.as_bytes());
+    std::fs::write("/tmp/scratch-bad.npy", &h).unwrap();
+    println!("{:?}", j.import_npy("/tmp/scratch-bad.npy".into(), 0).err());
+}
+
+#[test]
+fn view_rerank() {
+    let i = Arc::new(HnswIndex::new(config()));
+    for n in 0..200u64 { i.insert(vec![n as f32, (n * 7 % 13) as f32], n).unwrap(); }
+    let v = Arc::clone(&i).create_view(DistanceType::L1);
+    let r = v.search(vec![5.0, 9.0], 3, 50).unwrap();
+    assert_eq!(r[0].id, 5);
+    assert_eq!(r[0].distance, 5.0f32 - 5.0 + (9.0 - 9.0f32).abs());
+    assert_eq!(r.len(), 3);
+}
+
+#[test]
+fn point_data() {
+    let i = HnswIndex::new(config());
+    for n in 0..50u64 { i.insert(vec![n as f32, 1.0], n).unwrap(); }
+    assert_eq!(i.get_point_data(7).unwrap(), Some(vec![7.0, 1.0]));
+    i.insert(vec![100.0, 2.0], 100).unwrap();
+    assert_eq!(i.get_point_data(100).unwrap(), Some(vec![100.0, 2.0]));
+    i.remove_batch(vec![7]).unwrap();
+    assert_eq!(i.get_point_data(7).unwrap(), None);
+    assert_eq!(i.get_point_data(999).unwrap(), None);
+}
+
+#[test]
+fn integrity_with_tombstones() {
+    let i = HnswIndex::new(config());
+    for n in 0..100u64 { i.insert(vec![n as f32, (n * 7 % 13) as f32], n).unwrap(); }
+    i.remove_batch(vec![3, 4, 5]).unwrap();
+    let r = i.verify_integrity(true).unwrap();
+    assert!(r.ok, "{r:?}");
+    assert_eq!(r.graph_points, 97);
+    assert!(i.graph_neighbors(4, 0).is_err());
+    let n = i.graph_neighbors(6, 0).unwrap();
+    assert!(!n.is_empty() && !n.iter().any(|x| (3..=5).contains(x)));
+    i.export_graph("/tmp/scratch.json".into(), GraphFormat::Json).unwrap();
+    let s = std::fs::read_to_string("/tmp/scratch.json").unwrap();
+    assert!(!s.contains("\"id\":4,"));
+    // still tombstoned: a second report sees the same graph
+    assert_eq!(i.verify_integrity(false).unwrap().graph_points, 97);
+}
+
+#[test]
+fn compact_capacity() {
+    let i = HnswIndex::new(config());
+    for n in 0..100u64 { i.insert(vec![n as f32, 1.0], n).unwrap(); }
+    let mut small = config();
+    small.max_elements = 10;
+    let c = i.compact(vec![1, 2], small).unwrap();
+    assert_eq!(c.len().unwrap(), 98);
+    assert!(c.get_capacity() >= 98, "{}", c.get_capacity());
+}
+
+#[test]
+fn apply_changes_capacity() {
+    let mut c = config();
+    c.max_elements = 10;
+    let a = HnswIndex::new(config());
+    for n in 0..12u64 { a.insert(vec![n as f32, 1.0], n).unwrap(); }
+    a.insert(vec![50.0, 1.0], 3 + 100).unwrap();
+    let changes = a.export_changes(0).unwrap();
+    let b = HnswIndex::new(c);
+    for n in 0..8u64 { b.insert(vec![n as f32, 2.0], n).unwrap(); }
+    let err = b.apply_changes(changes).unwrap_err();
+    println!("{err:?}");
+    assert_eq!(b.len().unwrap(), 8);
+    assert_eq!(b.get_point_data(3).unwrap(), Some(vec![3.0, 2.0]));
+}
+
+#[test]
+fn dot_unit() {
+    let mut c = config();
+    c.distance = DistanceType::Dot;
+    let i = HnswIndex::new(c);
+    println!("{:?}", i.insert(vec![3.0, 4.0], 1).unwrap_err());
+    i.insert(vec![0.6, 0.8], 1).unwrap();
+    i.insert(vec![0.6004, 0.8], 2).unwrap();
+    println!("{:?}", i.search(vec![3.0, 4.0], 1, 10).unwrap_err());
+    assert_eq!(i.search(vec![0.6, 0.8], 1, 10).unwrap().len(), 1);
+    c.normalize_vectors = true;
+    let j = HnswIndex::new(c);
+    j.insert(vec![3.0, 4.0], 1).unwrap();
+    assert_eq!(j.search(vec![3.0, 4.0], 1, 10).unwrap().len(), 1);
+}
+
+#[test]
+fn session_retry() {
+    let mut c = config();
+    c.max_elements = 4;
+    let i = Arc::new(HnswIndex::new(c));
+    let s = Arc::clone(&i).insert_session(3).unwrap();
+    s.push(vec![1.0, 1.0], 1).unwrap();
+    s.push(vec![2.0, 1.0], 2).unwrap();
+    s.push(vec![3.0, 1.0], 3).unwrap();
+    s.push(vec![4.0, 1.0], 4).unwrap();
+    s.push(vec![5.0, 1.0], 5).unwrap();
+    assert!(s.push(vec![6.0, 1.0], 6).is_err());
+    assert_eq!(s.pending().unwrap(), 3);
+    i.reserve(10).unwrap();
+    assert_eq!(s.commit().unwrap(), 6);
+    assert_eq!(i.len().unwrap(), 6);
+}
+
+#[test]
+fn repair() {
+    let i = HnswIndex::new(config());
+    for n in 0..100u64 { i.insert(vec![n as f32, 1.0], n).unwrap(); }
+    i.remove_batch(vec![1, 2, 3]).unwrap();
+    assert_eq!(i.repair_graph(10).unwrap(), 3);
+    assert_eq!(i.repair_graph(1000).unwrap(), 0);
+    assert_eq!(i.len().unwrap(), 97);
+    assert_eq!(i.repair_graph(0).unwrap(), 0);
+}
+
+#[test]
+fn tombstones_roundtrip() {
+    let i = HnswIndex::new(config());
+    for n in 0..100u64 { i.insert(vec![n as f32, 1.0], n).unwrap(); }
+    i.remove_batch(vec![1, 2, 3]).unwrap();
+    let _ = std::fs::create_dir_all("/tmp/scratch-tomb");
+    i.save("/tmp/scratch-tomb".into(), "t".into()).unwrap();
+    assert_eq!(i.repair_graph(0).unwrap(), 3, "save must not purge");
+    assert_eq!(dump_format_version("/tmp/scratch-tomb".into(), "t".into()).unwrap(), 4);
+    for j in [
+        HnswIndex::load("/tmp/scratch-tomb".into(), "t".into(), config()).unwrap(),
+        HnswIndex::load_lazy("/tmp/scratch-tomb".into(), "t".into(), config(), false).unwrap(),
+    ] {
+        assert_eq!(j.len().unwrap(), 97);
+        assert!(!j.contains(2).unwrap());
+        assert!(j.search(vec![2.0, 1.0], 3, 50).unwrap().iter().all(|r| !(1..=3).contains(&r.id)));
+        j.insert(vec![2.0, 1.0], 2).unwrap();
+        assert_eq!(j.len().unwrap(), 98);
+        assert!(j.verify_integrity(true).unwrap().ok);
+    }
+}
+
+#[test]
+fn v3_loads() {
+    assert_eq!(dump_format_version("/tmp/scratch-v3".into(), "t".into()).unwrap(), 3);
+    let j = HnswIndex::load("/tmp/scratch-v3".into(), "t".into(), config()).unwrap();
+    assert_eq!(j.len().unwrap(), 98);
+    assert_eq!(j.get_payload(500).unwrap(), Some(vec![9, 9]));
+}
+
+#[test]
+fn stream_export() {
+    let i = HnswIndex::new(config());
+    for n in 0..1000u64 {
+        i.insert(vec![n as f32, (n * 7 % 13) as f32], n).unwrap();
+    }
+    i.remove_batch((0..10).collect()).unwrap();
+    for f in [ExportFormat::Npz, ExportFormat::Jsonl, ExportFormat::Arrow] {
+        assert_eq!(i.export_all("/tmp/scratch-exp".into(), f).unwrap(), 990);
+        let j = HnswIndex::new(config());
+        match f {
+            ExportFormat::Npz => {
+                j.import_npz("/tmp/scratch-exp".into(), "vectors".into(), Some("ids".into()), 0)
+                    .unwrap();
+            }
+            ExportFormat::Jsonl => {
+                j.import_jsonl("/tmp/scratch-exp".into(), "vector".into(), "id".into(), vec![])
+                    .unwrap();
+            }
+            ExportFormat::Arrow => {
+                j.import_arrow("/tmp/scratch-exp".into(), "vector".into(), "id".into())
+                    .unwrap();
+            }
+        }
+        assert_eq!(j.len().unwrap(), 990);
+        for n in [10, 500, 999] {
+            assert_eq!(j.get_point_data(n).unwrap(), i.get_point_data(n).unwrap());
+        }
+    }
+    assert_eq!(i.export_arrow("/tmp/scratch-exp".into()).unwrap(), 990);
+    assert_eq!(i.len().unwrap(), 990);
+}
+
+#[test]
+fn swift_mirror() {
+    let dir = "/tmp/scratch-mirror";
+    let _ = std::fs::create_dir_all(dir);
+    // npy
+    let header = "{'descr': '<f4', 'fortran_order': False, 'shape': (3, 2), }";
+    let mut npy = b"\x93NUMPY\x01\x00".to_vec();
+    let mut h = header.to_string();
+    while (10 + h.len() + 1) % 64 != 0 {
+        h.push(' ');
+    }
+    h.push('\n');
+    npy.extend((h.len() as u16).to_le_bytes());
+    npy.extend(h.as_bytes());
+    for v in [1.0f32, 0.0, 0.0, 1.0, 1.0, 1.0] {
+        npy.extend(v.to_le_bytes());
+    }
+    std::fs::write(format!("{dir}/v.npy"), &npy).unwrap();
+    let i = HnswIndex::new(config());
+    assert_eq!(i.import_npy(format!("{dir}/v.npy"), 10).unwrap(), 3);
+    assert_eq!(i.get_point_data(12).unwrap(), Some(vec![1.0, 1.0]));
+    // faiss IxM2 over IxFI
+    let mut f = Vec::new();
+    let head = |f: &mut Vec<u8>, fourcc: &[u8]| {
+        f.extend(fourcc);
+        f.extend(2i32.to_le_bytes());
+        f.extend(3i64.to_le_bytes());
+        f.extend([0u8; 16]);
+        f.push(1);
+        f.extend(1i32.to_le_bytes());
+    };
+    head(&mut f, b"IxM2");
+    head(&mut f, b"IxFI");
+    f.extend(6u64.to_le_bytes());
+    for v in [1.0f32, 0.0, 0.0, 1.0, 1.0, 1.0] {
+        f.extend(v.to_le_bytes());
+    }
+    f.extend(3u64.to_le_bytes());
+    for id in [7i64, 8, 9] {
+        f.extend(id.to_le_bytes());
+    }
+    std::fs::write(format!("{dir}/f.faiss"), &f).unwrap();
+    struct P;
+    impl ProgressListener for P {
+        fn on_progress(&self, _: u64, _: u64) {}
+    }
+    let j = HnswIndex::new(config());
+    assert_eq!(j.import_faiss_flat(format!("{dir}/f.faiss"), Box::new(P)).unwrap(), 3);
+    assert_eq!(j.get_point_data(9).unwrap(), Some(vec![1.0, 1.0]));
+    // hnswlib
+    let (dim, count, m) = (2u64, 3u64, 4u64);
+    let max_m0 = 2 * m;
+    let offset_data = max_m0 * 4 + 4;
+    let label_offset = offset_data + dim * 4;
+    let size = label_offset + 8;
+    let mut h = Vec::new();
+    for v in [0u64, count, count, size, label_offset, offset_data] {
+        h.extend(v.to_le_bytes());
+    }
+    h.extend(0i32.to_le_bytes());
+    h.extend(0u32.to_le_bytes());
+    for v in [m, max_m0, m] {
+        h.extend(v.to_le_bytes());
+    }
+    h.extend((1.0 / (m as f64).ln()).to_le_bytes());
+    h.extend(100u64.to_le_bytes());
+    let vecs = [[1.0f32, 0.0], [0.0, 1.0], [1.0, 1.0]];
+    for (n, v) in vecs.iter().enumerate() {
+        let mut e = vec![0u8; size as usize];
+        if n == 1 {
+            e[2] = 1;
+        }
+        e[offset_data as usize..offset_data as usize + 4].copy_from_slice(&v[0].to_le_bytes());
+        e[offset_data as usize + 4..label_offset as usize].copy_from_slice(&v[1].to_le_bytes());
+        e[label_offset as usize..].copy_from_slice(&(100 + n as u64).to_le_bytes());
+        h.extend(e);
+    }
+    for _ in 0..count {
+        h.extend(0u32.to_le_bytes());
+    }
+    std::fs::write(format!("{dir}/h.bin"), &h).unwrap();
+    let k = HnswIndex::from_hnswlib(format!("{dir}/h.bin"), 2, DistanceType::L2).unwrap();
+    assert_eq!(k.len().unwrap(), 2);
+    assert_eq!(k.get_point_data(102).unwrap(), Some(vec![1.0, 1.0]));
+    assert!(!k.contains(101).unwrap());
+}
+
+#[test]
+fn swift_mirror2() {
+    let a = HnswIndex::new(config());
+    a.insert_with_payload(vec![1.0, 0.0], 1, b"one".to_vec()).unwrap();
+    a.insert(vec![0.0, 1.0], 2).unwrap();
+    a.insert(vec![1.0, 1.0], 3).unwrap();
+    let b = HnswIndex::new(config());
+    let r = b.apply_changes(a.export_changes(0).unwrap()).unwrap();
+    println!("{r:?}");
+    assert_eq!(b.len().unwrap(), 3);
+    a.remove_batch(vec![2]).unwrap();
+    let r = b.apply_changes(a.export_changes(r.version).unwrap()).unwrap();
+    println!("{r:?}");
+    assert!(!b.contains(2).unwrap());
+    assert_eq!(b.get_payload(1).unwrap(), Some(b"one".to_vec()));
+    let c = HnswIndex::from_bytes(a.to_bytes().unwrap()).unwrap();
+    assert_eq!(c.len().unwrap(), 2);
+    assert_eq!(c.get_payload(1).unwrap(), Some(b"one".to_vec()));
+    assert_eq!(c.get_distance(), DistanceType::L2);
+    a.save_usearch("/tmp/scratch-mirror/u.usearch".into()).unwrap();
+    let d = HnswIndex::load_usearch("/tmp/scratch-mirror/u.usearch".into()).unwrap();
+    assert_eq!(d.len().unwrap(), 2);
+    assert_eq!(d.get_point_data(3).unwrap(), Some(vec![1.0, 1.0]));
+    a.insert(vec![0.5, 0.5], 2).unwrap();
+    assert_eq!(a.len().unwrap(), 3);
+    println!("{:?}", a.verify_integrity(true).unwrap());
+}
+
+#[test]
+fn swift_mirror3() {
+    let a = HnswIndex::new(config());
+    a.insert_batch(vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![1.0, 1.0]], vec![0, 1, 2]).unwrap();
+    a.insert(vec![-1.0, 0.0], 3).unwrap();
+    a.remove_batch(vec![1, 0]).unwrap();
+    a.insert(vec![0.5, 0.5], 0).unwrap();
+    let _ = std::fs::create_dir_all("/tmp/scratch-m3");
+    a.save("/tmp/scratch-m3".into(), "removal".into()).unwrap();
+    let b = HnswIndex::load("/tmp/scratch-m3".into(), "removal".into(), config()).unwrap();
+    assert_eq!(b.count().unwrap(), 3);
+    assert!(!b.contains(1).unwrap());
+    assert_eq!(b.get_point_data(0).unwrap(), Some(vec![0.5, 0.5]));
+    assert!(b.verify_integrity(true).unwrap().ok);
+}
@@ -0,0 +1,46 @@
+// Same names as the uniffi macros hnsw-swift uses, expanding to the item as
+// written (attributes) or to nothing (derives and scaffolding). lib.rs binds
+// this crate to the name `uniffi` when the ffi feature is off, so the
+// annotated code builds unchanged as a plain Rust library.
+
+use proc_macro::TokenStream;
+
+#[proc_macro_attribute]
+pub fn export(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+#[proc_macro_attribute]
+pub fn method(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+#[proc_macro_attribute]
+pub fn constructor(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+#[proc_macro_derive(Record, attributes(uniffi))]
+pub fn record(_item: TokenStream) -> TokenStream {
+    TokenStream::new()
+}
+
+#[proc_macro_derive(Enum, attributes(uniffi))]
+pub fn enum_(_item: TokenStream) -> TokenStream {
+    TokenStream::new()
+}
+
+#[proc_macro_derive(Object, attributes(uniffi))]
+pub fn object(_item: TokenStream) -> TokenStream {
+    TokenStream::new()
+}
+
+#[proc_macro_derive(Error, attributes(uniffi))]
+pub fn error(_item: TokenStream) -> TokenStream {
+    TokenStream::new()
+}
+
+#[proc_macro]
+pub fn setup_scaffolding(_input: TokenStream) -> TokenStream {
+    TokenStream::new()
+}